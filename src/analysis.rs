@@ -0,0 +1,65 @@
+use super::automaton::Automaton;
+use super::components::{board::Board, rule::Rule, state::State};
+
+/// Check whether a board equals another board shifted by an offset, wrapping around the edges.
+///
+/// # Arguments
+///
+/// - `board`: The board to check.
+/// - `original`: The board to shift, which must have the same dimensions as `board`.
+/// - `dx`: The offset along the x-axis.
+/// - `dy`: The offset along the y-axis.
+///
+/// # Returns
+///
+/// Whether every cell of `board` equals the cell of `original` at `(x - dx, y - dy)`, wrapped around the edges.
+fn equals_shifted<S: State>(board: &Board<S>, original: &Board<S>, dx: isize, dy: isize) -> bool {
+    let (width, height) = (board.width() as isize, board.height() as isize);
+    board.iter_coords().all(|(x, y)| {
+        let source_x: usize = (x as isize - dx).rem_euclid(width) as usize;
+        let source_y: usize = (y as isize - dy).rem_euclid(height) as usize;
+        board.get(x, y) == original.get(source_x, source_y)
+    })
+}
+
+/// Detect whether the pattern on a board is a spaceship: a pattern that returns to its original shape, shifted by a
+/// non-zero offset, after some number of generations.
+///
+/// A copy of the board is evolved with the rule for up to `max_period` generations. After `p` generations, the copy is
+/// compared with the original board shifted by every offset of at most `p` cells in each direction, which covers
+/// every spaceship of a rule that reads a neighbourhood of radius 1. The comparison wraps around the edges, so the
+/// pattern should be surrounded by enough background for the shift not to change its shape on a fixed board.
+/// Offsets under which the original board is unchanged, such as every offset of a uniform board, are ignored.
+///
+/// # Arguments
+///
+/// - `board`: The board with the pattern to check.
+/// - `rule`: The rule to evolve the pattern with.
+/// - `max_period`: The largest period to check.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+///
+/// # Returns
+///
+/// The period of the spaceship and the offset `(dx, dy)` it moves by in one period, with the smallest period found
+/// first and, within a period, the offset with the smallest `dy` and then `dx`. Returns None if the pattern does not
+/// move within `max_period` generations, or if the rule fails to apply.
+pub fn detect_spaceship<S: State>(board: &Board<S>, rule: &dyn Rule<S>, max_period: usize) -> Option<(usize, isize, isize)> {
+    let mut copy: Board<S> = board.clone();
+    let mut automaton: Automaton<S> = Automaton::new(&mut copy, vec![rule.clone_box()]);
+
+    for period in 1..=max_period {
+        automaton.evolve(1).ok()?;
+        let reach: isize = period as isize;
+        let offset: Option<(isize, isize)> = (-reach..=reach)
+            .flat_map(|dy| (-reach..=reach).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .find(|&(dx, dy)| equals_shifted(automaton.board(), board, dx, dy) && !equals_shifted(board, board, dx, dy));
+        if let Some((dx, dy)) = offset {
+            return Some((period, dx, dy));
+        }
+    }
+    None
+}
@@ -1,11 +1,43 @@
-use super::components::error::OutOfBoundsSetError;
+use super::components::error::{AutomatonError, OutOfBoundsSetError};
 use super::components::{
     board::{Board, Colour},
+    neighbourhood::Neighbourhood,
     rule::{Delta, Rule},
-    state::State,
+    state::{DiscreteState, State},
 };
-use super::ui::simulate;
+use super::rng::rule_cell_rng;
+use super::ui::{simulate, simulate_editable, simulate_random, simulate_with_colour_overrides, simulate_with_trail};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 use rayon::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::ControlFlow;
+
+/// The policy for resolving deltas that write the same cell within a single time step.
+///
+/// The policies are:
+/// - LastWins: Every delta is applied in order, so the last delta written to a cell wins. This is the default.
+/// - FirstWins: Only the first delta written to a cell is applied, and later deltas to the same cell are ignored.
+/// - Error: The time step fails with `AutomatonError::Conflict` identifying the cell, and the board is left unchanged.
+///
+/// Deltas are ordered by rule (in the order the rules are applied) and then by the cell the rule was applied to.
+/// Since most rules write every cell they are applied to, `FirstWins` and `Error` are mainly useful when rules
+/// only write the cells they change.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ConflictPolicy {
+    #[default]
+    LastWins,
+    FirstWins,
+    Error,
+}
+
+/// A private struct that tracks the cells near recent changes, which are the only cells visited at the next time step.
+struct ActiveSet {
+    /// The radius within which a changed cell makes its neighbours active
+    radius: usize,
+    /// The active cells in row-major order, or None if every cell is active
+    cells: Option<Vec<(usize, usize)>>,
+}
 
 /// A struct that represents a cellular automaton.
 ///
@@ -18,8 +50,15 @@ use rayon::prelude::*;
 /// # Fields
 ///
 /// - `board`: A reference to the board of cells.
-/// - `rules`: A vector of rules to apply to the board. The rules are applied in the order they are stored in the vector.
+/// - `rules`: A vector of rules to apply to the board. The rules are applied in the order they are stored in the vector, unless the order is shuffled.
 /// - `curr_time`: The current time step of the automaton.
+/// - `previous_board`: The board at the previous time step, recorded by second-order (reversible) evolution.
+/// - `rule_order_rng`: The random number generator used to shuffle the order of the rules at each time step, if enabled.
+/// - `conflict_policy`: The policy for resolving deltas that write the same cell within a single time step.
+/// - `history`: The time step of the first recorded board and the boards recorded at every time step since, if recording is enabled.
+/// - `seed`: The seed from which the random number generator of each cell is derived at every time step, if set.
+/// - `rejected_deltas`: The errors of the deltas that could not be applied at the latest time step.
+/// - `active_set`: The cells to visit at the next time step, if the active set is enabled.
 ///
 /// # Lifetime
 ///
@@ -28,6 +67,13 @@ pub struct Automaton<'a, S: State> {
     board: &'a mut Board<S>,
     rules: Vec<Box<dyn Rule<S>>>,
     curr_time: usize,
+    previous_board: Option<Board<S>>,
+    rule_order_rng: Option<StdRng>,
+    conflict_policy: ConflictPolicy,
+    history: Option<(usize, Vec<Board<S>>)>,
+    seed: Option<u64>,
+    rejected_deltas: Vec<OutOfBoundsSetError>,
+    active_set: Option<ActiveSet>,
 }
 
 impl<'a, S: State> Automaton<'a, S> {
@@ -47,6 +93,50 @@ impl<'a, S: State> Automaton<'a, S> {
             board,
             rules,
             curr_time: 0,
+            previous_board: None,
+            rule_order_rng: None,
+            conflict_policy: ConflictPolicy::default(),
+            history: None,
+            seed: None,
+            rejected_deltas: Vec::new(),
+            active_set: None,
+        }
+    }
+
+    /// Start building an automaton with an `AutomatonBuilder`.
+    ///
+    /// # Returns
+    ///
+    /// A new `AutomatonBuilder` with no board, no rules, and the default settings.
+    pub fn builder() -> AutomatonBuilder<'a, S> {
+        AutomatonBuilder::new()
+    }
+
+    /// Create a copy of the automaton that evolves the given board.
+    ///
+    /// `Automaton` cannot implement `Clone` because it holds an exclusive reference to its board, so the copy
+    /// must be given a board of its own. The rules, current time step, previous board, history and settings are cloned.
+    /// If the active set is enabled, the copy visits every cell at its first time step, since its board may differ.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: A reference to the board of cells for the copy, typically a clone of this automaton's board.
+    ///
+    /// # Returns
+    ///
+    /// A new `Automaton` with the same configuration as this one, evolving the given board.
+    pub fn clone_with_board<'b>(&self, board: &'b mut Board<S>) -> Automaton<'b, S> {
+        Automaton {
+            board,
+            rules: self.rules.clone(),
+            curr_time: self.curr_time,
+            previous_board: self.previous_board.clone(),
+            rule_order_rng: self.rule_order_rng.clone(),
+            conflict_policy: self.conflict_policy,
+            history: self.history.clone(),
+            seed: self.seed,
+            rejected_deltas: self.rejected_deltas.clone(),
+            active_set: self.active_set.as_ref().map(|active_set| ActiveSet { radius: active_set.radius, cells: None }),
         }
     }
 
@@ -59,6 +149,11 @@ impl<'a, S: State> Automaton<'a, S> {
         self.curr_time
     }
 
+    /// Set the current time step of the automaton, to resume a run on a board evolved by another automaton.
+    pub(crate) fn set_curr_time(&mut self, curr_time: usize) {
+        self.curr_time = curr_time;
+    }
+
     /// Get the board of the automaton.
     ///
     /// # Returns
@@ -68,6 +163,35 @@ impl<'a, S: State> Automaton<'a, S> {
         self.board
     }
 
+    /// Check whether any cell on the board satisfies a predicate, stopping at the first match.
+    ///
+    /// This is useful as a termination condition, such as continuing while any cell is burning.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate`: A function that takes the state of a cell and returns whether it matches.
+    pub fn any_match<F: Fn(S) -> bool>(&self, predicate: F) -> bool {
+        self.board.any_match(predicate)
+    }
+
+    /// Check whether no cell on the board satisfies a predicate, stopping at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate`: A function that takes the state of a cell and returns whether it matches.
+    pub fn none_match<F: Fn(S) -> bool>(&self, predicate: F) -> bool {
+        self.board.none_match(predicate)
+    }
+
+    /// Get the board at the previous time step, as recorded by second-order (reversible) evolution.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the previous board, or None if the automaton has not been evolved reversibly.
+    pub fn previous_board(&self) -> Option<&Board<S>> {
+        self.previous_board.as_ref()
+    }
+
     /// Get the rules of the automaton.
     ///
     /// # Returns
@@ -77,67 +201,446 @@ impl<'a, S: State> Automaton<'a, S> {
         &self.rules
     }
 
+    /// Get mutable access to the rules of the automaton, so rules can be replaced, reordered or removed between evolutions.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the vector of rules of the automaton.
+    pub fn rules_mut(&mut self) -> &mut Vec<Box<dyn Rule<S>>> {
+        self.wake_all_cells();
+        &mut self.rules
+    }
+
+    /// Get mutable access to a single rule of the automaton.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`: The index of the rule, in the order the rules are stored.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the rule, or None if the index is out of bounds.
+    pub fn rule_mut(&mut self, index: usize) -> Option<&mut Box<dyn Rule<S>>> {
+        self.wake_all_cells();
+        self.rules.get_mut(index)
+    }
+
     /// Add a rule to the automaton.
     ///
     /// # Arguments
     ///
     /// - `rule`: The rule to add to the automaton.
     pub fn add_rule(&mut self, rule: Box<dyn Rule<S>>) {
+        self.wake_all_cells();
         self.rules.push(rule);
     }
 
-    /// Apply the rules of the automaton to the board.
+    /// Shuffle the order in which the rules are applied at every time step.
+    ///
+    /// By default, rules are applied in the order they are stored, so later rules win when several rules write
+    /// the same cell. Shuffling the order with a seeded random number generator removes this bias while keeping
+    /// runs reproducible: the same seed always produces the same sequence of orders.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: The seed of the random number generator used to shuffle the rules.
+    pub fn enable_shuffled_rule_order(&mut self, seed: u64) {
+        self.rule_order_rng = Some(StdRng::seed_from_u64(seed));
+    }
+
+    /// Apply the rules in the order they are stored again, after `enable_shuffled_rule_order`.
+    pub fn disable_shuffled_rule_order(&mut self) {
+        self.rule_order_rng = None;
+    }
+
+    /// Seed the random number generators passed to rules, so that stochastic rules are reproducible.
+    ///
+    /// At every time step, each rule is given a generator for each cell derived from the seed, the time step, the
+    /// rule and the cell as in `rng::cell_rng`, through `Rule::delta_with_rng`. The generators do not depend on the order in
+    /// which cells are evaluated, so the same seed always produces the same run even though cells are evaluated in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: The seed of the run.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+    }
+
+    /// Get the seed of the random number generators passed to rules, if set.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Get the deltas that could not be applied at the latest time step.
+    ///
+    /// Deltas that write outside a board with fixed boundaries are skipped rather than failing the time step, so this
+    /// is the way to find rules that write past the edges of the board.
     ///
     /// # Returns
     ///
-    /// A `Result` containing an error if the rules could not be applied.
-    fn apply_rules(&mut self) -> Result<(), OutOfBoundsSetError> {
-        if self.rules.is_empty() {
-            return Ok(());
+    /// A slice of the errors of the rejected deltas, in the order the deltas were produced.
+    pub fn rejected_deltas(&self) -> &[OutOfBoundsSetError] {
+        &self.rejected_deltas
+    }
+
+    /// Set the policy for resolving deltas that write the same cell within a single time step.
+    ///
+    /// # Arguments
+    ///
+    /// - `conflict_policy`: The policy to use. The default is `ConflictPolicy::LastWins`.
+    pub fn set_conflict_policy(&mut self, conflict_policy: ConflictPolicy) {
+        self.conflict_policy = conflict_policy;
+    }
+
+    /// Get the policy for resolving deltas that write the same cell within a single time step.
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.conflict_policy
+    }
+
+    /// Start recording the board at every time step, so that the automaton can later be rewound.
+    ///
+    /// Recording is off by default because every recorded board is a full copy. The current board is recorded
+    /// immediately, followed by the board after every subsequent time step. Does nothing if recording is already enabled.
+    pub fn enable_history(&mut self) {
+        if self.history.is_none() {
+            self.history = Some((self.curr_time, vec![self.board.clone()]));
         }
+    }
+
+    /// Stop recording the board and discard the recorded history.
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Get the boards recorded since history was enabled, in time order.
+    ///
+    /// # Returns
+    ///
+    /// A slice of the recorded boards, which is empty if recording is not enabled.
+    pub fn history(&self) -> &[Board<S>] {
+        match &self.history {
+            Some((_, boards)) => boards,
+            None => &[],
+        }
+    }
 
-        let estimated_deltas: usize = self.board.width() * self.board.height() * self.rules.len();
+    /// Only visit the cells near recent changes at each time step, instead of every cell of the board.
+    ///
+    /// After each time step, the cells within `radius` of a cell that changed become the active set, and the next
+    /// time step applies the rules to those cells only. Every cell is visited at the first time step, and again
+    /// whenever the rules or the board are changed outside a time step. This speeds up sparse simulations, such as a
+    /// few live cells on a large board, and gives the same results as visiting every cell for rules that are
+    /// deterministic, do not depend on the time step, and only read and write cells within `radius` of the cell.
+    /// Other rules, such as stochastic rules, may leave cells unchanged that would otherwise have changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `radius`: The radius of the neighbourhood the rules read, such as 1 for the Game of Life.
+    pub fn enable_active_set(&mut self, radius: usize) {
+        self.active_set = Some(ActiveSet { radius, cells: None });
+    }
+
+    /// Stop tracking the active set, so every cell is visited at every time step.
+    pub fn disable_active_set(&mut self) {
+        self.active_set = None;
+    }
+
+    /// Get the cells that will be visited at the next time step.
+    ///
+    /// # Returns
+    ///
+    /// The active cells in row-major order, or None if the active set is not enabled or every cell will be visited.
+    pub fn active_cells(&self) -> Option<&[(usize, usize)]> {
+        self.active_set.as_ref().and_then(|active_set| active_set.cells.as_deref())
+    }
+
+    /// Restore the board recorded at the given time step and reset the current time step to it.
+    ///
+    /// The boards recorded after the given time step are discarded, so evolving the automaton again records the new
+    /// time steps. If the automaton has been evolved reversibly, the board before the given time step becomes the
+    /// previous board.
+    ///
+    /// # Arguments
+    ///
+    /// - `step`: The time step to rewind to.
+    ///
+    /// # Returns
+    ///
+    /// An error if recording is not enabled or no board was recorded at the given time step.
+    pub fn rewind_to(&mut self, step: usize) -> Result<(), String> {
+        let Some((start, boards)) = self.history.as_mut() else {
+            return Err("History is not enabled".to_string());
+        };
+        if step < *start || step - *start >= boards.len() {
+            return Err(format!(
+                "No board was recorded at time step {} (recorded time steps are {} to {})",
+                step,
+                start,
+                *start + boards.len() - 1
+            ));
+        }
+
+        let index: usize = step - *start;
+        boards.truncate(index + 1);
+        self.board.clone_from(&boards[index]);
+        if self.previous_board.is_some() {
+            self.previous_board = index.checked_sub(1).map(|previous| boards[previous].clone());
+        }
+        self.curr_time = step;
+        self.wake_all_cells();
+        Ok(())
+    }
+
+    /// Record the current board in the history, if recording is enabled.
+    fn record_history(&mut self) {
+        if let Some((_, boards)) = self.history.as_mut() {
+            boards.push(self.board.clone());
+        }
+    }
+
+    /// Determine the order in which to apply the rules for the next time step, shuffling it if enabled.
+    fn rule_order(&mut self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.rules.len()).collect();
+        if let Some(rng) = self.rule_order_rng.as_mut() {
+            order.shuffle(rng);
+        }
+        order
+    }
+
+    /// Get the cells to visit at the next time step: the active set if it is enabled and known, and otherwise every
+    /// cell, in row-major order.
+    fn cells_to_visit(&self) -> Vec<(usize, usize)> {
+        match &self.active_set {
+            Some(ActiveSet { cells: Some(cells), .. }) => cells.clone(),
+            _ => self.board.iter_coords().collect(),
+        }
+    }
+
+    /// Compute the deltas of every rule for the given cells, reading only the given board.
+    ///
+    /// # Arguments
+    ///
+    /// - `order`: The order in which to apply the rules.
+    /// - `coords`: The cells to apply the rules to.
+    /// - `board`: The board the rules read from.
+    ///
+    /// # Returns
+    ///
+    /// The deltas of each rule in turn, in the given order.
+    fn compute_deltas(&self, order: &[usize], coords: &[(usize, usize)], board: &Board<S>) -> Vec<Delta<S>> {
+        let estimated_deltas: usize = coords.len() * self.rules.len();
         let mut deltas: Vec<Delta<S>> = Vec::with_capacity(estimated_deltas);
 
-        let coords: Vec<(usize, usize)> = self.board.iter_coords().collect::<Vec<(usize, usize)>>();
-        for rule in self.rules.iter() {
+        for &index in order.iter() {
+            let rule: &dyn Rule<S> = self.rules[index].as_ref();
             let rule_deltas: Vec<Delta<S>> = coords
                 .par_iter()
-                .filter_map(|coord| {
-                    match rule.delta(coord.clone(), self.board) {
-                        Ok(deltas) => Some(deltas),
-                        Err(_) => None,
+                .filter_map(|&(x, y)| match self.seed {
+                    // Each rule draws from its own generators, so rules applied to the same cell are independent
+                    Some(seed) => {
+                        let mut rng: StdRng = rule_cell_rng(seed, index, self.curr_time, x, y);
+                        rule.delta_with_rng((x, y), board, self.curr_time, &mut rng).ok()
                     }
+                    None => rule.delta_at((x, y), board, self.curr_time).ok(),
                 })
                 .flatten()
                 .collect();
             deltas.extend(rule_deltas);
         }
+        deltas
+    }
 
-        deltas.iter().for_each(|delta| {
-            let _ = delta.apply(self.board);
-        });
-
+    /// Resolve deltas that write the same cell according to the conflict policy.
+    ///
+    /// # Arguments
+    ///
+    /// - `deltas`: The deltas of the time step, which are filtered in place under `ConflictPolicy::FirstWins`.
+    ///
+    /// # Returns
+    ///
+    /// An error identifying the first cell written twice under `ConflictPolicy::Error`.
+    fn resolve_conflicts(&self, deltas: &mut Vec<Delta<S>>) -> Result<(), AutomatonError> {
+        let mut written: Vec<bool> = match self.conflict_policy {
+            ConflictPolicy::LastWins => return Ok(()),
+            ConflictPolicy::FirstWins | ConflictPolicy::Error => vec![false; self.board.width() * self.board.height()],
+        };
+        if self.conflict_policy == ConflictPolicy::Error {
+            for delta in deltas.iter() {
+                if let Ok(index) = self.board.write_index(delta.x, delta.y) {
+                    if written[index] {
+                        let width: usize = self.board.width();
+                        return Err(AutomatonError::Conflict { x: index % width, y: index / width });
+                    }
+                    written[index] = true;
+                }
+            }
+        } else {
+            // Keep deltas that cannot be written, so that they are reported as rejected
+            deltas.retain(|delta| match self.board.write_index(delta.x, delta.y) {
+                Ok(index) if !written[index] => {
+                    written[index] = true;
+                    true
+                }
+                Ok(_) => false,
+                Err(_) => true,
+            });
+        }
         Ok(())
     }
 
+    /// Apply resolved deltas to a board and find the cells whose state changed.
+    ///
+    /// Only the cells written by the deltas are compared, against their states before any delta was applied, so cells
+    /// written more than once (or written back to their original state) are counted correctly.
+    ///
+    /// # Returns
+    ///
+    /// The cells whose state changed, in row-major order, and the errors of the deltas that could not be applied.
+    fn write_deltas(board: &mut Board<S>, deltas: &[Delta<S>]) -> (Vec<(usize, usize)>, Vec<OutOfBoundsSetError>) {
+        let width: usize = board.width();
+        let mut written: Vec<usize> = deltas.iter().filter_map(|delta| board.write_index(delta.x, delta.y).ok()).collect();
+        written.sort_unstable();
+        written.dedup();
+        let before: Vec<Option<S>> = written.iter().map(|&index| board.get(index % width, index / width)).collect();
+
+        let (_, rejected) = board.apply_deltas(deltas);
+        let changed: Vec<(usize, usize)> = written
+            .into_iter()
+            .zip(before)
+            .map(|(index, state)| ((index % width, index / width), state))
+            .filter(|&((x, y), state)| board.get(x, y) != state)
+            .map(|(coord, _)| coord)
+            .collect();
+        (changed, rejected)
+    }
+
+    /// Replace the active set with the cells within its radius of the cells that changed, if the active set is enabled.
+    fn update_active_set(&mut self, changed: &[(usize, usize)]) {
+        if let Some(active_set) = self.active_set.as_mut() {
+            let radius: isize = active_set.radius as isize;
+            let board: &Board<S> = self.board;
+            let mut next: Vec<(usize, usize)> = changed
+                .iter()
+                .flat_map(|&(x, y)| (-radius..=radius).flat_map(move |dy| (-radius..=radius).map(move |dx| (x, y, dx, dy))))
+                .filter_map(|(x, y, dx, dy)| Neighbourhood::resolve_offset(board, x, y, dx, dy))
+                .collect();
+            next.sort_unstable_by_key(|&(x, y)| (y, x));
+            next.dedup();
+            active_set.cells = Some(next);
+        }
+    }
+
+    /// Make every cell active at the next time step, if the active set is enabled.
+    ///
+    /// This is needed whenever the board or rules change outside a normal time step, since the active set only
+    /// tracks changes made by the rules.
+    fn wake_all_cells(&mut self) {
+        if let Some(active_set) = self.active_set.as_mut() {
+            active_set.cells = None;
+        }
+    }
+
+    /// Apply the rules of the automaton to the board.
+    ///
+    /// The rules always read the board. Without a buffer, the deltas are written back into the board in place. With a
+    /// buffer, which must hold a copy of the board, the deltas are written into the buffer, the buffer and the board
+    /// are swapped, and the same deltas are then written into the old board so the buffer holds a copy of the board
+    /// again for the next step.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`: A copy of the board to write the step into, or None to write into the board in place.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of cells whose state changed, or an error if the rules could not be applied.
+    fn apply_rules(&mut self, buffer: Option<&mut Board<S>>) -> Result<usize, AutomatonError> {
+        if self.rules.is_empty() {
+            self.rejected_deltas.clear();
+            self.update_active_set(&[]);
+            return Ok(0);
+        }
+
+        let order: Vec<usize> = self.rule_order();
+        let coords: Vec<(usize, usize)> = self.cells_to_visit();
+        let mut deltas: Vec<Delta<S>> = self.compute_deltas(&order, &coords, self.board);
+        self.resolve_conflicts(&mut deltas)?;
+
+        let (changed, rejected) = match buffer {
+            Some(buffer) => {
+                let written: (Vec<(usize, usize)>, Vec<OutOfBoundsSetError>) = Self::write_deltas(buffer, &deltas);
+                std::mem::swap(self.board, buffer);
+                Self::write_deltas(buffer, &deltas);
+                written
+            }
+            None => Self::write_deltas(self.board, &deltas),
+        };
+        self.rejected_deltas = rejected;
+        self.update_active_set(&changed);
+        Ok(changed.len())
+    }
+
     /// Advance the automaton by one time step.
     ///
-    /// The automaton applies the rules to the board and increments the time step.
+    /// The automaton applies the rules to the board, increments the time step, and records the board if history is enabled.
     ///
     /// # Returns
     ///
-    /// A `Result` containing an error if the automaton could not be advanced.
-    fn advance(&mut self) -> Result<(), OutOfBoundsSetError> {
-        self.apply_rules()?;
+    /// A `Result` containing the number of cells whose state changed, or an error if the automaton could not be advanced.
+    fn advance(&mut self) -> Result<usize, AutomatonError> {
+        self.advance_into(None)
+    }
+
+    /// Advance the automaton by one time step, writing the step into a buffer as described in `apply_rules`.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`: A copy of the board to write the step into, or None to write into the board in place.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of cells whose state changed, or an error if the automaton could not be advanced.
+    fn advance_into(&mut self, buffer: Option<&mut Board<S>>) -> Result<usize, AutomatonError> {
+        let changed: usize = self.apply_rules(buffer)?;
         self.curr_time += 1;
-        Ok(())
+        self.record_history();
+        Ok(changed)
+    }
+
+    /// Advance the automaton by one time step and report how many cells changed.
+    ///
+    /// This is a cheap way to detect quiescence, as the count is gathered while applying the step's deltas
+    /// rather than by comparing whole boards.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of cells whose state changed, or an error if the automaton could not be advanced.
+    pub fn step_reporting(&mut self) -> Result<usize, AutomatonError> {
+        self.advance()
+    }
+
+    /// Advance the automaton by the given number of time steps and report how many cells changed at each step.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of cells changed at each time step, or an error if the automaton could not be advanced.
+    pub fn evolve_reporting(&mut self, steps: usize) -> Result<Vec<usize>, AutomatonError> {
+        let mut changed: Vec<usize> = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            changed.push(self.advance()?);
+        }
+        Ok(changed)
     }
 
     /// Advance the automaton by the given number of time steps.
     ///
     /// The automaton applies the rules to the board and increments the time step by the given number.
-    /// Rules are applied in the order they are stored.
+    /// Rules are applied in the order they are stored, unless `enable_shuffled_rule_order` has been called, in which
+    /// case they are applied in a new seeded random order at every time step.
     ///
     /// # Arguments
     ///
@@ -146,13 +649,219 @@ impl<'a, S: State> Automaton<'a, S> {
     /// # Returns
     ///
     /// A `Result` containing an error if the automaton could not be advanced.
-    pub fn evolve(&mut self, steps: usize) -> Result<(), OutOfBoundsSetError> {
+    pub fn evolve(&mut self, steps: usize) -> Result<(), AutomatonError> {
         for _ in 0..steps {
             self.advance()?;
         }
         Ok(())
     }
 
+    /// Advance the automaton by the given number of time steps, reading each step from the board and writing it into
+    /// a separate buffer.
+    ///
+    /// `evolve` computes the deltas of every rule against the board before writing any of them back in place, so a
+    /// rule never observes a partially updated board there either. This method makes that a property of the update
+    /// itself rather than of the delta path: each step is written into a second buffer, which then replaces the board.
+    /// The buffer is copied from the board once per call and kept up to date by writing each step's deltas into it,
+    /// so steps do not copy the board. The conflict policy, seeding and rejected delta reporting behave as in `evolve`,
+    /// and the results are the same.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the automaton could not be advanced. A step that fails leaves the board unchanged.
+    pub fn evolve_synchronous(&mut self, steps: usize) -> Result<(), AutomatonError> {
+        if steps == 0 {
+            return Ok(());
+        }
+
+        let mut buffer: Board<S> = self.board.clone();
+        for _ in 0..steps {
+            self.advance_into(Some(&mut buffer))?;
+        }
+        Ok(())
+    }
+
+    /// Advance the automaton while a predicate on the board holds, up to a maximum number of time steps.
+    ///
+    /// The predicate is checked before every step, so no steps are taken if it does not hold initially.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_steps`: The maximum number of time steps to advance the automaton.
+    ///
+    /// - `pred`: A function that takes the board and the number of steps taken so far, and returns whether to keep advancing.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps taken, or an error if the automaton could not be advanced.
+    pub fn evolve_while<F: Fn(&Board<S>, usize) -> bool>(&mut self, max_steps: usize, pred: F) -> Result<usize, AutomatonError> {
+        let mut steps: usize = 0;
+        while steps < max_steps && pred(self.board, steps) {
+            self.advance()?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Advance the automaton until the board stops changing (a still life), up to a maximum number of time steps.
+    ///
+    /// A step that changes no cells means the board is stable, so evolution stops after it.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_steps`: The maximum number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps taken, including the step that left the board unchanged, or None if the board did not
+    /// stabilise within `max_steps` steps. Returns an error if the automaton could not be advanced.
+    pub fn evolve_until_stable(&mut self, max_steps: usize) -> Result<Option<usize>, AutomatonError> {
+        for step in 1..=max_steps {
+            if self.advance()? == 0 {
+                return Ok(Some(step));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advance the automaton until the board repeats one of its recent states, up to a maximum number of time steps.
+    ///
+    /// This detects oscillators as well as still lifes: a board that equals the board `k` steps earlier, for some `k` up
+    /// to `period`, is in a cycle of period `k`. A `period` of 1 only detects still lifes.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_steps`: The maximum number of time steps to advance the automaton.
+    ///
+    /// - `period`: The longest period of cycle to detect.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps taken, including the step that repeated an earlier board, or None if no cycle was found
+    /// within `max_steps` steps. Returns an error if the automaton could not be advanced.
+    pub fn evolve_until_cycle(&mut self, max_steps: usize, period: usize) -> Result<Option<usize>, AutomatonError> {
+        let mut recent: VecDeque<Board<S>> = VecDeque::with_capacity(period);
+        for step in 1..=max_steps {
+            recent.push_back(self.board.clone());
+            if recent.len() > period {
+                recent.pop_front();
+            }
+
+            self.advance()?;
+            if recent.contains(&*self.board) {
+                return Ok(Some(step));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advance the automaton until the board repeats any earlier board, up to a maximum number of time steps.
+    ///
+    /// Unlike `evolve_until_cycle`, cycles of any period are detected, because only the fingerprint of each board is
+    /// kept rather than a copy. A fingerprint collision between different boards would report a false cycle, though
+    /// this is very unlikely.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_steps`: The maximum number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps taken and the period of the cycle, or `None` if no board repeated within `max_steps`.
+    /// Returns an error if the automaton could not be advanced.
+    pub fn evolve_until_repeat(&mut self, max_steps: usize) -> Result<Option<(usize, usize)>, AutomatonError>
+    where
+        S: Hash,
+    {
+        let mut seen: HashMap<u64, usize> = HashMap::from([(self.board.fingerprint(), 0)]);
+        for step in 1..=max_steps {
+            self.advance()?;
+            if let Some(first_seen) = seen.insert(self.board.fingerprint(), step) {
+                return Ok(Some((step, step - first_seen)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advance the automaton by the given number of time steps, tracking the peak number of cells matching a predicate.
+    ///
+    /// The matching cells are counted on the initial board and after each step. If the peak is reached several times,
+    /// the earliest step is reported.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// - `pred`: A function that takes the state of a cell and returns whether it should be counted.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the peak count and the number of steps into the run at which it was first reached (0 for the initial
+    /// board), or an error if the automaton could not be advanced.
+    pub fn evolve_tracking_peak<F: Fn(S) -> bool>(&mut self, steps: usize, pred: F) -> Result<(usize, usize), AutomatonError> {
+        let mut peak: (usize, usize) = (self.board.count_where(|&state| pred(state)), 0);
+        for step in 1..=steps {
+            self.advance()?;
+            let count: usize = self.board.count_where(|&state| pred(state));
+            if count > peak.0 {
+                peak = (count, step);
+            }
+        }
+        Ok(peak)
+    }
+
+    /// Advance the automaton by the given number of time steps, counting the cells in each state after every step.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// The census of the initial board followed by the census after each step, so the result has `steps + 1` entries.
+    /// Each census maps every state present on the board to its number of cells, as in `Board::count_states`. Returns
+    /// an error if the automaton could not be advanced.
+    pub fn evolve_with_census(&mut self, steps: usize) -> Result<Vec<HashMap<S, usize>>, AutomatonError>
+    where
+        S: Eq + Hash,
+    {
+        let mut census: Vec<HashMap<S, usize>> = Vec::with_capacity(steps + 1);
+        census.push(self.board.count_states());
+        for _ in 0..steps {
+            self.advance()?;
+            census.push(self.board.count_states());
+        }
+        Ok(census)
+    }
+
+    /// Advance the automaton by the given number of time steps, calling a function after each step.
+    ///
+    /// The callback can observe each generation for metrics or logging, and can stop evolution early by returning
+    /// `ControlFlow::Break`.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The maximum number of time steps to advance the automaton.
+    ///
+    /// - `cb`: A function called after each step with the current time step of the automaton and the board.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps taken, or an error if the automaton could not be advanced.
+    pub fn evolve_with_callback<F: FnMut(usize, &Board<S>) -> ControlFlow<()>>(&mut self, steps: usize, mut cb: F) -> Result<usize, AutomatonError> {
+        for step in 1..=steps {
+            self.advance()?;
+            if cb(self.curr_time, self.board).is_break() {
+                return Ok(step);
+            }
+        }
+        Ok(steps)
+    }
+
     /// Advance the automaton by the given number of time steps and print the board at each time step.
     ///
     /// The automaton applies the rules to the board and increments the time step by the given number.
@@ -171,7 +880,7 @@ impl<'a, S: State> Automaton<'a, S> {
         &mut self,
         steps: usize,
         interval: u64,
-    ) -> Result<(), OutOfBoundsSetError> {
+    ) -> Result<(), AutomatonError> {
         for _ in 0..steps {
             self.advance()?;
             std::thread::sleep(std::time::Duration::from_millis(interval));
@@ -198,11 +907,412 @@ impl<'a, S: State> Automaton<'a, S> {
     /// # Type Parameters
     /// 
     /// - `S`: The type of state that each cell in the board can have. It must implement `Into<Colour>`.
-    pub fn visualise(&mut self, steps: usize, interval: u64) -> Result<(), OutOfBoundsSetError>
+    pub fn visualise(&mut self, steps: usize, interval: u64) -> Result<(), AutomatonError>
     where
         S: Into<Colour>,
     {
         simulate(self, steps, interval);
         Ok(())
     }
+
+    /// Visualise the automaton with a fading trail behind recently changed cells.
+    ///
+    /// Cells that changed on the current step are rendered in the trail colour, fading back to the colour of their
+    /// state over `trail_length` steps. This makes it easier to follow moving patterns such as gliders and ants.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// - `interval`: The number of milliseconds between each time step.
+    ///
+    /// - `trail`: The colour of the trail.
+    ///
+    /// - `trail_length`: The number of steps the trail takes to fade.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the automaton could not be advanced.
+    pub fn visualise_with_trail(&mut self, steps: usize, interval: u64, trail: Colour, trail_length: usize) -> Result<(), AutomatonError>
+    where
+        S: Into<Colour>,
+    {
+        simulate_with_trail(self, steps, interval, trail, trail_length);
+        Ok(())
+    }
+
+    /// Visualise the automaton with the colours of some states replaced.
+    ///
+    /// Each cell whose state has a colour in `colour_overrides` is rendered in the mapped colour instead, which makes
+    /// it easy to highlight a state of interest. The overrides only change the rendering, not the automaton, and can
+    /// be edited at runtime with the recolour control in the window.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// - `interval`: The number of milliseconds between each time step.
+    ///
+    /// - `colour_overrides`: A map from the colour of a state to the colour it is rendered in.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the automaton could not be advanced.
+    pub fn visualise_with_colour_overrides(&mut self, steps: usize, interval: u64, colour_overrides: HashMap<Colour, Colour>) -> Result<(), AutomatonError>
+    where
+        S: Into<Colour>,
+    {
+        simulate_with_colour_overrides(self, steps, interval, colour_overrides);
+        Ok(())
+    }
+
+    /// Advance the automaton by the given number of time steps and export the run to an animated GIF.
+    ///
+    /// The file contains one frame for the current board followed by one frame for each time step, so a run of
+    /// `steps` time steps produces `steps + 1` frames. Each cell is drawn as a square of `cell_px` by `cell_px`
+    /// pixels in the colour of its state. The animation loops forever. Available with the `gif` feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// - `path`: The path of the GIF file to write. An existing file is overwritten.
+    ///
+    /// - `cell_px`: The width and height of each cell in pixels.
+    ///
+    /// - `frame_delay_ms`: The number of milliseconds each frame is shown for, rounded down to the nearest 10ms.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the automaton could not be advanced, the image is larger than
+    /// 65535 pixels in either dimension, or the file could not be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_px` is zero.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have. It must implement `Into<Colour>`.
+    #[cfg(feature = "gif")]
+    pub fn export_gif<P: AsRef<std::path::Path>>(&mut self, steps: usize, path: P, cell_px: usize, frame_delay_ms: u64) -> std::io::Result<()>
+    where
+        S: Into<Colour>,
+    {
+        use std::io::{Error, ErrorKind, Write};
+
+        assert!(cell_px > 0, "Cell size must be at least one pixel");
+
+        let (board_width, board_height) = (self.board.width(), self.board.height());
+        let too_large = || Error::new(ErrorKind::InvalidInput, "GIF dimensions must not exceed 65535 pixels");
+        let width: u16 = board_width.checked_mul(cell_px).and_then(|w| u16::try_from(w).ok()).ok_or_else(too_large)?;
+        let height: u16 = board_height.checked_mul(cell_px).and_then(|h| u16::try_from(h).ok()).ok_or_else(too_large)?;
+        let delay: u16 = u16::try_from(frame_delay_ms / 10).unwrap_or(u16::MAX);
+
+        let mut frames: Vec<super::components::board::BoardRepresentation> = Vec::with_capacity(steps + 1);
+        frames.push(self.board.to_representation());
+        for _ in 0..steps {
+            self.advance().map_err(|error| Error::other(format!("{:?}", error)))?;
+            frames.push(self.board.to_representation());
+        }
+
+        // Use a single global palette when the run has few enough colours, which keeps frames exact and small
+        let mut palette: HashMap<Colour, u8> = HashMap::new();
+        for colour in frames.iter().flatten().flatten() {
+            if palette.len() > 256 {
+                break;
+            }
+            let next_index: usize = palette.len();
+            palette.entry(*colour).or_insert(next_index as u8);
+        }
+        let indexed: bool = palette.len() <= 256;
+        let mut global_palette: Vec<u8> = vec![0; palette.len() * 3];
+        if indexed {
+            for (colour, &index) in &palette {
+                let offset: usize = index as usize * 3;
+                global_palette[offset..offset + 3].copy_from_slice(&[colour.r, colour.g, colour.b]);
+            }
+        }
+
+        let file = std::fs::File::create(path)?;
+        let mut encoder = gif::Encoder::new(std::io::BufWriter::new(file), width, height, if indexed { &global_palette } else { &[] })
+            .map_err(Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(Error::other)?;
+
+        for representation in &frames {
+            let pixel_size: usize = if indexed { 1 } else { 3 };
+            let mut buffer: Vec<u8> = Vec::with_capacity(width as usize * height as usize * pixel_size);
+            for row in representation {
+                let mut scaled_row: Vec<u8> = Vec::with_capacity(width as usize * pixel_size);
+                for colour in row {
+                    for _ in 0..cell_px {
+                        if indexed {
+                            scaled_row.push(palette[colour]);
+                        } else {
+                            scaled_row.extend_from_slice(&[colour.r, colour.g, colour.b]);
+                        }
+                    }
+                }
+                for _ in 0..cell_px {
+                    buffer.extend_from_slice(&scaled_row);
+                }
+            }
+
+            let mut frame = if indexed {
+                gif::Frame { width, height, buffer: buffer.into(), ..gif::Frame::default() }
+            } else {
+                gif::Frame::from_rgb(width, height, &buffer)
+            };
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(Error::other)?;
+        }
+        encoder.into_inner()?.flush()
+    }
+}
+
+/// Visualise an automaton built on a randomly generated starting board.
+///
+/// A board is built by `make_board` from a random number generator seeded with `seed`, and an automaton is
+/// created on it with the rules returned by `make_rules`. The simulation is then run for the given number of
+/// steps and interval. The window includes a "Randomise" button that rebuilds the board and automaton from the
+/// next seed and restarts the simulation, so the same seed always reproduces the same run.
+///
+/// # Arguments
+///
+/// - `make_board`: A function that builds the starting board using the given random number generator.
+///
+/// - `make_rules`: A function that returns the rules of the automaton.
+///
+/// - `steps`: The number of time steps to advance the automaton.
+///
+/// - `interval`: The number of milliseconds between each time step.
+///
+/// - `seed`: The seed of the random number generator used for the first board.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have. It must implement `State` and `Into<Colour>`.
+pub fn visualise_random<S, B, R>(make_board: B, make_rules: R, steps: usize, interval: u64, seed: u64)
+where
+    S: State + Into<Colour>,
+    B: Fn(&mut StdRng) -> Board<S> + Send + Sync + 'static,
+    R: Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync + 'static,
+{
+    simulate_random(make_board, make_rules, steps, interval, seed);
+}
+
+/// Visualise an automaton on a starting board drawn by the user.
+///
+/// The window opens in an editing mode showing the given board, where clicking a cell replaces its state with
+/// the state returned by `toggle`. The "Run" button creates an automaton on the drawn board with the rules
+/// returned by `make_rules`, and runs the simulation for the given number of steps and interval.
+///
+/// # Arguments
+///
+/// - `board`: The starting board to draw on.
+///
+/// - `make_rules`: A function that returns the rules of the automaton.
+///
+/// - `steps`: The number of time steps to advance the automaton.
+///
+/// - `interval`: The number of milliseconds between each time step.
+///
+/// - `toggle`: A function that returns the state a clicked cell should take, given its current state. For
+///   two-state automata this swaps the states, and for others it can cycle through them.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have. It must implement `State` and `Into<Colour>`.
+pub fn visualise_editable<S, R, T>(board: Board<S>, make_rules: R, steps: usize, interval: u64, toggle: T)
+where
+    S: State + Into<Colour>,
+    R: Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync + 'static,
+    T: Fn(S) -> S + Send + Sync + 'static,
+{
+    simulate_editable(board, make_rules, steps, interval, toggle);
+}
+
+impl<'a, S: DiscreteState> Automaton<'a, S> {
+    /// Advance the automaton as a second-order (reversible) cellular automaton by the given number of time steps.
+    ///
+    /// At each step, the rules are applied to the current board and the result is combined with the previous
+    /// board using an exclusive-or, so that `next = rules(current) xor previous`. If no previous board has been
+    /// recorded yet, the current board is used as the previous board.
+    ///
+    /// Because the update can be inverted, calling `reverse` and evolving for the same number of steps recovers
+    /// the board before the first reversible step.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the automaton could not be advanced.
+    pub fn evolve_reversible(&mut self, steps: usize) -> Result<(), AutomatonError> {
+        for _ in 0..steps {
+            // The combination with the previous board can change cells the rules did not, so every cell is visited
+            self.wake_all_cells();
+            let current: Board<S> = self.board.clone();
+            self.apply_rules(None)?;
+            let previous: Board<S> = self.previous_board.take().unwrap_or_else(|| current.clone());
+            *self.board = self.board.xor_with(&previous);
+            self.previous_board = Some(current);
+            self.wake_all_cells();
+            self.curr_time += 1;
+            self.record_history();
+        }
+        Ok(())
+    }
+
+    /// Reverse the direction of time for second-order (reversible) evolution by swapping the current and previous boards.
+    ///
+    /// Does nothing if no previous board has been recorded.
+    pub fn reverse(&mut self) {
+        if let Some(previous) = self.previous_board.as_mut() {
+            std::mem::swap(self.board, previous);
+            self.wake_all_cells();
+        }
+    }
+}
+
+/// A builder for configuring an `Automaton` through chained method calls.
+///
+/// `Automaton::new` remains the simplest way to create an automaton with the default settings, while the builder
+/// gathers the optional settings in one place before the automaton is created.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+///
+/// # Fields
+///
+/// - `board`: A reference to the board of cells, which must be provided before building.
+/// - `rules`: The rules to apply to the board, in order.
+/// - `conflict_policy`: The policy for resolving deltas that write the same cell within a single time step.
+/// - `history`: Whether to record the board at every time step.
+/// - `rule_order_seed`: The seed used to shuffle the order of the rules at each time step, if enabled.
+/// - `seed`: The seed of the random number generators passed to rules, if set.
+///
+/// # Lifetime
+///
+/// - `'a`: The lifetime of the board.
+pub struct AutomatonBuilder<'a, S: State> {
+    board: Option<&'a mut Board<S>>,
+    rules: Vec<Box<dyn Rule<S>>>,
+    conflict_policy: ConflictPolicy,
+    history: bool,
+    rule_order_seed: Option<u64>,
+    seed: Option<u64>,
+}
+
+impl<S: State> Default for AutomatonBuilder<'_, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, S: State> AutomatonBuilder<'a, S> {
+    /// Create a new `AutomatonBuilder` with no board, no rules, and the default settings.
+    pub fn new() -> Self {
+        Self {
+            board: None,
+            rules: Vec::new(),
+            conflict_policy: ConflictPolicy::default(),
+            history: false,
+            rule_order_seed: None,
+            seed: None,
+        }
+    }
+
+    /// Set the board of cells for the automaton to evolve.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: A reference to the board of cells.
+    pub fn board(mut self, board: &'a mut Board<S>) -> Self {
+        self.board = Some(board);
+        self
+    }
+
+    /// Add a rule to apply to the board after the rules added so far.
+    ///
+    /// # Arguments
+    ///
+    /// - `rule`: The rule to add.
+    pub fn rule(mut self, rule: Box<dyn Rule<S>>) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add several rules to apply to the board after the rules added so far.
+    ///
+    /// # Arguments
+    ///
+    /// - `rules`: The rules to add, in order.
+    pub fn rules(mut self, rules: Vec<Box<dyn Rule<S>>>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// Set the policy for resolving deltas that write the same cell within a single time step.
+    ///
+    /// # Arguments
+    ///
+    /// - `conflict_policy`: The policy to use. The default is `ConflictPolicy::LastWins`.
+    pub fn conflict_policy(mut self, conflict_policy: ConflictPolicy) -> Self {
+        self.conflict_policy = conflict_policy;
+        self
+    }
+
+    /// Set whether to record the board at every time step, as with `Automaton::enable_history`.
+    ///
+    /// # Arguments
+    ///
+    /// - `history`: Whether to record the board. Recording is off by default.
+    pub fn with_history(mut self, history: bool) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Shuffle the order in which the rules are applied at every time step, as with `Automaton::enable_shuffled_rule_order`.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: The seed of the random number generator used to shuffle the rules.
+    pub fn shuffled_rule_order(mut self, seed: u64) -> Self {
+        self.rule_order_seed = Some(seed);
+        self
+    }
+
+    /// Seed the random number generators passed to rules, as with `Automaton::set_seed`.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed`: The seed of the run.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Build the automaton.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the configured automaton, or an error if no board was provided.
+    pub fn build(self) -> Result<Automaton<'a, S>, String> {
+        let board: &'a mut Board<S> = self.board.ok_or("An automaton cannot be built without a board")?;
+        let mut automaton: Automaton<'a, S> = Automaton::new(board, self.rules);
+        automaton.set_conflict_policy(self.conflict_policy);
+        if self.history {
+            automaton.enable_history();
+        }
+        if let Some(seed) = self.rule_order_seed {
+            automaton.enable_shuffled_rule_order(seed);
+        }
+        if let Some(seed) = self.seed {
+            automaton.set_seed(seed);
+        }
+        Ok(automaton)
+    }
 }
@@ -0,0 +1,149 @@
+use super::components::{
+    board::{Board, BoundaryCondition},
+    rule::BlockRule,
+    state::State,
+};
+use rayon::prelude::*;
+
+/// A struct that represents a block (Margolus) cellular automaton.
+///
+/// The board is partitioned into 2x2 blocks, and a block rule updates every block at once. On even time steps the
+/// blocks start at the origin, and on odd time steps they are offset by one cell in both directions, so information
+/// can cross the block boundaries of the previous step. Blocks on the edge of an offset partition wrap around the
+/// board, so only periodic boards with even dimensions are supported.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+///
+/// # Fields
+///
+/// - `board`: A reference to the board of cells.
+/// - `rule`: The block rule to apply to every block.
+/// - `curr_time`: The current time step of the automaton.
+///
+/// # Lifetime
+///
+/// - `'a`: The lifetime of the board.
+pub struct BlockAutomaton<'a, S: State> {
+    board: &'a mut Board<S>,
+    rule: Box<dyn BlockRule<S>>,
+    curr_time: usize,
+}
+
+impl<'a, S: State> BlockAutomaton<'a, S> {
+    /// Create a new `BlockAutomaton` with the given board and block rule.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: A reference to the board of cells.
+    ///
+    /// - `rule`: The block rule to apply to every block.
+    ///
+    /// # Returns
+    ///
+    /// A new `BlockAutomaton` with the given board and block rule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the board does not have a periodic boundary condition, or if its width or height is odd.
+    pub fn new(board: &'a mut Board<S>, rule: Box<dyn BlockRule<S>>) -> Self {
+        if !matches!(board.boundary_condition(), BoundaryCondition::Periodic) {
+            panic!("Block automata require a periodic boundary condition");
+        }
+        if !board.width().is_multiple_of(2) || !board.height().is_multiple_of(2) {
+            panic!("Block automata require even dimensions, but the board is {}x{}", board.width(), board.height());
+        }
+        Self { board, rule, curr_time: 0 }
+    }
+
+    /// Get the current time step of the automaton.
+    ///
+    /// # Returns
+    ///
+    /// The current time step of the automaton.
+    pub fn curr_time(&self) -> usize {
+        self.curr_time
+    }
+
+    /// Get the board of the automaton.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the board of the automaton.
+    pub fn board(&self) -> &Board<S> {
+        self.board
+    }
+
+    /// Apply a block rule to every block of the partition with the given parity.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: A reference to the board of cells.
+    ///
+    /// - `rule`: The block rule to apply to every block.
+    ///
+    /// - `parity`: The parity of the time step, which is also the offset of the partition.
+    fn apply_blocks(board: &mut Board<S>, rule: &dyn BlockRule<S>, parity: usize) {
+        let (width, height): (usize, usize) = (board.width(), board.height());
+        let cell = |x: usize, y: usize| ((parity + x) % width, (parity + y) % height);
+        let origins: Vec<(usize, usize)> = (0..height)
+            .step_by(2)
+            .flat_map(|y| (0..width).step_by(2).map(move |x| (x, y)))
+            .collect();
+
+        let snapshot: &Board<S> = board;
+        let blocks: Vec<[[S; 2]; 2]> = origins
+            .par_iter()
+            .map(|&(x, y)| {
+                let block: [[S; 2]; 2] = std::array::from_fn(|dy| {
+                    std::array::from_fn(|dx| {
+                        let (cx, cy): (usize, usize) = cell(x + dx, y + dy);
+                        snapshot.get(cx, cy).unwrap()
+                    })
+                });
+                rule.block_delta(block, parity)
+            })
+            .collect();
+
+        // The blocks of a partition are disjoint, so every cell is written exactly once
+        for (&(x, y), block) in origins.iter().zip(blocks) {
+            for (dy, row) in block.iter().enumerate() {
+                for (dx, &state) in row.iter().enumerate() {
+                    let (cx, cy): (usize, usize) = cell(x + dx, y + dy);
+                    board.set(cx, cy, state).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Advance the automaton by the given number of time steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance the automaton.
+    pub fn evolve(&mut self, steps: usize) {
+        for _ in 0..steps {
+            Self::apply_blocks(self.board, self.rule.as_ref(), self.curr_time % 2);
+            self.curr_time += 1;
+        }
+    }
+
+    /// Undo the given number of time steps using the inverse of the automaton's block rule.
+    ///
+    /// The steps are undone in reverse order, each on the partition it was taken on, so evolving and then evolving
+    /// backward by the same number of steps recovers the original board when `inverse` inverts the rule. Rules that
+    /// are their own inverse, such as block swaps, can pass a copy of themselves.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to undo. At most `curr_time` steps are undone.
+    ///
+    /// - `inverse`: The inverse of the automaton's block rule.
+    pub fn evolve_backward(&mut self, steps: usize, inverse: &dyn BlockRule<S>) {
+        for _ in 0..steps.min(self.curr_time) {
+            self.curr_time -= 1;
+            Self::apply_blocks(self.board, inverse, self.curr_time % 2);
+        }
+    }
+}
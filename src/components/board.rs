@@ -1,17 +1,25 @@
 use super::error::OutOfBoundsSetError;
-use super::state::State;
+use super::rule::Delta;
+use super::state::common_states::{AntDirection, GameOfLifeState};
+use super::state::{DiscreteState, State};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rayon::prelude::*;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 /// The type of boundary condition to use for the board, which determines how to handle cells at the edges of the board.
 ///
 /// The boundary conditions are:
 /// - Periodic: The board wraps around at the edges.
 /// - Fixed: The cells at the edges are fixed with a given state.
+/// - Reflective: Coordinates past an edge are mirrored back into the board, so x = -1 maps to x = 0 and x = width maps to x = width - 1.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoundaryCondition<S: State> {
     Periodic,
     Fixed(S),
+    Reflective,
 }
 
 impl<S: State> std::fmt::Display for BoundaryCondition<S> {
@@ -19,10 +27,94 @@ impl<S: State> std::fmt::Display for BoundaryCondition<S> {
         match self {
             BoundaryCondition::Periodic => write!(f, "Periodic"),
             BoundaryCondition::Fixed(s) => write!(f, "Fixed({:?})", s),
+            BoundaryCondition::Reflective => write!(f, "Reflective"),
         }
     }
 }
 
+/// The policy for writes outside a board with a fixed boundary condition.
+///
+/// Writes outside periodic and reflective boards always land on a cell of the board, so the policy only applies to
+/// fixed boards. The policies are:
+/// - Error: The write fails with an `OutOfBoundsSetError`. This is the default.
+/// - Ignore: The write succeeds without changing the board, which suits rules that intentionally write past the
+///   edges, such as ants walking off the board.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeWritePolicy {
+    #[default]
+    Error,
+    Ignore,
+}
+
+/// Mirror an index into the range `0..size`, as used by the reflective boundary condition.
+///
+/// Indices past an edge are reflected back across that edge, so -1 maps to 0 and `size` maps to `size - 1`.
+///
+/// # Arguments
+///
+/// - `index`: The index to mirror, which may be negative or past the end of the range.
+/// - `size`: The size of the range.
+///
+/// # Returns
+///
+/// The mirrored index, which is always in the range `0..size`.
+pub(crate) fn reflect(index: isize, size: usize) -> usize {
+    let period: isize = 2 * size as isize;
+    let m: isize = index.rem_euclid(period);
+    if m < size as isize {
+        m as usize
+    } else {
+        (period - 1 - m) as usize
+    }
+}
+
+/// An axis of the board, used to describe the direction of a path spanning the board.
+///
+/// The axes are:
+/// - Horizontal: From the left edge to the right edge.
+/// - Vertical: From the top edge to the bottom edge.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+/// A rectangular region of a board.
+///
+/// # Fields
+///
+/// - `x`: The x-coordinate of the top-left cell of the region.
+/// - `y`: The y-coordinate of the top-left cell of the region.
+/// - `width`: The width of the region.
+/// - `height`: The height of the region.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    /// Create a new `Rect` with the given top-left corner and dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the top-left cell of the region.
+    /// - `y`: The y-coordinate of the top-left cell of the region.
+    /// - `width`: The width of the region.
+    /// - `height`: The height of the region.
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Check whether the region contains the cell at the given coordinates.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x >= self.x && x - self.x < self.width && y >= self.y && y - self.y < self.height
+    }
+}
+
 /// A struct that represents a board of cells in a cellular automaton.
 ///
 /// The board contains a vector of cells and the dimensions of the board.
@@ -37,11 +129,15 @@ impl<S: State> std::fmt::Display for BoundaryCondition<S> {
 /// - `cells`: A vector of the cells in the board.
 /// - `dim`: A tuple containing the width and height of the board.
 /// - `boundary_condition`: The boundary condition of the board.
+/// - `edge_write_policy`: The policy for writes outside a board with a fixed boundary condition.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Board<S: State> {
     cells: Vec<S>,
     dim: (usize, usize),
     boundary_condition: BoundaryCondition<S>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    edge_write_policy: EdgeWritePolicy,
 }
 
 impl<S: State> Board<S> {
@@ -68,6 +164,139 @@ impl<S: State> Board<S> {
             dim: (width, height),
             cells,
             boundary_condition,
+            edge_write_policy: EdgeWritePolicy::default(),
+        }
+    }
+
+    /// Create a new `Board` whose cells are given by a function of their coordinates.
+    ///
+    /// The cells are written directly into the board in row-major order, without building an intermediate 2D vector.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: The width of the board.
+    /// - `height`: The height of the board.
+    /// - `boundary_condition`: The boundary condition of the board.
+    /// - `f`: A function that returns the state of the cell at `(x, y)`.
+    pub fn from_fn<F: Fn(usize, usize) -> S>(
+        width: usize,
+        height: usize,
+        boundary_condition: BoundaryCondition<S>,
+        f: F,
+    ) -> Self {
+        let mut cells: Vec<S> = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+
+        Self {
+            dim: (width, height),
+            cells,
+            boundary_condition,
+            edge_write_policy: EdgeWritePolicy::default(),
+        }
+    }
+
+    /// Create a new symmetric `Board` by mirroring a top-left quadrant horizontally and vertically.
+    ///
+    /// The board is twice as wide and twice as tall as the quadrant. The top-right quadrant is the mirror image of
+    /// the given quadrant, and the bottom half is the mirror image of the top half.
+    ///
+    /// # Arguments
+    ///
+    /// - `quadrant`: The states of the top-left quadrant of the board as a 2D vector.
+    /// - `boundary_condition`: The boundary condition of the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quadrant is empty or its rows have different lengths.
+    pub fn from_quadrant(quadrant: Vec<Vec<S>>, boundary_condition: BoundaryCondition<S>) -> Self {
+        let (quadrant_width, quadrant_height): (usize, usize) = (quadrant[0].len(), quadrant.len());
+        if quadrant_width == 0 || quadrant.iter().any(|row| row.len() != quadrant_width) {
+            panic!("The quadrant must be a non-empty rectangle");
+        }
+
+        let mirror = |i: usize, size: usize| if i < size { i } else { 2 * size - 1 - i };
+        Self::from_fn(2 * quadrant_width, 2 * quadrant_height, boundary_condition, |x, y| {
+            quadrant[mirror(y, quadrant_height)][mirror(x, quadrant_width)]
+        })
+    }
+
+    /// Create a new `Board` whose cells are filled by a function of a random number generator.
+    ///
+    /// The cells are filled one at a time in row-major order, calling `f` once per cell. The board is therefore fully
+    /// determined by the state of `rng`: a seeded generator such as `StdRng::seed_from_u64` always produces the same
+    /// board for the same seed, dimensions and `f` (for a given version of the `rand` crate).
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: The width of the board.
+    /// - `height`: The height of the board.
+    /// - `boundary_condition`: The boundary condition of the board.
+    /// - `rng`: The random number generator to draw from.
+    /// - `f`: A function that draws the state of a cell from the random number generator.
+    pub fn random_with<R: Rng, F: FnMut(&mut R) -> S>(
+        width: usize,
+        height: usize,
+        boundary_condition: BoundaryCondition<S>,
+        rng: &mut R,
+        mut f: F,
+    ) -> Self {
+        let cells: Vec<S> = (0..width * height).map(|_| f(rng)).collect();
+        Self {
+            dim: (width, height),
+            cells,
+            boundary_condition,
+            edge_write_policy: EdgeWritePolicy::default(),
+        }
+    }
+
+    /// Create a new `Board` with exactly `count` cells in the `alive` state, placed at random distinct positions.
+    ///
+    /// Unlike drawing each cell independently, this guarantees the density of the board exactly: to reach a density
+    /// `d`, pass `(d * (width * height) as f64).floor() as usize` as the count. The positions are drawn from a `StdRng`
+    /// seeded with `seed`, so the same seed always produces the same board.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: The width of the board.
+    /// - `height`: The height of the board.
+    /// - `boundary_condition`: The boundary condition of the board.
+    /// - `alive`: The state of the selected cells.
+    /// - `dead`: The state of every other cell.
+    /// - `count`: The number of cells to set to `alive`.
+    /// - `seed`: The seed of the random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the number of cells on the board.
+    pub fn from_exact_density(
+        width: usize,
+        height: usize,
+        boundary_condition: BoundaryCondition<S>,
+        alive: S,
+        dead: S,
+        count: usize,
+        seed: u64,
+    ) -> Self {
+        let area: usize = width * height;
+        if count > area {
+            panic!("Cannot place {} cells on a board of {} cells", count, area);
+        }
+
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        let mut cells: Vec<S> = vec![dead; area];
+        for index in rand::seq::index::sample(&mut rng, area, count) {
+            cells[index] = alive;
+        }
+
+        Self {
+            dim: (width, height),
+            cells,
+            boundary_condition,
+            edge_write_policy: EdgeWritePolicy::default(),
         }
     }
 
@@ -81,11 +310,111 @@ impl<S: State> Board<S> {
         self.dim.1
     }
 
+    /// Get the dimensions of the board.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the form `(width, height)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.dim
+    }
+
+    /// Get the cells of the board as a flat slice.
+    ///
+    /// The cells are stored in row-major order, so the cell at `(x, y)` is at index `y * width + x`. This ordering is
+    /// part of the board's contract and is safe to rely on, for example when handing the cells to another library
+    /// without copying them.
+    ///
+    /// # Returns
+    ///
+    /// A slice of `width * height` cells in row-major order.
+    pub fn as_slice(&self) -> &[S] {
+        &self.cells
+    }
+
     /// Get the boundary condition of the board.
     pub fn boundary_condition(&self) -> BoundaryCondition<S> {
         self.boundary_condition.clone()
     }
 
+    /// Get the signed offset from one cell to another, taking the boundary condition into account.
+    ///
+    /// Under periodic boundaries, the offset is the shortest path around the torus along each axis, so moving from
+    /// column 0 to column `width - 1` gives -1 rather than `width - 1`. When both directions are equally short, the
+    /// positive offset is returned. Under fixed and reflective boundaries, the offset is the plain difference.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: The coordinates of the starting cell.
+    /// - `to`: The coordinates of the target cell.
+    ///
+    /// # Returns
+    ///
+    /// The offset `(dx, dy)` such that moving by it from `from` reaches `to`.
+    pub fn wrapped_delta(&self, from: (usize, usize), to: (usize, usize)) -> (isize, isize) {
+        let dx: isize = to.0 as isize - from.0 as isize;
+        let dy: isize = to.1 as isize - from.1 as isize;
+        match self.boundary_condition {
+            BoundaryCondition::Periodic => {
+                let shortest = |d: isize, size: usize| {
+                    let d: isize = d.rem_euclid(size as isize);
+                    if d > size as isize / 2 { d - size as isize } else { d }
+                };
+                (shortest(dx, self.dim.0), shortest(dy, self.dim.1))
+            }
+            BoundaryCondition::Fixed(_) | BoundaryCondition::Reflective => (dx, dy),
+        }
+    }
+
+    /// Map possibly out-of-bounds coordinates onto the board according to the boundary condition.
+    ///
+    /// Under periodic boundaries, the coordinates wrap around; under reflective boundaries, they are mirrored back
+    /// into the board; under fixed boundaries, coordinates outside the board have no cell on it.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate, which may be negative or past the right edge.
+    /// - `y`: The y-coordinate, which may be negative or past the bottom edge.
+    ///
+    /// # Returns
+    ///
+    /// The coordinates of the cell on the board, or None if the coordinates are outside a board with fixed boundaries.
+    pub fn normalise_coord(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height): (isize, isize) = (self.dim.0 as isize, self.dim.1 as isize);
+        match self.boundary_condition {
+            BoundaryCondition::Periodic => Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)),
+            BoundaryCondition::Reflective => Some((reflect(x, self.dim.0), reflect(y, self.dim.1))),
+            BoundaryCondition::Fixed(_) => match (0..width).contains(&x) && (0..height).contains(&y) {
+                true => Some((x as usize, y as usize)),
+                false => None,
+            },
+        }
+    }
+
+    /// Get the coordinates of the cell next to a cell in the given direction, according to the boundary condition.
+    ///
+    /// Up is towards row 0 and left is towards column 0. Steps off an edge wrap around periodic boards, are mirrored
+    /// back into reflective boards, and leave fixed boards.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `direction`: The direction of the neighbour.
+    ///
+    /// # Returns
+    ///
+    /// The coordinates of the neighbour, or None if it is outside a board with fixed boundaries.
+    pub fn neighbour(&self, x: usize, y: usize, direction: AntDirection) -> Option<(usize, usize)> {
+        let (x, y): (isize, isize) = (x as isize, y as isize);
+        match direction {
+            AntDirection::Up => self.normalise_coord(x, y - 1),
+            AntDirection::Right => self.normalise_coord(x + 1, y),
+            AntDirection::Down => self.normalise_coord(x, y + 1),
+            AntDirection::Left => self.normalise_coord(x - 1, y),
+        }
+    }
+
     /// Get the state of a cell on the board.
     ///
     /// # Arguments
@@ -106,7 +435,83 @@ impl<S: State> Board<S> {
         }
     }
 
-    /// Set the state of a cell on the board. Wraps around the edges if the boundary condition is periodic.
+    /// Get a mutable reference to the state of a cell on the board, to change it in place.
+    ///
+    /// Coordinates are resolved in the same way as `set`: they wrap around the edges if the boundary condition is
+    /// periodic and mirror back into the board if it is reflective.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    ///
+    /// - `y`: The y-coordinate of the cell.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the state of the cell, or None if the coordinates are out of bounds for a fixed boundary condition.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut S> {
+        let index: usize = self.write_index(x, y).ok()?;
+        self.cells.get_mut(index)
+    }
+
+    /// Get the states of several cells on the board at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `coords`: A slice of `(x, y)` coordinates of the cells.
+    ///
+    /// # Returns
+    ///
+    /// The states of the cells in the same order as `coords`, with None for coordinates that are out of bounds.
+    pub fn get_many(&self, coords: &[(usize, usize)]) -> Vec<Option<S>> {
+        coords.iter().map(|&(x, y)| self.get(x, y)).collect()
+    }
+
+    /// Check whether any cell on the board satisfies a predicate, stopping at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate`: A function that takes the state of a cell and returns whether it matches.
+    pub fn any_match<F: Fn(S) -> bool>(&self, predicate: F) -> bool {
+        self.cells.iter().any(|&state| predicate(state))
+    }
+
+    /// Check whether no cell on the board satisfies a predicate, stopping at the first match.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate`: A function that takes the state of a cell and returns whether it matches.
+    pub fn none_match<F: Fn(S) -> bool>(&self, predicate: F) -> bool {
+        !self.any_match(predicate)
+    }
+
+    /// Count the number of cells in each state on the board.
+    ///
+    /// # Returns
+    ///
+    /// A map from each state present on the board to the number of cells in that state. States with no cells are not included.
+    pub fn count_states(&self) -> HashMap<S, usize>
+    where
+        S: Eq + Hash,
+    {
+        let mut counts: HashMap<S, usize> = HashMap::new();
+        for &state in &self.cells {
+            *counts.entry(state).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Count the number of cells on the board that satisfy a predicate.
+    ///
+    /// # Arguments
+    ///
+    /// - `predicate`: A function that takes a reference to the state of a cell and returns whether it should be counted.
+    pub fn count_where<F: Fn(&S) -> bool>(&self, predicate: F) -> usize {
+        self.cells.iter().filter(|state| predicate(state)).count()
+    }
+
+    /// Set the state of a cell on the board. Wraps around the edges if the boundary condition is periodic, and mirrors
+    /// back into the board if it is reflective.
     ///
     /// # Arguments
     ///
@@ -116,29 +521,310 @@ impl<S: State> Board<S> {
     ///
     /// # Returns
     ///
-    /// An error if the coordinates are out of bounds for a fixed boundary condition.
+    /// An error if the coordinates are out of bounds for a fixed boundary condition, unless the edge write policy is
+    /// `EdgeWritePolicy::Ignore`, in which case the write is skipped.
     #[inline(always)]
     pub fn set(&mut self, x: usize, y: usize, state: S) -> Result<(), OutOfBoundsSetError> {
+        match self.write_index(x, y) {
+            Ok(index) => self.cells[index] = state,
+            Err(_) if self.edge_write_policy == EdgeWritePolicy::Ignore => {}
+            Err(error) => return Err(error),
+        }
+        Ok(())
+    }
+
+    /// Set the policy for writes outside a board with a fixed boundary condition.
+    ///
+    /// # Arguments
+    ///
+    /// - `edge_write_policy`: The policy to use. The default is `EdgeWritePolicy::Error`.
+    pub fn set_edge_write_policy(&mut self, edge_write_policy: EdgeWritePolicy) {
+        self.edge_write_policy = edge_write_policy;
+    }
+
+    /// Get the policy for writes outside a board with a fixed boundary condition.
+    pub fn edge_write_policy(&self) -> EdgeWritePolicy {
+        self.edge_write_policy
+    }
+
+    /// Swap the states of two cells on the board. Each coordinate is wrapped or mirrored as in `set`.
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: The coordinates of the first cell.
+    /// - `b`: The coordinates of the second cell.
+    ///
+    /// # Returns
+    ///
+    /// An error if either coordinate is out of bounds for a fixed boundary condition, in which case the board is unchanged.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> Result<(), OutOfBoundsSetError> {
+        let index_a: usize = self.write_index(a.0, a.1)?;
+        let index_b: usize = self.write_index(b.0, b.1)?;
+        self.cells.swap(index_a, index_b);
+        Ok(())
+    }
+
+    /// Apply a batch of deltas to the board in order, reporting the deltas that could not be applied.
+    ///
+    /// Each delta is applied as with `set`, so deltas only fail for coordinates outside a board with fixed boundaries
+    /// and the default edge write policy. Deltas skipped by `EdgeWritePolicy::Ignore` count as applied.
+    /// Failing deltas are skipped rather than stopping the batch.
+    ///
+    /// # Arguments
+    ///
+    /// - `deltas`: The deltas to apply, in order.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the number of deltas applied and the errors of the deltas that were rejected, in order.
+    pub fn apply_deltas(&mut self, deltas: &[Delta<S>]) -> (usize, Vec<OutOfBoundsSetError>) {
+        let mut applied: usize = 0;
+        let mut rejected: Vec<OutOfBoundsSetError> = Vec::new();
+        for delta in deltas {
+            match delta.apply(self) {
+                Ok(()) => applied += 1,
+                Err(error) => rejected.push(error),
+            }
+        }
+        (applied, rejected)
+    }
+
+    /// Write a pattern onto the board with its top-left cell at the given coordinates.
+    ///
+    /// Each cell of the pattern is written as with `set`, so the pattern wraps around the edges of a periodic board
+    /// and mirrors back into a reflective one. Rows of the pattern may have different lengths.
+    ///
+    /// # Arguments
+    ///
+    /// - `x0`: The x-coordinate of the top-left cell of the pattern.
+    /// - `y0`: The y-coordinate of the top-left cell of the pattern.
+    /// - `pattern`: The states of the pattern, indexed by row and then by column.
+    ///
+    /// # Returns
+    ///
+    /// An error for the first cell of the pattern that falls outside a board with a fixed boundary condition, in
+    /// which case the board is left unchanged. With `EdgeWritePolicy::Ignore`, those cells are skipped instead.
+    pub fn stamp(&mut self, x0: usize, y0: usize, pattern: &[Vec<S>]) -> Result<(), OutOfBoundsSetError> {
+        let cells = || {
+            pattern
+                .iter()
+                .enumerate()
+                .flat_map(|(dy, row)| row.iter().enumerate().map(move |(dx, &state)| (x0 + dx, y0 + dy, state)))
+        };
+
+        if self.edge_write_policy == EdgeWritePolicy::Error {
+            if let Some(error) = cells().find_map(|(x, y, _)| self.write_index(x, y).err()) {
+                return Err(error);
+            }
+        }
+        cells().try_for_each(|(x, y, state)| self.set(x, y, state))
+    }
+
+    /// Get the index in `cells` written to by a `set` at the given coordinates, honouring the boundary condition.
+    #[inline(always)]
+    pub(crate) fn write_index(&self, x: usize, y: usize) -> Result<usize, OutOfBoundsSetError> {
         match self.boundary_condition {
             BoundaryCondition::Periodic => {
                 let x: usize = x % self.dim.0;
                 let y: usize = y % self.dim.1;
-                self.cells[y * self.dim.0 + x] = state;
+                Ok(y * self.dim.0 + x)
             }
             BoundaryCondition::Fixed(_fixed_state) => {
                 if x < self.dim.0 && y < self.dim.1 {
-                    self.cells[y * self.dim.0 + x] = state;
+                    Ok(y * self.dim.0 + x)
                 } else {
-                    return Err(OutOfBoundsSetError {
+                    Err(OutOfBoundsSetError {
                         x,
                         y,
                         width: self.dim.0,
                         height: self.dim.1,
-                    });
+                    })
                 }
             }
+            BoundaryCondition::Reflective => {
+                let x: usize = reflect(x as isize, self.dim.0);
+                let y: usize = reflect(y as isize, self.dim.1);
+                Ok(y * self.dim.0 + x)
+            }
         }
-        Ok(())
+    }
+
+    /// Check whether two boards have the same dimensions and cells, ignoring their boundary conditions and edge write
+    /// policies.
+    ///
+    /// Unlike `==`, which also compares the boundary condition and the edge write policy, this treats boards that
+    /// differ only in how their edges are handled as equal, which is usually what assertions about the cells of a
+    /// board mean.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The board to compare with.
+    ///
+    /// # Returns
+    ///
+    /// Whether the boards have the same width, height and cell states.
+    pub fn cells_eq(&self, other: &Board<S>) -> bool {
+        self.dim == other.dim && self.cells == other.cells
+    }
+
+    /// Compute a fingerprint of the dimensions and cells of the board, for cheaply detecting repeated boards.
+    ///
+    /// Boards with the same dimensions and cells, as compared by `cells_eq`, always have the same fingerprint. The
+    /// hash is deterministic within a build, so fingerprints can be compared across runs of the same program but
+    /// should not be persisted across versions of the standard library. Different boards can collide, though this is
+    /// very unlikely.
+    ///
+    /// # Returns
+    ///
+    /// A 64-bit hash of the width, height and cell states of the board.
+    pub fn fingerprint(&self) -> u64
+    where
+        S: Hash,
+    {
+        let mut hasher: DefaultHasher = DefaultHasher::new();
+        self.dim.hash(&mut hasher);
+        self.cells.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Get the number of cells the board can hold without reallocating.
+    ///
+    /// This is at least `width * height`, and may be larger if the board was built from an iterator of unknown length.
+    pub fn capacity(&self) -> usize {
+        self.cells.capacity()
+    }
+
+    /// Shrink the memory allocated for the cells of the board to fit its current size.
+    ///
+    /// Boards keep any excess capacity they were created with, so this is useful to release memory held by boards
+    /// that are kept around for long runs.
+    pub fn shrink_to_fit(&mut self) {
+        self.cells.shrink_to_fit();
+    }
+
+    /// Resize the board, keeping the cells that still fit at the same coordinates.
+    ///
+    /// Cells outside the new dimensions are dropped, and newly exposed cells are filled with `fill`. A `Neighbourhood`
+    /// shared with the board stays correct after a resize, since its cache is invalidated whenever the dimensions of
+    /// the board it is used with change.
+    ///
+    /// # Arguments
+    ///
+    /// - `new_width`: The new width of the board.
+    /// - `new_height`: The new height of the board.
+    /// - `fill`: The state of the newly exposed cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new width or height is zero.
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: S) {
+        if new_width == 0 || new_height == 0 {
+            panic!("Board dimensions must be non-zero");
+        }
+
+        let (width, height) = self.dim;
+        let mut cells: Vec<S> = Vec::with_capacity(new_width * new_height);
+        for y in 0..new_height {
+            for x in 0..new_width {
+                match x < width && y < height {
+                    true => cells.push(self.cells[y * width + x]),
+                    false => cells.push(fill),
+                }
+            }
+        }
+
+        self.cells = cells;
+        self.dim = (new_width, new_height);
+    }
+
+    /// Copy a rectangular window of the board into a new board with the same boundary condition.
+    ///
+    /// # Arguments
+    ///
+    /// - `x0`: The x-coordinate of the top-left cell of the window.
+    /// - `y0`: The y-coordinate of the top-left cell of the window.
+    /// - `w`: The width of the window.
+    /// - `h`: The height of the window.
+    ///
+    /// # Returns
+    ///
+    /// A board containing the cells `[x0, x0 + w) x [y0, y0 + h)`, or an error for the bottom-right cell of the window
+    /// if the window extends past the edge of the board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width or height of the window is zero.
+    pub fn subregion(&self, x0: usize, y0: usize, w: usize, h: usize) -> Result<Board<S>, OutOfBoundsSetError> {
+        if w == 0 || h == 0 {
+            panic!("Subregion dimensions must be non-zero");
+        }
+        if x0 + w > self.dim.0 || y0 + h > self.dim.1 {
+            return Err(OutOfBoundsSetError {
+                x: x0 + w - 1,
+                y: y0 + h - 1,
+                width: self.dim.0,
+                height: self.dim.1,
+            });
+        }
+
+        let mut cells: Vec<S> = Vec::with_capacity(w * h);
+        for y in y0..y0 + h {
+            let start: usize = y * self.dim.0 + x0;
+            cells.extend_from_slice(&self.cells[start..start + w]);
+        }
+
+        Ok(Self {
+            dim: (w, h),
+            cells,
+            boundary_condition: self.boundary_condition.clone(),
+            edge_write_policy: self.edge_write_policy,
+        })
+    }
+
+    /// Pad the board with a halo (ghost border) of the given width, filled with the given state.
+    ///
+    /// Together with `subregion` and `strip_halo`, this allows a board to be split into tiles that are evolved
+    /// separately: each tile is evolved with a halo as wide as the radius of the rules' neighbourhoods, and the halo
+    /// is stripped afterwards. A tile cut from a larger board with its halo included (by widening the window passed to
+    /// `subregion`) has a halo of real neighbours, while `with_halo` is for tiles on the edge of a fixed board.
+    ///
+    /// # Arguments
+    ///
+    /// - `radius`: The width of the halo on each side.
+    /// - `fill`: The state of the cells in the halo.
+    ///
+    /// # Returns
+    ///
+    /// A new board with the same boundary condition, `2 * radius` cells wider and taller, with this board in the middle.
+    pub fn with_halo(&self, radius: usize, fill: S) -> Board<S> {
+        let mut padded: Board<S> = Board::from_fn(self.dim.0 + 2 * radius, self.dim.1 + 2 * radius, self.boundary_condition.clone(), |x, y| {
+            match (x.checked_sub(radius), y.checked_sub(radius)) {
+                (Some(x), Some(y)) => self.get(x, y).unwrap_or(fill),
+                _ => fill,
+            }
+        });
+        padded.edge_write_policy = self.edge_write_policy;
+        padded
+    }
+
+    /// Remove a halo of the given width from the edges of the board, recovering the interior.
+    ///
+    /// # Arguments
+    ///
+    /// - `radius`: The width of the halo on each side.
+    ///
+    /// # Returns
+    ///
+    /// A new board with the same boundary condition, without the outer `radius` cells on each side.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the board is not more than `2 * radius` cells wide and tall, as there would be no interior left.
+    pub fn strip_halo(&self, radius: usize) -> Board<S> {
+        if self.dim.0 <= 2 * radius || self.dim.1 <= 2 * radius {
+            panic!("A {}x{} board has no interior inside a halo of width {}", self.dim.0, self.dim.1, radius);
+        }
+        self.subregion(radius, radius, self.dim.0 - 2 * radius, self.dim.1 - 2 * radius).unwrap()
     }
 
     /// Get an iterator over the coordinates of the board.
@@ -149,12 +835,171 @@ impl<S: State> Board<S> {
     ///
     /// The iterator yields tuples of the form `(x, y)`.
     pub fn iter_coords(&self) -> IterCoords {
-        IterCoords {
-            x: 0,
-            y: 0,
-            width: self.dim.0,
-            height: self.dim.1,
+        IterCoords::new(self.dim.0, self.dim.1)
+    }
+
+    /// Get an iterator over the rows of the board.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the rows of the board from top to bottom, each a slice of the cells from left to right.
+    /// The rows borrow the board's storage, so no cells are copied.
+    pub fn rows(&self) -> impl Iterator<Item = &[S]> {
+        // A board without columns has no cells, and `chunks_exact` cannot take a chunk size of 0
+        self.cells.chunks_exact(self.dim.0.max(1))
+    }
+
+    /// Get an iterator over the columns of the board.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the columns of the board from left to right, each a vector of the cells from top to bottom.
+    /// Columns are not contiguous in the board's storage, so each column is gathered into a new vector.
+    pub fn columns(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        (0..self.dim.0).map(move |x| self.cells.iter().skip(x).step_by(self.dim.0).copied().collect())
+    }
+
+    /// Combine the board cell-by-cell with another board using an exclusive-or.
+    ///
+    /// This is the building block of second-order (reversible) cellular automata, where the next board is the
+    /// result of the rules combined with the previous board.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The board to combine with.
+    ///
+    /// # Returns
+    ///
+    /// A new board with the boundary condition of `self`, where each cell is `self.xor(other)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the boards have different dimensions.
+    pub fn xor_with(&self, other: &Board<S>) -> Board<S>
+    where
+        S: DiscreteState,
+    {
+        if self.dim != other.dim {
+            panic!("Cannot combine boards of size {:?} and {:?}", self.dim, other.dim);
+        }
+
+        Self {
+            cells: self
+                .cells
+                .iter()
+                .zip(other.cells.iter())
+                .map(|(&a, &b)| a.xor(b))
+                .collect(),
+            dim: self.dim,
+            boundary_condition: self.boundary_condition.clone(),
+            edge_write_policy: self.edge_write_policy,
+        }
+    }
+
+    /// Check whether the active cells of the board percolate along the given axis.
+    ///
+    /// The board percolates if a path of 4-connected (orthogonally adjacent) active cells spans from one edge of
+    /// the board to the opposite edge. Paths do not wrap around the edges, even under a periodic boundary condition.
+    ///
+    /// # Arguments
+    ///
+    /// - `is_active`: A function that returns true if a cell with the given state is active.
+    /// - `direction`: The axis to check: `Vertical` for top-to-bottom, `Horizontal` for left-to-right.
+    ///
+    /// # Returns
+    ///
+    /// True if a connected path of active cells spans the board along the given axis.
+    pub fn percolates<F: Fn(S) -> bool>(&self, is_active: F, direction: Axis) -> bool {
+        let (width, height) = self.dim;
+        let active: Vec<bool> = self.cells.iter().map(|&cell| is_active(cell)).collect();
+        let mut visited: Vec<bool> = vec![false; width * height];
+
+        // Flood fill from every active cell on the starting edge
+        let mut stack: Vec<(usize, usize)> = match direction {
+            Axis::Vertical => (0..width).map(|x| (x, 0)).collect(),
+            Axis::Horizontal => (0..height).map(|y| (0, y)).collect(),
+        };
+        stack.retain(|&(x, y)| active[y * width + x]);
+        stack.iter().for_each(|&(x, y)| visited[y * width + x] = true);
+
+        while let Some((x, y)) = stack.pop() {
+            let reached_end: bool = match direction {
+                Axis::Vertical => y == height - 1,
+                Axis::Horizontal => x == width - 1,
+            };
+            if reached_end {
+                return true;
+            }
+
+            let neighbours: [(usize, usize); 4] = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbours {
+                if nx < width && ny < height && active[ny * width + nx] && !visited[ny * width + nx] {
+                    visited[ny * width + nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
         }
+
+        false
+    }
+
+    /// Get a compact textual representation of the board without borders.
+    ///
+    /// Each cell is printed using its glyph (see `State::display_glyph`), or its `Debug` representation if it has no
+    /// glyph, right-aligned to the widest cell, with cells separated by a single space and rows separated by newlines.
+    /// If every cell is a single character wide, the width collapses to 1 and the separators are dropped, so each row
+    /// prints as a contiguous line of glyphs.
+    ///
+    /// # Returns
+    ///
+    /// A string containing one line per row of the board.
+    pub fn display_compact(&self) -> String {
+        if self.dim.0 == 0 {
+            return String::new();
+        }
+
+        let cells: Vec<String> = self.cells.iter().map(cell_text).collect();
+        let max_width: usize = cells.iter().map(|cell| cell.len()).max().unwrap_or(1);
+        let separator: &str = if max_width == 1 { "" } else { " " };
+
+        let mut output: String = String::with_capacity((max_width + separator.len()) * cells.len() + self.dim.1);
+        for row in cells.chunks(self.dim.0) {
+            let line: Vec<String> = row
+                .iter()
+                .map(|cell| format!("{:>width$}", cell, width = max_width))
+                .collect();
+            output.push_str(&line.join(separator));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Render the board as a compact grid of single characters, one per cell.
+    ///
+    /// Unlike `display_compact`, the glyph for each state is chosen by the caller, so large boards stay readable in a
+    /// terminal and renders are easy to compare in tests.
+    ///
+    /// # Arguments
+    ///
+    /// - `glyph`: A function that returns the character to draw for a state.
+    ///
+    /// # Returns
+    ///
+    /// A string containing one line per row of the board, with rows separated by newlines and no trailing newline.
+    pub fn render_ascii(&self, glyph: impl Fn(&S) -> char) -> String {
+        let mut output: String = String::with_capacity(self.cells.len() + self.dim.1);
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                output.push('\n');
+            }
+            output.extend(row.iter().map(&glyph));
+        }
+        output
     }
 
     /// Get a representation of the board as a 2D vector of colours.
@@ -169,6 +1014,22 @@ impl<S: State> Board<S> {
     where
         S: Into<Colour> + Clone,
     {
+        self.to_representation_with(|state| state.into())
+    }
+
+    /// Get a representation of the board as a 2D vector of colours, using a custom colour scheme.
+    ///
+    /// This allows a board to be rendered with more detail than the `Into<Colour>` implementation of its state, such
+    /// as showing the heading of Langton's ant with `LangtonsAntState::heading_colour`.
+    ///
+    /// # Arguments
+    ///
+    /// - `colour_of`: A function that returns the colour of a cell from its state.
+    ///
+    /// # Returns
+    ///
+    /// A 2D vector of colours representing the board.
+    pub fn to_representation_with<F: Fn(S) -> Colour + Sync>(&self, colour_of: F) -> BoardRepresentation {
         // Threshold for when to use within-row parallelisation
         const ROW_PARALLELISM_THRESHOLD: usize = 128;
         let parallelise_rows: bool = self.dim.0 > ROW_PARALLELISM_THRESHOLD;
@@ -180,13 +1041,13 @@ impl<S: State> Board<S> {
                     true => (0..self.dim.0)
                         // For larger boards, parallelise within rows too
                         .into_par_iter()
-                        .map(|x| self.get(x, y).unwrap().into())
+                        .map(|x| colour_of(self.get(x, y).unwrap()))
                         .collect(),
                     false => {
                         // For smaller boards, process rows sequentially (better cache locality)
                         let mut row = Vec::with_capacity(self.dim.0);
                         for x in 0..self.dim.0 {
-                            row.push(self.get(x, y).unwrap().into());
+                            row.push(colour_of(self.get(x, y).unwrap()));
                         }
                         row
                     }
@@ -194,6 +1055,85 @@ impl<S: State> Board<S> {
             })
             .collect()
     }
+
+    /// Save the board as a PNG image. Available with the `image` feature.
+    ///
+    /// Each cell is drawn as a square of `cell_px` by `cell_px` pixels in the colour of its state, so the image is
+    /// `width * cell_px` pixels wide and `height * cell_px` pixels tall.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the PNG file to write. An existing file is overwritten.
+    ///
+    /// - `cell_px`: The width and height of each cell in pixels.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an error if the image is too large or the file could not be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_px` is zero.
+    #[cfg(feature = "image")]
+    pub fn save_png<P: AsRef<std::path::Path>>(&self, path: P, cell_px: usize) -> std::io::Result<()>
+    where
+        S: Into<Colour> + Clone,
+    {
+        use std::io::{Error, ErrorKind};
+
+        assert!(cell_px > 0, "Cell size must be at least one pixel");
+
+        let too_large = || Error::new(ErrorKind::InvalidInput, "PNG dimensions must not exceed u32::MAX pixels");
+        let width: u32 = self.dim.0.checked_mul(cell_px).and_then(|w| u32::try_from(w).ok()).ok_or_else(too_large)?;
+        let height: u32 = self.dim.1.checked_mul(cell_px).and_then(|h| u32::try_from(h).ok()).ok_or_else(too_large)?;
+
+        let representation: BoardRepresentation = self.to_representation();
+        let image = image::RgbImage::from_fn(width, height, |px, py| {
+            let colour: Colour = representation[py as usize / cell_px][px as usize / cell_px];
+            image::Rgb([colour.r, colour.g, colour.b])
+        });
+        image.save_with_format(path, image::ImageFormat::Png).map_err(|error| match error {
+            image::ImageError::IoError(error) => error,
+            error => Error::other(error),
+        })
+    }
+}
+
+impl Board<GameOfLifeState> {
+    /// Create a new Game of Life board with periodic boundary conditions where each cell is alive with the given probability.
+    ///
+    /// The cells are drawn from a `StdRng` seeded with `seed`, so the same seed always produces the same board
+    /// (see `random_with` for the determinism guarantees).
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: The width of the board.
+    /// - `height`: The height of the board.
+    /// - `density`: The probability that each cell is alive, between 0.0 and 1.0.
+    /// - `seed`: The seed of the random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the density is not between 0.0 and 1.0.
+    pub fn random_alive(width: usize, height: usize, density: f64, seed: u64) -> Self {
+        if !(0.0..=1.0).contains(&density) {
+            panic!("Density must be between 0.0 and 1.0");
+        }
+
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        Self::random_with(width, height, BoundaryCondition::Periodic, &mut rng, |rng| match rng.gen_bool(density) {
+            true => GameOfLifeState::Alive,
+            false => GameOfLifeState::Dead,
+        })
+    }
+}
+
+/// Get the text used to print a cell: its glyph if it has one, or otherwise its `Debug` representation.
+fn cell_text<S: State>(state: &S) -> String {
+    match state.display_glyph() {
+        Some(glyph) => glyph.to_string(),
+        None => format!("{:?}", state),
+    }
 }
 
 impl<S: State> std::fmt::Display for Board<S> {
@@ -204,7 +1144,7 @@ impl<S: State> std::fmt::Display for Board<S> {
             .map(|y| {
                 let mut row: Vec<String> = Vec::with_capacity(self.dim.0);
                 for x in 0..self.dim.0 {
-                    row.push(format!("{:?}", self.get(x, y).unwrap()));
+                    row.push(cell_text(&self.get(x, y).unwrap()));
                 }
                 row
             })
@@ -252,7 +1192,8 @@ impl<S: State> std::fmt::Display for Board<S> {
 pub type BoardRepresentation = Vec<Vec<Colour>>;
 
 /// A struct representing an RGB colour, used for rendering.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Colour {
     /// The red component of the colour.
     pub r: u8,
@@ -296,6 +1237,18 @@ impl Colour {
     pub fn blue() -> Self {
         Self { r: 0, g: 0, b: 255 }
     }
+
+    /// Linearly interpolate between this colour and another colour.
+    ///
+    /// # Arguments
+    ///
+    /// - `other`: The colour to interpolate towards.
+    /// - `t`: The interpolation factor, where 0.0 gives this colour and 1.0 gives `other`. It is clamped to [0, 1].
+    pub fn lerp(self, other: Colour, t: f64) -> Colour {
+        let t: f64 = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Colour::new(mix(self.r, other.r), mix(self.g, other.g), mix(self.b, other.b))
+    }
 }
 
 impl From<Colour> for String {
@@ -321,6 +1274,18 @@ pub struct IterCoords {
     height: usize,
 }
 
+impl IterCoords {
+    /// Create a new `IterCoords` over a board of the given width and height.
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+}
+
 impl Iterator for IterCoords {
     type Item = (usize, usize);
 
@@ -13,4 +13,37 @@ impl Debug for OutOfBoundsSetError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Out of bounds error: ({}, {}) accessed a board of size ({}, {})", self.x, self.y, self.width, self.height)
     }
-}
\ No newline at end of file
+}
+
+/// Error type for when a rule or pattern string cannot be parsed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+}
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Parse error: {}", self.message)
+    }
+}
+
+/// Error type for when an automaton cannot be advanced.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AutomatonError {
+    /// A cell was accessed out of bounds.
+    OutOfBounds(OutOfBoundsSetError),
+    /// More than one delta wrote the cell at the given coordinates in a single step, under `ConflictPolicy::Error`.
+    Conflict { x: usize, y: usize },
+}
+impl Debug for AutomatonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AutomatonError::OutOfBounds(error) => write!(f, "{:?}", error),
+            AutomatonError::Conflict { x, y } => write!(f, "Conflict error: more than one delta wrote cell ({}, {}) in a single step", x, y),
+        }
+    }
+}
+impl From<OutOfBoundsSetError> for AutomatonError {
+    fn from(error: OutOfBoundsSetError) -> Self {
+        AutomatonError::OutOfBounds(error)
+    }
+}
@@ -0,0 +1,187 @@
+use super::board::{reflect, Board, BoundaryCondition, IterCoords};
+use super::error::OutOfBoundsSetError;
+use super::state::State;
+
+/// A struct that represents a board of cells whose dimensions are fixed at compile time.
+///
+/// Unlike `Board`, the cells are stored inline in a `[[S; W]; H]` array, so creating, reading, writing and
+/// stepping the board never allocates on the heap. This makes it suitable for tiny automata in hot inner loops
+/// or allocation-free environments.
+///
+/// The board exposes the same `get`/`set`/`iter_coords` surface as `Board`, and is stepped without allocating
+/// through `step`. It cannot be evolved by an `Automaton` directly: an `Automaton` only runs on a `Board`, so using
+/// one means converting with `to_board` and back with `from_board`, and both conversions allocate on the heap.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+/// - `W`: The width of the board.
+/// - `H`: The height of the board.
+///
+/// # Fields
+///
+/// - `cells`: A row-major array of the cells in the board.
+/// - `boundary_condition`: The boundary condition of the board.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBoard<S: State, const W: usize, const H: usize> {
+    cells: [[S; W]; H],
+    boundary_condition: BoundaryCondition<S>,
+}
+
+impl<S: State, const W: usize, const H: usize> FixedBoard<S, W, H> {
+    /// Create a new `FixedBoard` with the given cells and boundary condition.
+    ///
+    /// # Arguments
+    ///
+    /// - `cells`: The initial state of the cells in the board, indexed as `cells[y][x]`.
+    /// - `boundary_condition`: The boundary condition of the board.
+    pub fn new(cells: [[S; W]; H], boundary_condition: BoundaryCondition<S>) -> Self {
+        Self {
+            cells,
+            boundary_condition,
+        }
+    }
+
+    /// Create a new `FixedBoard` with every cell set to the given state.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`: The state of every cell in the board.
+    /// - `boundary_condition`: The boundary condition of the board.
+    pub fn filled(state: S, boundary_condition: BoundaryCondition<S>) -> Self {
+        Self::new([[state; W]; H], boundary_condition)
+    }
+
+    /// Get the width of the board.
+    pub fn width(&self) -> usize {
+        W
+    }
+
+    /// Get the height of the board.
+    pub fn height(&self) -> usize {
+        H
+    }
+
+    /// Get the boundary condition of the board.
+    pub fn boundary_condition(&self) -> BoundaryCondition<S> {
+        self.boundary_condition.clone()
+    }
+
+    /// Get the state of a cell on the board.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    ///
+    /// - `y`: The y-coordinate of the cell.
+    ///
+    /// # Returns
+    ///
+    /// The state of the cell at the given coordinates, or None if the coordinates are out of bounds.
+    #[inline(always)]
+    pub fn get(&self, x: usize, y: usize) -> Option<S> {
+        if x < W && y < H {
+            Some(self.cells[y][x])
+        } else {
+            None
+        }
+    }
+
+    /// Set the state of a cell on the board. Wraps around the edges if the boundary condition is periodic, and mirrors
+    /// back into the board if it is reflective.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `state`: The new state of the cell.
+    ///
+    /// # Returns
+    ///
+    /// An error if the coordinates are out of bounds for a fixed boundary condition.
+    #[inline(always)]
+    pub fn set(&mut self, x: usize, y: usize, state: S) -> Result<(), OutOfBoundsSetError> {
+        match self.boundary_condition {
+            BoundaryCondition::Periodic => {
+                self.cells[y % H][x % W] = state;
+            }
+            BoundaryCondition::Fixed(_fixed_state) => {
+                if x < W && y < H {
+                    self.cells[y][x] = state;
+                } else {
+                    return Err(OutOfBoundsSetError {
+                        x,
+                        y,
+                        width: W,
+                        height: H,
+                    });
+                }
+            }
+            BoundaryCondition::Reflective => {
+                self.cells[reflect(y as isize, H)][reflect(x as isize, W)] = state;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get an iterator over the coordinates of the board.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the cell coordinates of the board in row-major order.
+    ///
+    /// The iterator yields tuples of the form `(x, y)`.
+    pub fn iter_coords(&self) -> IterCoords {
+        IterCoords::new(W, H)
+    }
+
+    /// Advance the board by one synchronous step without allocating.
+    ///
+    /// The new state of every cell is computed by `next_state` from a copy of the board taken before the step,
+    /// so every cell sees the same previous generation.
+    ///
+    /// # Arguments
+    ///
+    /// - `next_state`: A function that takes the coordinates of a cell and the previous board, and returns the new state of the cell.
+    pub fn step<F>(&mut self, next_state: F)
+    where
+        F: Fn((usize, usize), &Self) -> S,
+    {
+        let previous: Self = self.clone();
+        for (x, y) in previous.iter_coords() {
+            self.cells[y][x] = next_state((x, y), &previous);
+        }
+    }
+
+    /// Convert the board into a heap-allocated `Board` with the same cells and boundary condition.
+    ///
+    /// This allows a `FixedBoard` to be evolved with an `Automaton`.
+    pub fn to_board(&self) -> Board<S> {
+        Board::new(
+            self.cells.iter().map(|row| row.to_vec()).collect(),
+            self.boundary_condition.clone(),
+        )
+    }
+
+    /// Create a `FixedBoard` from a `Board` with matching dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to copy the cells and boundary condition from.
+    ///
+    /// # Returns
+    ///
+    /// The fixed board, or None if the dimensions of the board do not match `W` and `H`.
+    pub fn from_board(board: &Board<S>) -> Option<Self> {
+        if board.width() != W || board.height() != H {
+            return None;
+        }
+
+        let mut cells: [[S; W]; H] = [[board.get(0, 0)?; W]; H];
+        for (x, y) in board.iter_coords() {
+            cells[y][x] = board.get(x, y)?;
+        }
+
+        Some(Self::new(cells, board.boundary_condition()))
+    }
+}
@@ -0,0 +1,68 @@
+use rand::Rng;
+use std::fmt::Debug;
+
+/// Methods for combining the genes of two parents into a child
+///
+/// A population passes its crossover strategy to `Genotype::crossover_with` for every child. A genotype whose
+/// parameters are a vector of genes can honour each strategy by combining its genes with `crossover_genes`:
+/// - Uniform: Each gene of the child is taken from either parent with equal probability.
+/// - SinglePoint: A cut point is chosen at random, and the child takes the genes before it from the first parent and
+///   the genes from it onwards from the second parent.
+/// - TwoPoint: Two cut points are chosen at random, and the child takes the genes between them from the second parent
+///   and the rest from the first parent.
+///
+/// Genotypes that do not override `crossover_with` ignore the strategy and use their own `crossover`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CrossoverStrategy {
+    /// Uniform crossover, where each gene comes from either parent. This is the default.
+    #[default]
+    Uniform,
+    /// Single-point crossover, where the genes are cut once
+    SinglePoint,
+    /// Two-point crossover, where the genes are cut twice
+    TwoPoint,
+}
+
+impl CrossoverStrategy {
+    /// Combine the genes of two parents according to the strategy.
+    ///
+    /// # Arguments
+    ///
+    /// - `first`: The genes of the first parent.
+    /// - `second`: The genes of the second parent.
+    /// - `rng`: The random number generator used to choose genes and cut points.
+    ///
+    /// # Returns
+    ///
+    /// The genes of the child, which has as many genes as each parent. Genes of fewer than two elements cannot be cut,
+    /// so single-point and two-point crossover return the genes of the first parent unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parents have a different number of genes.
+    pub fn crossover_genes<T: Clone, R: Rng>(&self, first: &[T], second: &[T], rng: &mut R) -> Vec<T> {
+        if first.len() != second.len() {
+            panic!("Cannot cross over genes of lengths {} and {}", first.len(), second.len());
+        }
+        let n: usize = first.len();
+
+        match self {
+            Self::Uniform => first
+                .iter()
+                .zip(second.iter())
+                .map(|(a, b)| if rng.gen_bool(0.5) { a.clone() } else { b.clone() })
+                .collect(),
+            _ if n < 2 => first.to_vec(),
+            Self::SinglePoint => {
+                let cut: usize = rng.gen_range(1..n);
+                first[..cut].iter().chain(second[cut..].iter()).cloned().collect()
+            }
+            Self::TwoPoint => {
+                // The cuts are distinct positions between genes, so the child takes at least one gene from the second parent
+                let cuts: Vec<usize> = rand::seq::index::sample(rng, n + 1, 2).into_vec();
+                let (start, end): (usize, usize) = (cuts[0].min(cuts[1]), cuts[0].max(cuts[1]));
+                first[..start].iter().chain(second[start..end].iter()).chain(first[end..].iter()).cloned().collect()
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::automaton::Automaton;
+use crate::components::{board::Board, rule::Rule, state::State};
+
+/// A fitness objective that rewards rules for turning a fixed seed board into a target board.
+///
+/// This is the most common fitness objective for evolving cellular automata, so genotypes can call `evaluate` from
+/// their `fitness` implementation instead of writing the comparison themselves.
+///
+/// # Type Parameters
+/// - `S`: The type of state that each cell in the board can have.
+///
+/// # Fields
+///
+/// - `seed_board`: The board each rule is evolved from.
+/// - `target`: The board each rule should produce.
+/// - `steps`: The number of steps each rule is evolved for.
+#[derive(Clone, Debug)]
+pub struct TargetFitness<S: State> {
+    /// The board each rule is evolved from.
+    seed_board: Board<S>,
+    /// The board each rule should produce.
+    target: Board<S>,
+    /// The number of steps each rule is evolved for.
+    steps: usize,
+}
+
+impl<S: State> TargetFitness<S> {
+    /// Create a new `TargetFitness` with the given seed board, target board and number of steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `seed_board`: The board each rule is evolved from.
+    /// - `target`: The board each rule should produce.
+    /// - `steps`: The number of steps each rule is evolved for.
+    ///
+    /// # Returns
+    ///
+    /// A new `TargetFitness` with the given seed board, target board and number of steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seed board and the target board have different dimensions.
+    pub fn new(seed_board: Board<S>, target: Board<S>, steps: usize) -> Self {
+        if (seed_board.width(), seed_board.height()) != (target.width(), target.height()) {
+            panic!(
+                "Cannot compare a seed board of size {:?} with a target of size {:?}",
+                (seed_board.width(), seed_board.height()),
+                (target.width(), target.height())
+            );
+        }
+
+        Self { seed_board, target, steps }
+    }
+
+    /// Get the board each rule is evolved from.
+    pub fn seed_board(&self) -> &Board<S> {
+        &self.seed_board
+    }
+
+    /// Get the board each rule should produce.
+    pub fn target(&self) -> &Board<S> {
+        &self.target
+    }
+
+    /// Get the number of steps each rule is evolved for.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Evaluate a rule against the target.
+    ///
+    /// # Arguments
+    ///
+    /// - `rule`: The rule to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// The fraction of cells that match the target after evolving the seed board with the rule, as described in
+    /// `evaluate_against_target`.
+    pub fn evaluate(&self, rule: &dyn Rule<S>) -> f64 {
+        evaluate_against_target(rule, &self.seed_board, &self.target, self.steps)
+    }
+}
+
+/// Evaluate how closely a rule turns a seed board into a target board.
+///
+/// A copy of the seed board is evolved with the rule for the given number of steps, so the seed board itself is left
+/// unchanged and every rule is evaluated from the same starting point.
+///
+/// # Arguments
+///
+/// - `rule`: The rule to evaluate.
+/// - `seed_board`: The board to evolve from.
+/// - `target`: The board the rule should produce.
+/// - `steps`: The number of steps to evolve the seed board for.
+///
+/// # Returns
+///
+/// The fraction of cells in the evolved board that have the same state as the corresponding cell in the target,
+/// between 0.0 and 1.0. A rule whose evolution fails scores 0.0, and an empty board scores 1.0.
+///
+/// # Panics
+///
+/// Panics if the seed board and the target board have different dimensions.
+pub fn evaluate_against_target<S: State>(rule: &dyn Rule<S>, seed_board: &Board<S>, target: &Board<S>, steps: usize) -> f64 {
+    let (width, height): (usize, usize) = (seed_board.width(), seed_board.height());
+    if (width, height) != (target.width(), target.height()) {
+        panic!(
+            "Cannot compare a seed board of size {:?} with a target of size {:?}",
+            (width, height),
+            (target.width(), target.height())
+        );
+    }
+    if width * height == 0 {
+        return 1.0;
+    }
+
+    let mut board: Board<S> = seed_board.clone();
+    if Automaton::new(&mut board, vec![rule.clone_box()]).evolve(steps).is_err() {
+        return 0.0;
+    }
+
+    let matching: usize = board
+        .iter_coords()
+        .filter(|&(x, y)| board.get(x, y) == target.get(x, y))
+        .count();
+    matching as f64 / (width * height) as f64
+}
@@ -1,9 +1,11 @@
 use crate::components::{
     board::Board,
+    genetic::crossover_strategy::CrossoverStrategy,
     rule::Rule,
     state::State,
 };
 use std::fmt::Debug;
+use std::time::Instant;
 
 /// A trait that defines a rule for a cellular automaton that can be evolved using genetic algorithms.
 /// 
@@ -14,7 +16,7 @@ use std::fmt::Debug;
 /// # Type Parameters
 /// 
 /// - `S`: The type of state that each cell in the board can have.
-pub trait Genotype<S: State>: Rule<S> + Clone + Debug + Send + Sync {
+pub trait Genotype<S: State>: Rule<S> + Clone + Debug + Send + Sync + 'static {
     /// Perform crossover with another genotype to produce offspring.
     /// 
     /// Combines genetic material from self and other to create new rule according to the crossover strategy.
@@ -26,6 +28,24 @@ pub trait Genotype<S: State>: Rule<S> + Clone + Debug + Send + Sync {
     /// 
     /// A new genotype that is a combination of self and other.
     fn crossover(&self, other: &Self) -> Self;
+
+    /// Perform crossover with another genotype using the given crossover strategy.
+    /// 
+    /// This is called by `Population` with its crossover strategy when creating offspring. The default implementation
+    /// ignores the strategy and calls `crossover`. Genotypes whose parameters are a vector of genes can override it to
+    /// honour each strategy by combining their genes with `CrossoverStrategy::crossover_genes`.
+    /// 
+    /// # Arguments
+    /// - `other`: The other genotype to crossover with.
+    /// - `strategy`: The crossover strategy to use.
+    /// 
+    /// # Returns
+    /// 
+    /// A new genotype that is a combination of self and other.
+    fn crossover_with(&self, other: &Self, strategy: &CrossoverStrategy) -> Self {
+        let _ = strategy;
+        self.crossover(other)
+    }
     
     /// Mutate this genotype with the given mutation rate.
     /// 
@@ -46,4 +66,64 @@ pub trait Genotype<S: State>: Rule<S> + Clone + Debug + Send + Sync {
     /// 
     /// A fitness score as a floating-point number.
     fn fitness(&self, board: &Board<S>) -> f64;
+
+    /// Calculate the fitness of this genotype, giving up once the deadline has passed.
+    /// 
+    /// This is called by `Population` instead of `fitness` when a fitness timeout is enabled. Evaluations run on the
+    /// caller's thread and cannot be interrupted from outside, so genotypes with long evaluations should override this
+    /// to check the deadline regularly, for example between generations of the automaton they evolve, and return None
+    /// once it has passed. The default implementation ignores the deadline and calls `fitness`.
+    /// 
+    /// # Arguments
+    /// - `board`: The board to evaluate the rule against.
+    /// - `deadline`: The time by which the evaluation should finish.
+    /// 
+    /// # Returns
+    /// 
+    /// The fitness score, or None if the evaluation gave up because the deadline passed.
+    fn fitness_until(&self, board: &Board<S>, deadline: Instant) -> Option<f64> {
+        let _ = deadline;
+        Some(self.fitness(board))
+    }
+
+    /// Get the numeric genes of this genotype.
+    /// 
+    /// The genes are used by the default implementation of `distance`. Genotypes without a numeric representation
+    /// can keep the default, which returns no genes, and override `distance` instead.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector of the genes of this genotype.
+    fn genes(&self) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// Calculate the distance between this genotype and another.
+    /// 
+    /// The distance measures how genetically different two genotypes are, for example to measure the diversity of a
+    /// population. By default, it is the Euclidean distance between the genes of the two genotypes.
+    /// 
+    /// # Arguments
+    /// - `other`: The other genotype to measure the distance to.
+    /// 
+    /// # Returns
+    /// 
+    /// The distance between the two genotypes as a non-negative floating-point number.
+    /// 
+    /// # Panics
+    /// 
+    /// The default implementation panics if the two genotypes have a different number of genes.
+    fn distance(&self, other: &Self) -> f64 {
+        let (genes, other_genes) = (self.genes(), other.genes());
+        if genes.len() != other_genes.len() {
+            panic!("Cannot compute the distance between genotypes with {} and {} genes", genes.len(), other_genes.len());
+        }
+
+        genes
+            .iter()
+            .zip(other_genes.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
 }
\ No newline at end of file
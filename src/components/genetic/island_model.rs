@@ -0,0 +1,148 @@
+use crate::components::{
+    board::Board,
+    genetic::{genotype::Genotype, population::Population},
+    state::State,
+};
+use rayon::prelude::*;
+
+/// A struct that represents an island model, in which several populations evolve independently and occasionally exchange genotypes.
+///
+/// The islands are arranged in a ring, so each island sends its fittest genotypes to the next island and receives the
+/// fittest genotypes of the previous island. Isolating the islands between migrations helps to preserve diversity,
+/// while migration spreads good genotypes across the islands.
+///
+/// # Type Parameters
+/// - `S`: The type of state that each cell in the board can have.
+/// - `G`: The type of genotype that represents a rule for the cellular automaton.
+///
+/// # Fields
+///
+/// - `islands`: The populations on each island, in ring order.
+/// - `migration_interval`: The number of generations between migrations, or 0 to never migrate.
+/// - `num_migrants`: The number of genotypes each island sends to the next island at each migration.
+/// - `generation`: The number of generations the islands have been advanced by.
+#[derive(Clone, Debug)]
+pub struct IslandModel<S: State, G: Genotype<S>> {
+    /// The populations on each island, in ring order.
+    islands: Vec<Population<S, G>>,
+    /// The number of generations between migrations, or 0 to never migrate.
+    migration_interval: usize,
+    /// The number of genotypes each island sends to the next island at each migration.
+    num_migrants: usize,
+    /// The number of generations the islands have been advanced by.
+    generation: usize,
+}
+
+impl<S: State, G: Genotype<S>> IslandModel<S, G> {
+    /// Create a new `IslandModel` with the given islands and migration settings.
+    ///
+    /// # Arguments
+    /// - `islands`: The populations on each island, in ring order.
+    /// - `migration_interval`: The number of generations between migrations, or 0 to never migrate.
+    /// - `num_migrants`: The number of genotypes each island sends to the next island at each migration.
+    ///
+    /// # Returns
+    ///
+    /// A new `IslandModel` with the given islands and migration settings.
+    pub fn new(islands: Vec<Population<S, G>>, migration_interval: usize, num_migrants: usize) -> Self {
+        Self {
+            islands,
+            migration_interval,
+            num_migrants,
+            generation: 0,
+        }
+    }
+
+    /// Get the populations on each island.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of populations, in ring order.
+    pub fn islands(&self) -> &Vec<Population<S, G>> {
+        &self.islands
+    }
+
+    /// Get the populations on each island mutably.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the vector of populations, in ring order.
+    pub fn islands_mut(&mut self) -> &mut Vec<Population<S, G>> {
+        &mut self.islands
+    }
+
+    /// Get the number of generations the islands have been advanced by.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Move the fittest genotypes of each island to the next island in the ring.
+    ///
+    /// Every island sends its migrants before any island receives them, so an island never passes on the migrants it
+    /// has just received, and each island loses and gains the same number of genotypes. Islands with fewer genotypes
+    /// than `num_migrants` send all of their genotypes.
+    ///
+    /// # Arguments
+    ///
+    /// - `num_migrants`: The number of genotypes each island sends to the next island.
+    /// - `board`: A reference to the board of cells to evaluate the genotypes against.
+    pub fn migrate(&mut self, num_migrants: usize, board: &Board<S>) {
+        if self.islands.len() < 2 || num_migrants == 0 {
+            return;
+        }
+
+        let emigrants: Vec<Vec<G>> = self.islands
+            .iter_mut()
+            .map(|island| {
+                // Select the fittest genotypes, ranking NaN fitness last as in elitism, and remove them from the back
+                // so the indices stay valid
+                let fitness_scores: Vec<f64> = island.fitness_scores(board);
+                let mut ranked_indices: Vec<usize> = (0..fitness_scores.len()).collect();
+                ranked_indices.sort_by(|&a, &b| {
+                    let (a, b): (f64, f64) = (fitness_scores[a], fitness_scores[b]);
+                    a.is_nan().cmp(&b.is_nan()).then(b.total_cmp(&a))
+                });
+                ranked_indices.truncate(num_migrants);
+                ranked_indices.sort_unstable_by(|a, b| b.cmp(a));
+                ranked_indices
+                    .into_iter()
+                    .map(|index| island.remove_genotype(index).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let num_islands: usize = self.islands.len();
+        for (i, migrants) in emigrants.into_iter().enumerate() {
+            let destination: &mut Population<S, G> = &mut self.islands[(i + 1) % num_islands];
+            migrants.into_iter().for_each(|genotype| destination.add_genotype(genotype));
+        }
+    }
+
+    /// Advance every island by the given number of generations, migrating genotypes every `migration_interval` generations.
+    ///
+    /// The islands are advanced independently and in parallel, each by shrinking and then growing its population.
+    ///
+    /// # Arguments
+    ///
+    /// - `generations`: The number of generations to advance the islands by.
+    /// - `death_percentage`: The percentage of each population to remove (0.0 to 1.0).
+    /// - `growth_percentage`: The percentage of each population to add (0.0 to 1.0).
+    /// - `board`: A reference to the board of cells to evaluate the genotypes against.
+    ///
+    /// # Returns
+    ///
+    /// A result indicating success or failure. An error is returned if any island could not be advanced.
+    pub fn advance_all_generations(&mut self, generations: usize, death_percentage: f64, growth_percentage: f64, board: &Board<S>) -> Result<(), String> {
+        for _ in 0..generations {
+            self.islands
+                .par_iter_mut()
+                .try_for_each(|island| island.advance_generation(death_percentage, growth_percentage, board))?;
+            self.generation += 1;
+
+            if self.migration_interval > 0 && self.generation.is_multiple_of(self.migration_interval) {
+                self.migrate(self.num_migrants, board);
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
 pub mod genotype;
 pub mod selection_strategy;
+pub mod crossover_strategy;
+pub mod fitness;
+pub mod island_model;
 pub mod population;
\ No newline at end of file
@@ -1,19 +1,28 @@
 use crate::components::{
     board::Board,
     genetic::{
+        crossover_strategy::CrossoverStrategy,
         genotype::Genotype,
         selection_strategy::SelectionStrategy,
     },
     state::State,
 };
 use std::fmt::Debug;
+use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
 use rayon::prelude::*;
 use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use std::{fs, io, path::Path};
+use std::time::{Duration, Instant};
 
 /// A struct that represents a population of genotypes in a genetic algorithm.
 ///
 /// The population contains a vector of genotypes, a selection strategy, and a mutation rate for the population. It implements methods for creating a new population, calculating fitness scores, and adding or removing genotypes.
 ///
+/// The fitness of each genotype is cached when the population is shrunk or grown, so each genotype is only evaluated
+/// once until the cache is cleared with `clear_fitness_cache`. The cache must be cleared whenever the board the
+/// genotypes are evaluated against changes.
+///
 /// # Type Parameters
 /// - `S`: The type of state that each cell in the board can have.
 /// - `G`: The type of genotype that represents a rule for the cellular automaton.
@@ -23,6 +32,13 @@ use std::marker::PhantomData;
 /// - `genotypes`: A vector of genotypes in the population.
 /// - `selection_strategy`: The strategy to use for selection (e.g., tournament, roulette, etc.).
 /// - `mutation_rate`: The rate of mutation for the population. Between 0.0 and 1.0.
+/// - `fitness_timeout`: The time limit for evaluating the fitness of a genotype and the fitness assigned when it is exceeded, if any.
+/// - `elitism`: The number of fittest genotypes that always survive when the population is shrunk.
+/// - `crossover_strategy`: The strategy passed to the genotypes when creating offspring.
+/// - `fitness_cache`: The cached fitness of each genotype, if it has been evaluated since the cache was last cleared.
+/// - `adaptive_mutation`: The minimum and maximum mutation rates, if the mutation rate adapts to the progress of the population.
+/// - `previous_best_fitness`: The best fitness at the start of the previous generation, used to adapt the mutation rate.
+/// - `rng`: The random number generator that seeds the selection of parents for each child, if the population is seeded.
 #[derive(Clone, Debug)]
 pub struct Population<S: State, G: Genotype<S>> {
     /// A vector of genotypes in the population.
@@ -31,6 +47,20 @@ pub struct Population<S: State, G: Genotype<S>> {
     selection_strategy: SelectionStrategy,
     /// The rate of mutation for the population. Between 0.0 and 1.0.
     mutation_rate: f64,
+    /// The time limit for evaluating the fitness of a genotype and the fitness assigned when it is exceeded, if any.
+    fitness_timeout: Option<(Duration, f64)>,
+    /// The number of fittest genotypes that always survive when the population is shrunk.
+    elitism: usize,
+    /// The strategy passed to the genotypes when creating offspring.
+    crossover_strategy: CrossoverStrategy,
+    /// The cached fitness of each genotype, if it has been evaluated since the cache was last cleared.
+    fitness_cache: Vec<Option<f64>>,
+    /// The minimum and maximum mutation rates, if the mutation rate adapts to the progress of the population.
+    adaptive_mutation: Option<(f64, f64)>,
+    /// The best fitness at the start of the previous generation, used to adapt the mutation rate.
+    previous_best_fitness: Option<f64>,
+    /// The random number generator that seeds the selection of parents for each child, if the population is seeded.
+    rng: Option<StdRng>,
     _phantom: PhantomData<S>,
 }
 
@@ -59,9 +89,16 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
             panic!("Mutation rate must be between 0.0 and 1.0");
         }
         Self {
+            fitness_cache: vec![None; genotypes.len()],
             genotypes,
             selection_strategy,
             mutation_rate,
+            fitness_timeout: None,
+            elitism: 0,
+            crossover_strategy: CrossoverStrategy::default(),
+            adaptive_mutation: None,
+            previous_best_fitness: None,
+            rng: None,
             _phantom: PhantomData,
         }
     }
@@ -75,8 +112,159 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
         &self.genotypes
     }
 
+    /// Seed the selection of parents, so the parents of each child are selected reproducibly.
+    /// 
+    /// Each child is created with its own random number generator, seeded from a `StdRng` seeded with `seed`, so the
+    /// same seed always selects the same parents even when children are created in parallel. Crossover and mutation
+    /// use the genotype's own randomness, so the children themselves are only reproducible if the genotype's
+    /// `crossover_with` and `mutate` are. Unseeded populations select parents with the thread-local random number generator.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `seed`: The seed of the random number generator.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+    }
+
+    /// Get the strategy used to select parents.
+    pub fn selection_strategy(&self) -> &SelectionStrategy {
+        &self.selection_strategy
+    }
+
+    /// Get the current mutation rate of the population.
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    /// Let the mutation rate adapt to the progress of the population, within the given bounds.
+    /// 
+    /// At the start of each call to `advance_generation`, the best fitness of the population is compared with the best
+    /// fitness at the start of the previous generation:
+    /// - If the best fitness has improved, the mutation rate moves halfway towards `min`, to exploit the progress.
+    /// - Otherwise, the population has stagnated, and the mutation rate moves halfway towards `max`, to explore more.
+    /// 
+    /// The first generation after enabling adaptive mutation only records the best fitness. The new rate applies to the
+    /// offspring created in the same generation. The current mutation rate is clamped to the bounds.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `min`: The minimum mutation rate.
+    /// - `max`: The maximum mutation rate.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the bounds are not between 0.0 and 1.0, or `min` is greater than `max`.
+    pub fn set_adaptive_mutation(&mut self, min: f64, max: f64) {
+        if !(0.0..=1.0).contains(&min) || !(0.0..=1.0).contains(&max) || min > max {
+            panic!("Mutation rate bounds must satisfy 0.0 <= min <= max <= 1.0");
+        }
+        self.adaptive_mutation = Some((min, max));
+        self.previous_best_fitness = None;
+        self.mutation_rate = self.mutation_rate.clamp(min, max);
+    }
+
+    /// Stop adapting the mutation rate, keeping the current mutation rate.
+    pub fn disable_adaptive_mutation(&mut self) {
+        self.adaptive_mutation = None;
+        self.previous_best_fitness = None;
+    }
+
+    /// Adjust the mutation rate according to whether the best fitness has improved since the previous generation.
+    fn adapt_mutation_rate(&mut self, board: &Board<S>) {
+        let Some((min, max)) = self.adaptive_mutation else {
+            return;
+        };
+        if self.genotypes.is_empty() {
+            return;
+        }
+
+        let best_fitness: f64 = self.cached_fitness_scores(board).into_iter().fold(f64::MIN, f64::max);
+        if let Some(previous_best_fitness) = self.previous_best_fitness {
+            self.mutation_rate = match best_fitness > previous_best_fitness {
+                true => self.mutation_rate - (self.mutation_rate - min) / 2.0,
+                false => self.mutation_rate + (max - self.mutation_rate) / 2.0,
+            };
+        }
+        self.previous_best_fitness = Some(best_fitness);
+    }
+
+    /// Limit the time taken to evaluate the fitness of each genotype.
+    /// 
+    /// Genotypes whose fitness evaluation takes longer than `timeout` are assigned `timeout_fitness` instead. Each
+    /// genotype is evaluated with `Genotype::fitness_until` and a deadline `timeout` after its evaluation starts, so a
+    /// runaway genotype can stop as soon as the deadline passes rather than stalling the population. Evaluations are
+    /// not run on separate threads and cannot be interrupted, so a genotype that keeps the default `fitness_until`
+    /// still runs to completion, and is assigned `timeout_fitness` if it finishes late.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `timeout`: The maximum time to wait for the fitness of a genotype.
+    /// - `timeout_fitness`: The fitness assigned to genotypes that exceed the timeout, typically the worst possible fitness.
+    pub fn enable_fitness_timeout(&mut self, timeout: Duration, timeout_fitness: f64) {
+        self.fitness_timeout = Some((timeout, timeout_fitness));
+        self.clear_fitness_cache();
+    }
+
+    /// Remove the time limit on fitness evaluation, so each fitness is evaluated to completion.
+    pub fn disable_fitness_timeout(&mut self) {
+        self.fitness_timeout = None;
+        self.clear_fitness_cache();
+    }
+
+    /// Get the time limit for evaluating the fitness of a genotype, if any.
+    pub fn fitness_timeout(&self) -> Option<Duration> {
+        self.fitness_timeout.map(|(timeout, _)| timeout)
+    }
+
+    /// Set the number of fittest genotypes that always survive when the population is shrunk.
+    /// 
+    /// Without elitism, the best genotype can be lost to the randomness of the selection strategy. With elitism, the
+    /// `elitism` genotypes with the highest fitness are excluded from selection for death, so they survive each
+    /// generation unchanged. Deaths are then selected from the remaining genotypes, so fewer genotypes than requested
+    /// may die if the rest of the population is too small.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `elitism`: The number of fittest genotypes to protect, or 0 to disable elitism.
+    pub fn set_elitism(&mut self, elitism: usize) {
+        self.elitism = elitism;
+    }
+
+    /// Get the number of fittest genotypes that always survive when the population is shrunk.
+    pub fn elitism(&self) -> usize {
+        self.elitism
+    }
+
+    /// Set the crossover strategy used when creating offspring.
+    /// 
+    /// The strategy is passed to `Genotype::crossover_with`, so it only has an effect on genotypes that override it.
+    /// The default strategy is `CrossoverStrategy::Uniform`.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `crossover_strategy`: The crossover strategy to use.
+    pub fn set_crossover_strategy(&mut self, crossover_strategy: CrossoverStrategy) {
+        self.crossover_strategy = crossover_strategy;
+    }
+
+    /// Get the crossover strategy used when creating offspring.
+    pub fn crossover_strategy(&self) -> CrossoverStrategy {
+        self.crossover_strategy
+    }
+
+    /// Clear the cached fitness of every genotype, so each genotype is evaluated again when its fitness is next needed.
+    /// 
+    /// This must be called whenever the board the genotypes are evaluated against changes, since cached fitness
+    /// scores are not tied to a board.
+    pub fn clear_fitness_cache(&mut self) {
+        self.fitness_cache.fill(None);
+    }
+
     /// Calculate the fitness scores of all genotypes in the population.
     /// 
+    /// Cached fitness scores are reused, and only the genotypes without a cached fitness are evaluated. This method
+    /// does not add to the cache. If a fitness timeout is enabled, genotypes that exceed it are assigned the timeout fitness.
+    /// 
     /// # Arguments
     /// 
     /// - `board`: A reference to the board of cells to evaluate the genotypes against.
@@ -87,10 +275,123 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
     pub fn fitness_scores(&self, board: &Board<S>) -> Vec<f64> {
         self.genotypes
             .par_iter()
-            .map(|genotype| genotype.fitness(board))
+            .zip(self.fitness_cache.par_iter())
+            .map(|(genotype, cached)| cached.unwrap_or_else(|| self.evaluate_fitness(genotype, board)))
+            .collect()
+    }
+
+    /// Calculate the fitness scores of all genotypes in the population, evaluating only the genotypes without a cached
+    /// fitness and caching the results.
+    fn cached_fitness_scores(&mut self, board: &Board<S>) -> Vec<f64> {
+        let fitness_scores: Vec<f64> = self.fitness_scores(board);
+        self.fitness_cache = fitness_scores.iter().map(|&fitness| Some(fitness)).collect();
+        fitness_scores
+    }
+
+    /// Evaluate the fitness of a single genotype, applying the fitness timeout if one is enabled.
+    fn evaluate_fitness(&self, genotype: &G, board: &Board<S>) -> f64 {
+        match self.fitness_timeout {
+            Some((timeout, timeout_fitness)) => {
+                let deadline: Instant = Instant::now() + timeout;
+                match genotype.fitness_until(board, deadline) {
+                    Some(fitness) if Instant::now() <= deadline => fitness,
+                    _ => timeout_fitness,
+                }
+            }
+            None => genotype.fitness(board),
+        }
+    }
+
+    /// Calculate relative fitness scores by playing every genotype against every other genotype in a round-robin.
+    /// 
+    /// This is intended for co-evolutionary setups, where the fitness of a genotype depends on the genotypes it competes
+    /// against rather than on a fixed board. The pairings are evaluated in parallel.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `compete`: A function that returns the result of the first genotype against the second, where higher is better.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector of the average result of each genotype against every other genotype, or 0.0 for a population with a single genotype.
+    pub fn coevolve_scores<F: Fn(&G, &G) -> f64 + Sync>(&self, compete: F) -> Vec<f64> {
+        let opponents: usize = self.genotypes.len().saturating_sub(1);
+        if opponents == 0 {
+            return vec![0.0; self.genotypes.len()];
+        }
+
+        self.genotypes
+            .par_iter()
+            .enumerate()
+            .map(|(i, genotype)| {
+                let total: f64 = self.genotypes
+                    .par_iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, opponent)| compete(genotype, opponent))
+                    .sum();
+                total / opponents as f64
+            })
             .collect()
     }
 
+    /// Calculate the genetic diversity of the population as the mean distance between every pair of genotypes.
+    /// 
+    /// This is useful to detect premature convergence, where the population collapses onto a few similar genotypes.
+    /// Every pair is compared, which takes O(n²) distance evaluations; see `diversity_sampled` for large populations.
+    /// The pairs are evaluated in parallel.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `distance`: A function that returns the distance between two genotypes, such as `Genotype::distance`.
+    /// 
+    /// # Returns
+    /// 
+    /// The mean pairwise distance, or 0.0 for a population with fewer than two genotypes.
+    pub fn diversity<F: Fn(&G, &G) -> f64 + Sync>(&self, distance: F) -> f64 {
+        let n: usize = self.genotypes.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        let total: f64 = (0..n)
+            .into_par_iter()
+            .map(|i| (i + 1..n).map(|j| distance(&self.genotypes[i], &self.genotypes[j])).sum::<f64>())
+            .sum();
+        total / (n * (n - 1) / 2) as f64
+    }
+
+    /// Estimate the genetic diversity of the population from a random sample of pairs of genotypes.
+    /// 
+    /// Each of the `k` pairs is drawn uniformly from the pairs of distinct genotypes, with replacement, so the result is
+    /// an unbiased estimate of `diversity` that takes only `k` distance evaluations. The pairs are drawn from a `StdRng`
+    /// seeded with `seed`, so the same seed always samples the same pairs.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `k`: The number of pairs to sample.
+    /// - `seed`: The seed of the random number generator.
+    /// - `distance`: A function that returns the distance between two genotypes, such as `Genotype::distance`.
+    /// 
+    /// # Returns
+    /// 
+    /// The mean distance of the sampled pairs, or 0.0 for a population with fewer than two genotypes or if `k` is 0.
+    pub fn diversity_sampled<F: Fn(&G, &G) -> f64>(&self, k: usize, seed: u64, distance: F) -> f64 {
+        let n: usize = self.genotypes.len();
+        if n < 2 || k == 0 {
+            return 0.0;
+        }
+
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        let total: f64 = (0..k)
+            .map(|_| {
+                let pair: Vec<usize> = rand::seq::index::sample(&mut rng, n, 2).into_vec();
+                distance(&self.genotypes[pair[0]], &self.genotypes[pair[1]])
+            })
+            .sum();
+        total / k as f64
+    }
+
     /// Remove a genotype from the population at the given index.
     /// 
     /// # Arguments
@@ -108,10 +409,12 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
         if index >= self.genotypes.len() {
             return Err(format!("Index out of bounds: {}", index));
         }
+        self.fitness_cache.remove(index);
         Ok(self.genotypes.remove(index))
     }
 
     pub fn add_genotype(&mut self, genotype: G) {
+        self.fitness_cache.push(None);
         self.genotypes.push(genotype)
     }
 
@@ -138,23 +441,46 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
             return Err("Population is empty".to_string());
         }
 
+        let fitness_scores: Vec<f64> = self.cached_fitness_scores(board);
+        let seed: u64 = self.child_seeds(1)[0];
+        let child: G = self.create_child(&fitness_scores, seed);
+
+        // Add the child to the population
+        self.fitness_cache.push(None);
+        self.genotypes.push(child);
+        Ok(())
+    }
+
+    /// Draw a seed for each of the given number of children, from the population's random number generator if it is seeded.
+    fn child_seeds(&mut self, num_children: usize) -> Vec<u64> {
+        match &mut self.rng {
+            Some(rng) => (0..num_children).map(|_| rng.gen()).collect(),
+            None => {
+                let mut rng: rand::prelude::ThreadRng = thread_rng();
+                (0..num_children).map(|_| rng.gen()).collect()
+            }
+        }
+    }
+
+    /// Create a child genotype by selecting two parents using the selection strategy and performing crossover and mutation.
+    fn create_child(&self, fitness_scores: &[f64], seed: u64) -> G {
         // Select parents using the selection strategy
-        let fitness_scores: Vec<f64> = self.fitness_scores(board);
-        let (parent1_index, parent2_index) = self.selection_strategy.select_parents(&fitness_scores);
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        let (parent1_index, parent2_index) = self.selection_strategy.select_parents_with_rng(fitness_scores, &mut rng);
 
         let parent1: &G = &self.genotypes[parent1_index];
         let parent2: &G = &self.genotypes[parent2_index];
 
         // Perform crossover and mutation to create a child genotype
-        let mut child: G = parent1.crossover(parent2);
+        let mut child: G = parent1.crossover_with(parent2, &self.crossover_strategy);
         child.mutate(self.mutation_rate);
-
-        // Add the child to the population
-        Ok(self.genotypes.push(child))
+        child
     }
 
     /// Kill a percentage of the population based on fitness scores using the selection strategy.
     /// 
+    /// If elitism is enabled, the fittest genotypes are never killed (see `set_elitism`).
+    /// 
     /// # Arguments
     /// 
     /// - `percentage`: The percentage of the population to kill relative to the current population (0.0 to 1.0).
@@ -172,11 +498,27 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
             return Err("Population is empty".to_string());
         }
 
-        let fitness_scores: Vec<f64> = self.fitness_scores(board);
-        let selected_indices: Vec<usize> = self.selection_strategy.select_deaths(&fitness_scores, percentage);
-        
+        let fitness_scores: Vec<f64> = self.cached_fitness_scores(board);
+        let mut selected_indices: Vec<usize> = match self.elitism {
+            0 => self.selection_strategy.select_deaths(&fitness_scores, 1.0 - percentage),
+            elitism => {
+                // Protect the fittest genotypes from selection, ranking NaN fitness last so it is never protected
+                let mut ranked_indices: Vec<usize> = (0..fitness_scores.len()).collect();
+                ranked_indices.sort_by(|&a, &b| {
+                    let (a, b): (f64, f64) = (fitness_scores[a], fitness_scores[b]);
+                    a.is_nan().cmp(&b.is_nan()).then(b.total_cmp(&a))
+                });
+                ranked_indices.truncate(elitism);
+                self.selection_strategy.select_deaths_protected(&fitness_scores, 1.0 - percentage, &ranked_indices)
+            }
+        };
+
+        // Remove from the back so earlier removals do not shift the indices of later ones, and remove each genotype once
+        selected_indices.sort_unstable_by(|a, b| b.cmp(a));
+        selected_indices.dedup();
         selected_indices.iter().for_each(|&index| {
             self.genotypes.remove(index);
+            self.fitness_cache.remove(index);
         });
 
         Ok(())
@@ -184,6 +526,9 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
 
     /// Grow the population by adding a percentage of new genotypes based on fitness scores using the selection strategy.
     /// 
+    /// The fitness of the population is evaluated once, and every child is then created in parallel from the
+    /// population as it was before growing, so children are never selected as parents of other children in the same batch.
+    /// 
     /// # Arguments
     /// 
     /// - `percentage`: The percentage of the population to grow relative to the current population (0.0 to 1.0).
@@ -203,15 +548,26 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
 
         // Calculate the number of new genotypes to add
         let num_new_genotypes = (self.genotypes.len() as f64 * percentage).round() as usize;
-        for _ in 0..num_new_genotypes {
-            self.add_child(board)?;
+        if num_new_genotypes == 0 {
+            return Ok(());
         }
 
+        // Create every child from the same fitness scores, then add them to the population together
+        let fitness_scores: Vec<f64> = self.cached_fitness_scores(board);
+        let children: Vec<G> = self.child_seeds(num_new_genotypes)
+            .into_par_iter()
+            .map(|seed| self.create_child(&fitness_scores, seed))
+            .collect();
+
+        self.fitness_cache.extend(std::iter::repeat_n(None, children.len()));
+        self.genotypes.extend(children);
         Ok(())
     }
 
     /// Advance the population by one generation by first shrinking it (removing less fit individuals) and then growing it (adding new offspring).
     /// 
+    /// If adaptive mutation is enabled, the mutation rate is adjusted first (see `set_adaptive_mutation`).
+    /// 
     /// # Arguments
     /// 
     /// - `death_percentage`: The percentage of the population to remove (0.0 to 1.0).
@@ -222,6 +578,8 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
     /// 
     /// A result indicating success or failure.
     pub fn advance_generation(&mut self, death_percentage: f64, growth_percentage: f64, board: &Board<S>) -> Result<(), String> {
+        self.adapt_mutation_rate(board);
+
         // First remove less fit individuals
         self.shrink_population(death_percentage, board)?;
         
@@ -232,6 +590,61 @@ impl<S: State, G: Genotype<S>> Population<S, G> {
     }
 }
 
+/// The parts of a population that are saved to a file: its genotypes, selection strategy, and mutation rate.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PopulationRecord<T> {
+    genotypes: T,
+    selection_strategy: SelectionStrategy,
+    mutation_rate: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<S: State, G: Genotype<S> + serde::Serialize + serde::de::DeserializeOwned> Population<S, G> {
+    /// Save the genotypes, selection strategy, and mutation rate of the population to a JSON file.
+    ///
+    /// The remaining settings, the fitness cache, and the random number generator are not saved, so a loaded
+    /// population starts with the defaults of `Population::new`.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the file to write.
+    ///
+    /// # Returns
+    ///
+    /// An error if the population cannot be serialised or the file cannot be written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let record: PopulationRecord<&Vec<G>> = PopulationRecord {
+            genotypes: &self.genotypes,
+            selection_strategy: self.selection_strategy.clone(),
+            mutation_rate: self.mutation_rate,
+        };
+        let json: String = serde_json::to_string(&record).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Load a population saved with `save` from a JSON file.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the file to read.
+    ///
+    /// # Returns
+    ///
+    /// The population described by the file, or an error if the file cannot be read, cannot be parsed, or has a
+    /// mutation rate that is not between 0.0 and 1.0.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let json: String = fs::read_to_string(&path)
+            .map_err(|error| format!("Failed to read {}: {}", path.as_ref().display(), error))?;
+        let record: PopulationRecord<Vec<G>> = serde_json::from_str(&json)
+            .map_err(|error| format!("Failed to parse {}: {}", path.as_ref().display(), error))?;
+        if !(0.0..=1.0).contains(&record.mutation_rate) {
+            return Err(format!("Mutation rate {} in {} is not between 0.0 and 1.0", record.mutation_rate, path.as_ref().display()));
+        }
+        Ok(Self::new(record.genotypes, record.selection_strategy, record.mutation_rate))
+    }
+}
+
 // Implement IntoIterator for Population to allow consuming iteration
 impl<S: State, G: Genotype<S>> IntoIterator for Population<S, G> {
     type Item = G;
@@ -258,6 +671,8 @@ impl<'a, S: State, G: Genotype<S>> IntoIterator for &'a mut Population<S, G> {
     type IntoIter = std::slice::IterMut<'a, G>;
 
     fn into_iter(self) -> Self::IntoIter {
+        // The genotypes may be changed through the iterator, so their cached fitness can no longer be trusted
+        self.clear_fitness_cache();
         self.genotypes.iter_mut()
     }
 }
\ No newline at end of file
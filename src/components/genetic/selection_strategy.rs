@@ -1,8 +1,9 @@
-use rand::{Rng, thread_rng, seq::SliceRandom};
+use rand::{Rng, thread_rng};
 use std::fmt::Debug;
 
 /// Methods for selecting parents from a population
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectionStrategy {
     /// Tournament selection with the given tournament size
     Tournament(usize),
@@ -12,21 +13,31 @@ pub enum SelectionStrategy {
     Rank(f64),
     /// Truncation selection (selecting from top percentage)
     Truncation(f64),
+    /// Stochastic universal sampling (fitness proportionate selection with evenly spaced pointers)
+    StochasticUniversalSampling,
 }
 
 impl SelectionStrategy {
     /// Select two parent indices based on fitness scores
     pub fn select_parents(&self, fitness_scores: &[f64]) -> (usize, usize) {
+        self.select_parents_with_rng(fitness_scores, &mut thread_rng())
+    }
+
+    /// Select two parent indices based on fitness scores, using the given random number generator
+    /// 
+    /// Selection with a seeded random number generator is reproducible: the same generator state and fitness scores
+    /// always select the same parents.
+    pub fn select_parents_with_rng<R: Rng>(&self, fitness_scores: &[f64], rng: &mut R) -> (usize, usize) {
         match self {
-            Self::Tournament(size) => self.tournament_selection(fitness_scores, *size),
-            Self::RouletteWheel => self.roulette_wheel_selection(fitness_scores),
-            Self::Rank(pressure) => self.rank_selection(fitness_scores, *pressure),
-            Self::Truncation(percentage) => self.truncation_selection(fitness_scores, *percentage),
+            Self::Tournament(size) => self.tournament_selection(fitness_scores, *size, rng),
+            Self::RouletteWheel => self.roulette_wheel_selection(fitness_scores, rng),
+            Self::Rank(pressure) => self.rank_selection(fitness_scores, *pressure, rng),
+            Self::Truncation(percentage) => self.truncation_selection(fitness_scores, *percentage, rng),
+            Self::StochasticUniversalSampling => self.stochastic_universal_sampling(fitness_scores, rng),
         }
     }
 
-    fn tournament_selection(&self, fitness_scores: &[f64], tournament_size: usize) -> (usize, usize) {
-        let mut rng: rand::prelude::ThreadRng = thread_rng();
+    fn tournament_selection<R: Rng>(&self, fitness_scores: &[f64], tournament_size: usize, rng: &mut R) -> (usize, usize) {
         let population_size: usize = fitness_scores.len();
         
         // First parent
@@ -60,8 +71,7 @@ impl SelectionStrategy {
         (best_idx1, best_idx2)
     }
 
-    fn roulette_wheel_selection(&self, fitness_scores: &[f64]) -> (usize, usize) {
-        let mut rng = thread_rng();
+    fn roulette_wheel_selection<R: Rng>(&self, fitness_scores: &[f64], rng: &mut R) -> (usize, usize) {
         let total_fitness: f64 = fitness_scores.iter().sum();
         
         // Handle edge case of zero total fitness
@@ -100,8 +110,7 @@ impl SelectionStrategy {
         (parent1, parent2)
     }
     
-    fn rank_selection(&self, fitness_scores: &[f64], selection_pressure: f64) -> (usize, usize) {
-        let mut rng: rand::prelude::ThreadRng = thread_rng();
+    fn rank_selection<R: Rng>(&self, fitness_scores: &[f64], selection_pressure: f64, rng: &mut R) -> (usize, usize) {
         let n: usize = fitness_scores.len();
         
         // Rank individuals by fitness scores
@@ -142,8 +151,7 @@ impl SelectionStrategy {
         (parent1, parent2)
     }
     
-    fn truncation_selection(&self, fitness_scores: &[f64], percentage: f64) -> (usize, usize) {
-        let mut rng: rand::prelude::ThreadRng = thread_rng();
+    fn truncation_selection<R: Rng>(&self, fitness_scores: &[f64], percentage: f64, rng: &mut R) -> (usize, usize) {
         let n: usize = fitness_scores.len();
         
         // Sort indices by fitness scores
@@ -161,130 +169,193 @@ impl SelectionStrategy {
         (parent1, parent2)
     }
 
+    fn stochastic_universal_sampling<R: Rng>(&self, fitness_scores: &[f64], rng: &mut R) -> (usize, usize) {
+        let n: usize = fitness_scores.len();
+        let total_fitness: f64 = fitness_scores.iter().sum();
+
+        // Handle edge case of zero total fitness
+        if total_fitness <= 0.0 {
+            return (rng.gen_range(0..n), rng.gen_range(0..n));
+        }
+
+        // A single spin places two pointers half the total fitness apart
+        let spacing: f64 = total_fitness / 2.0;
+        let start: f64 = rng.gen_range(0.0..spacing);
+        let pointers: [f64; 2] = [start, start + spacing];
+
+        // Walk the cumulative fitness once, falling back to the last individual in case of rounding
+        let mut parents: [usize; 2] = [n - 1; 2];
+        let mut cumulative: f64 = 0.0;
+        let mut pointer: usize = 0;
+        for (i, fitness) in fitness_scores.iter().enumerate() {
+            cumulative += fitness;
+            while pointer < pointers.len() && pointers[pointer] < cumulative {
+                parents[pointer] = i;
+                pointer += 1;
+            }
+            if pointer == pointers.len() {
+                break;
+            }
+        }
+
+        (parents[0], parents[1])
+    }
+
     /// Select indices for death based on fitness scores
     /// 
+    /// Each strategy favours the least fit individuals, and every index is selected at most once.
+    /// 
     /// # Arguments
     /// 
     /// - `fitness_scores`: A slice of fitness scores for the population.
-    /// - `percentage`: The percentage of individuals remaining after selection.
+    /// - `percentage`: The percentage of individuals remaining after selection (0.0 to 1.0).
     /// 
     /// # Returns
     /// 
-    /// A vector of indices representing the individuals selected for death.
+    /// A vector of `n - round(n * percentage)` distinct indices representing the individuals selected for death, where `n` is the population size.
     pub fn select_deaths(&self, fitness_scores: &[f64], percentage: f64) -> Vec<usize> {
         match self {
             Self::Tournament(size) => self.tournament_selection_death(fitness_scores, *size, percentage),
             Self::RouletteWheel => self.roulette_wheel_selection_death(fitness_scores, percentage),
             Self::Rank(pressure) => self.rank_selection_death(fitness_scores, *pressure, percentage),
             Self::Truncation(trunc_percentage) => self.truncation_selection_death(fitness_scores, *trunc_percentage, percentage),
+            // Evenly spaced pointers can land on the same individual more than once, so deaths are drawn one at a time
+            Self::StochasticUniversalSampling => self.roulette_wheel_selection_death(fitness_scores, percentage),
         }
     }
 
-    fn tournament_selection_death(&self, fitness_scores: &[f64], tournament_size: usize, percentage: f64) -> Vec<usize> {
-        let mut rng: rand::prelude::ThreadRng = thread_rng();
-        let population_size: usize = fitness_scores.len();
-        
-        // Select individuals for the tournament
-        let mut selected_indices: Vec<usize> = (0..population_size).collect();
-        selected_indices.shuffle(&mut rng);
-        
-        // Select the best individuals from the tournament
-        let mut best_indices: Vec<usize> = Vec::new();
-        for i in 0..tournament_size {
-            best_indices.push(selected_indices[i]);
+    /// Select indices for death based on fitness scores, never selecting the protected indices
+    /// 
+    /// Deaths are selected by the strategy from the unprotected individuals only. If there are fewer unprotected
+    /// individuals than deaths, every unprotected individual is selected.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `fitness_scores`: A slice of fitness scores for the population.
+    /// - `percentage`: The percentage of individuals remaining after selection (0.0 to 1.0).
+    /// - `protected`: The indices of the individuals that must survive.
+    /// 
+    /// # Returns
+    /// 
+    /// A vector of distinct indices representing the individuals selected for death, none of which are protected.
+    pub fn select_deaths_protected(&self, fitness_scores: &[f64], percentage: f64, protected: &[usize]) -> Vec<usize> {
+        let candidates: Vec<usize> = (0..fitness_scores.len()).filter(|i| !protected.contains(i)).collect();
+        if candidates.is_empty() {
+            return Vec::new();
         }
-        
-        // Sort the best indices by fitness scores
-        best_indices.sort_by(|&a, &b| fitness_scores[b].partial_cmp(&fitness_scores[a]).unwrap());
-        
-        // Select the worst individuals for death
-        let num_deaths: usize = (population_size as f64 * (1.0 - percentage)).round() as usize;
-        best_indices[num_deaths..].to_vec()
+
+        // Select the same number of deaths from the candidates as from the whole population, as far as possible
+        let num_deaths: usize = Self::death_count(fitness_scores.len(), percentage).min(candidates.len());
+        let candidate_scores: Vec<f64> = candidates.iter().map(|&i| fitness_scores[i]).collect();
+        let candidate_percentage: f64 = (candidates.len() - num_deaths) as f64 / candidates.len() as f64;
+
+        self.select_deaths(&candidate_scores, candidate_percentage)
+            .into_iter()
+            .map(|i| candidates[i])
+            .collect()
     }
 
-    fn roulette_wheel_selection_death(&self, fitness_scores: &[f64], percentage: f64) -> Vec<usize> {
+    /// Get the number of deaths that leave the given percentage of the population remaining.
+    fn death_count(population_size: usize, percentage: f64) -> usize {
+        let remaining: usize = (population_size as f64 * percentage).round() as usize;
+        population_size - remaining.min(population_size)
+    }
+
+    /// Select `count` distinct indices at random, with probabilities proportional to the given weights.
+    /// 
+    /// Indices are chosen uniformly from those remaining if all of their weights are zero.
+    fn weighted_sample_without_replacement(weights: &[f64], count: usize) -> Vec<usize> {
         let mut rng: rand::prelude::ThreadRng = thread_rng();
-        let total_fitness: f64 = fitness_scores.iter().sum();
-        
-        // Select individuals for death based on fitness scores
-        let mut selected_indices: Vec<usize> = Vec::new();
-        let num_deaths: usize = (fitness_scores.len() as f64 * (1.0 - percentage)).round() as usize;
-        
-        for _ in 0..num_deaths {
-            let mut spin: f64 = rng.gen_range(0.0..total_fitness);
-            let mut parent: usize = 0;
-            
-            for (i, fitness) in fitness_scores.iter().enumerate() {
-                spin -= fitness;
-                if spin <= 0.0 {
-                    parent = i;
-                    break;
+        let mut remaining: Vec<usize> = (0..weights.len()).collect();
+        let mut selected_indices: Vec<usize> = Vec::with_capacity(count);
+
+        for _ in 0..count.min(weights.len()) {
+            let total_weight: f64 = remaining.iter().map(|&i| weights[i]).sum();
+            let mut position: usize = rng.gen_range(0..remaining.len());
+
+            if total_weight > 0.0 {
+                // Fall back to the last positive weight in case rounding leaves the spin above zero
+                position = remaining.iter().rposition(|&i| weights[i] > 0.0).unwrap();
+                let mut spin: f64 = rng.gen_range(0.0..total_weight);
+                for (j, &i) in remaining.iter().enumerate() {
+                    spin -= weights[i];
+                    if spin <= 0.0 && weights[i] > 0.0 {
+                        position = j;
+                        break;
+                    }
                 }
             }
-            
-            selected_indices.push(parent);
+
+            selected_indices.push(remaining.swap_remove(position));
         }
-        
+
         selected_indices
     }
 
-    fn rank_selection_death(&self, fitness_scores: &[f64], selection_pressure: f64, percentage: f64) -> Vec<usize> {
+    fn tournament_selection_death(&self, fitness_scores: &[f64], tournament_size: usize, percentage: f64) -> Vec<usize> {
         let mut rng: rand::prelude::ThreadRng = thread_rng();
-        let n: usize = fitness_scores.len();
-        
-        // Rank individuals by fitness scores
-        let mut ranked_indices: Vec<usize> = (0..n).collect();
-        ranked_indices.sort_by(|&a, &b| fitness_scores[b].partial_cmp(&fitness_scores[a]).unwrap());
-        
-        // Calculate selection probabilities based on ranks
-        let total_rank: f64 = (1..=n).map(|i| i as f64).sum();
-        let probabilities: Vec<f64> = ranked_indices.iter()
-            .map(|&idx| (n - idx) as f64 / total_rank * selection_pressure)
-            .collect();
-        
-        // Select individuals for death based on probabilities
-        let num_deaths: usize = (n as f64 * (1.0 - percentage)).round() as usize;
-        let mut selected_indices: Vec<usize> = Vec::new();
-        
+        let num_deaths: usize = Self::death_count(fitness_scores.len(), percentage);
+
+        let mut alive: Vec<usize> = (0..fitness_scores.len()).collect();
+        let mut selected_indices: Vec<usize> = Vec::with_capacity(num_deaths);
+
         for _ in 0..num_deaths {
-            let mut spin: f64 = rng.gen_range(0.0..1.0);
-            let mut parent: usize = 0;
-            
-            for (i, prob) in probabilities.iter().enumerate() {
-                spin -= prob;
-                if spin <= 0.0 {
-                    parent = i;
-                    break;
-                }
-            }
-            
-            selected_indices.push(parent);
+            // Hold a tournament between distinct survivors and select the least fit
+            let size: usize = tournament_size.clamp(1, alive.len());
+            let worst: usize = rand::seq::index::sample(&mut rng, alive.len(), size)
+                .into_iter()
+                .min_by(|&a, &b| fitness_scores[alive[a]].partial_cmp(&fitness_scores[alive[b]]).unwrap())
+                .unwrap();
+
+            selected_indices.push(alive.swap_remove(worst));
         }
-        
+
         selected_indices
     }
 
+    fn roulette_wheel_selection_death(&self, fitness_scores: &[f64], percentage: f64) -> Vec<usize> {
+        let num_deaths: usize = Self::death_count(fitness_scores.len(), percentage);
+
+        // The chance of death is proportional to how far an individual falls short of the fittest
+        let max_fitness: f64 = fitness_scores.iter().cloned().fold(f64::MIN, f64::max);
+        let weights: Vec<f64> = fitness_scores.iter().map(|fitness| max_fitness - fitness).collect();
+
+        Self::weighted_sample_without_replacement(&weights, num_deaths)
+    }
+
+    fn rank_selection_death(&self, fitness_scores: &[f64], selection_pressure: f64, percentage: f64) -> Vec<usize> {
+        let n: usize = fitness_scores.len();
+        let num_deaths: usize = Self::death_count(n, percentage);
+
+        // Rank individuals from least to most fit
+        let mut ranked_indices: Vec<usize> = (0..n).collect();
+        ranked_indices.sort_by(|&a, &b| fitness_scores[a].partial_cmp(&fitness_scores[b]).unwrap());
+
+        // The least fit individual has the highest weight, and a higher selection pressure favours it more strongly
+        let mut weights: Vec<f64> = vec![0.0; n];
+        for (rank, &index) in ranked_indices.iter().enumerate() {
+            weights[index] = ((n - rank) as f64).powf(selection_pressure);
+        }
+
+        Self::weighted_sample_without_replacement(&weights, num_deaths)
+    }
+
     fn truncation_selection_death(&self, fitness_scores: &[f64], trunc_percentage: f64, percentage: f64) -> Vec<usize> {
         let mut rng: rand::prelude::ThreadRng = thread_rng();
         let n: usize = fitness_scores.len();
-        
-        // Sort indices by fitness scores
+        let num_deaths: usize = Self::death_count(n, percentage);
+
+        // Sort indices from least to most fit
         let mut indices: Vec<usize> = (0..n).collect();
-        indices.sort_by(|&a, &b| fitness_scores[b].partial_cmp(&fitness_scores[a]).unwrap());
-        
-        // Select top percentage of individuals
+        indices.sort_by(|&a, &b| fitness_scores[a].partial_cmp(&fitness_scores[b]).unwrap());
+
+        // Deaths are drawn from the individuals outside the top percentage, widened if there are not enough of them
         let cutoff: usize = (n as f64 * trunc_percentage).round() as usize;
-        let selected_indices: Vec<usize> = indices[..cutoff].to_vec();
-        
-        // Select individuals for death based on fitness scores
-        let num_deaths: usize = (n as f64 * (1.0 - percentage)).round() as usize;
-        let mut selected_for_death: Vec<usize> = Vec::new();
-        
-        for _ in 0..num_deaths {
-            let parent: usize = selected_indices[rng.gen_range(0..cutoff)];
-            selected_for_death.push(parent);
-        }
-        
-        selected_for_death
+        let pool_size: usize = (n - cutoff.min(n)).max(num_deaths);
+
+        rand::seq::index::sample(&mut rng, pool_size, num_deaths)
+            .into_iter()
+            .map(|i| indices[i])
+            .collect()
     }
 }
@@ -1,5 +1,7 @@
 pub mod state;
 pub mod board;
+pub mod fixed_board;
+pub mod pattern;
 pub mod neighbourhood;
 pub mod error;
 pub mod rule;
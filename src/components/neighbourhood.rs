@@ -1,15 +1,148 @@
-use super::board::{Board, BoundaryCondition};
-use super::state::State;
+use super::board::{reflect, Board, BoundaryCondition};
+use super::state::{ContinuousState, State};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
 
 /// The type of neighbourhood to use for a cellular automaton, which determines the cells to consider when updating a cell.
 ///
 /// The neighbourhood types are:
 /// - VonNeumann: The four cells directly adjacent to the cell.
 /// - Moore: The eight cells directly adjacent to the cell.
+/// - Circular: The cells whose Euclidean distance from the cell is at most the radius.
+/// - Custom: The cells at the given `(dx, dy)` offsets from the cell, in the given order.
+/// - Provider: The cells given by a `NeighbourhoodProvider`, such as a neighbourhood on a hexagonal grid.
 pub enum NeighbourhoodType {
     VonNeumann,
     Moore,
+    Circular,
+    Custom(Vec<(isize, isize)>),
+    Provider(Box<dyn NeighbourhoodProvider>),
+}
+
+/// A trait that provides the shape of a neighbourhood, independent of the topology of the grid.
+///
+/// A provider only describes which cells are in the neighbourhood, as `(dx, dy)` offsets from the cell. The boundary
+/// handling and caching are done by `Neighbourhood`, so every provider shares them.
+pub trait NeighbourhoodProvider: Send + Sync {
+    /// Get the offsets of the cells in the neighbourhood of the cell at the given coordinates.
+    ///
+    /// Square grids return the same offsets for every cell, while other topologies stored on a square board (such as
+    /// hexagonal grids in offset coordinates) may return different offsets depending on the cell.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(dx, dy)` offsets, in the order the neighbourhood is returned.
+    fn offsets(&self, x: usize, y: usize) -> Vec<(isize, isize)>;
+
+    /// Get the radius of the neighbourhood, which is the largest absolute component of any offset.
+    fn radius(&self) -> usize;
+}
+
+/// The offsets of every cell in the square of the given radius, ordered by `dx` and then `dy`.
+fn square_offsets(radius: usize) -> impl Iterator<Item = (isize, isize)> {
+    let radius: isize = radius as isize;
+    (-radius..=radius).flat_map(move |dx| (-radius..=radius).map(move |dy| (dx, dy)))
+}
+
+/// A Von Neumann neighbourhood on a square grid: the cells within the given Manhattan distance.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VonNeumannProvider {
+    pub radius: usize,
+}
+
+impl NeighbourhoodProvider for VonNeumannProvider {
+    fn offsets(&self, _x: usize, _y: usize) -> Vec<(isize, isize)> {
+        let radius: isize = self.radius as isize;
+        square_offsets(self.radius)
+            .filter(|(dx, dy)| dx.abs() + dy.abs() <= radius)
+            .collect()
+    }
+
+    fn radius(&self) -> usize {
+        self.radius
+    }
+}
+
+/// A Moore neighbourhood on a square grid: the cells within the given Chebyshev distance.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MooreProvider {
+    pub radius: usize,
+}
+
+impl NeighbourhoodProvider for MooreProvider {
+    fn offsets(&self, _x: usize, _y: usize) -> Vec<(isize, isize)> {
+        square_offsets(self.radius).collect()
+    }
+
+    fn radius(&self) -> usize {
+        self.radius
+    }
+}
+
+/// A circular neighbourhood on a square grid: the cells within the given Euclidean distance.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CircularProvider {
+    pub radius: usize,
+}
+
+impl NeighbourhoodProvider for CircularProvider {
+    fn offsets(&self, _x: usize, _y: usize) -> Vec<(isize, isize)> {
+        let radius: isize = self.radius as isize;
+        square_offsets(self.radius)
+            .filter(|(dx, dy)| dx * dx + dy * dy <= radius * radius)
+            .collect()
+    }
+
+    fn radius(&self) -> usize {
+        self.radius
+    }
+}
+
+/// A neighbourhood given by an arbitrary list of offsets, the same for every cell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OffsetProvider {
+    pub offsets: Vec<(isize, isize)>,
+}
+
+impl NeighbourhoodProvider for OffsetProvider {
+    fn offsets(&self, _x: usize, _y: usize) -> Vec<(isize, isize)> {
+        self.offsets.clone()
+    }
+
+    fn radius(&self) -> usize {
+        self.offsets
+            .iter()
+            .map(|&(dx, dy)| dx.unsigned_abs().max(dy.unsigned_abs()))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The six adjacent cells of a hexagonal grid stored on a square board in "odd-r" offset coordinates, where odd
+/// rows are shifted half a cell to the right.
+///
+/// The offsets depend on the parity of the row, ordered clockwise from the upper-left neighbour. For periodic
+/// boundaries to wrap correctly, the board should have an even height.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct HexagonalProvider;
+
+impl NeighbourhoodProvider for HexagonalProvider {
+    fn offsets(&self, _x: usize, y: usize) -> Vec<(isize, isize)> {
+        match y % 2 {
+            0 => vec![(-1, -1), (0, -1), (1, 0), (0, 1), (-1, 1), (-1, 0)],
+            _ => vec![(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 0)],
+        }
+    }
+
+    fn radius(&self) -> usize {
+        1
+    }
 }
 
 /// A struct that defines the neighbourhood of a cell in a cellular automaton.
@@ -19,19 +152,59 @@ pub enum NeighbourhoodType {
 ///
 /// - `neighbourhood_type`: The type of neighbourhood to use.
 /// - `radius`: The radius of the neighbourhood.
+/// - `exclude_centre`: Whether the cell itself (the offset `(0, 0)`) is left out of the neighbourhood.
 /// 
+/// The cache is behind a lock, so a neighbourhood can be queried through a shared reference and shared between
+/// threads, such as rayon tasks evaluating a rule over a board.
+///
 /// # Warning
 /// 
-/// Sharing a neighbourhood instance between multiple boards can lead to unexpected behaviour due to caching.
+/// The cache only holds the neighbourhoods of one board size and boundary condition at a time. Sharing a
+/// neighbourhood instance between boards that differ in either gives correct results, but the cache is cleared every
+/// time the board changes, so each board is better served by its own instance.
 pub struct Neighbourhood {
     /// The type of neighbourhood to use.
     pub neighbourhood_type: NeighbourhoodType,
     /// The radius of the neighbourhood.
     pub radius: usize,
-    /// Cache of the dimensions and boundary type of the board
-    board_cache: ((usize, usize), String),
-    /// Cache of the neighbourhoods of each cell
-    neighbour_cache: HashMap<(usize, usize), Vec<Option<(usize, usize)>>>,
+    /// Whether the cell itself (the offset `(0, 0)`) is left out of the neighbourhood.
+    pub exclude_centre: bool,
+    /// Cache of the neighbourhoods of each cell, and the board they were computed for
+    cache: RwLock<NeighbourCache>,
+    /// Number of lookups answered from the cache since it was last cleared
+    cache_hits: AtomicUsize,
+    /// Number of lookups computed and added to the cache since it was last cleared
+    cache_misses: AtomicUsize,
+}
+
+/// The kind of boundary condition of a board, without the state of a fixed boundary.
+///
+/// The coordinates of a neighbourhood depend only on the kind of boundary, so this is all the cache needs to compare.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum BoundaryKind {
+    #[default]
+    Periodic,
+    Fixed,
+    Reflective,
+}
+
+impl<S: State> From<&BoundaryCondition<S>> for BoundaryKind {
+    fn from(boundary_condition: &BoundaryCondition<S>) -> Self {
+        match boundary_condition {
+            BoundaryCondition::Periodic => BoundaryKind::Periodic,
+            BoundaryCondition::Fixed(_) => BoundaryKind::Fixed,
+            BoundaryCondition::Reflective => BoundaryKind::Reflective,
+        }
+    }
+}
+
+/// A private struct that holds the cached neighbourhoods of a `Neighbourhood`.
+#[derive(Default)]
+struct NeighbourCache {
+    /// The dimensions and boundary kind of the board, and whether the centre was excluded
+    board: ((usize, usize), BoundaryKind, bool),
+    /// The neighbourhoods of each cell
+    neighbours: HashMap<(usize, usize), Vec<Option<(usize, usize)>>>,
 }
 
 impl Neighbourhood {
@@ -40,13 +213,117 @@ impl Neighbourhood {
         Self {
             neighbourhood_type,
             radius,
-            board_cache: ((0, 0), String::new()),
-            neighbour_cache: HashMap::new(),
+            exclude_centre: false,
+            cache: RwLock::new(NeighbourCache::default()),
+            cache_hits: AtomicUsize::new(0),
+            cache_misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Create a new `Neighbourhood` with the given type and radius that leaves out the cell itself.
+    ///
+    /// The offset `(0, 0)` is removed from the offsets of the neighbourhood, so every query returns only the
+    /// neighbours of the cell, in the same order as they would otherwise be returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `neighbourhood_type`: The type of neighbourhood to use.
+    /// - `radius`: The radius of the neighbourhood.
+    pub fn new_excluding_centre(neighbourhood_type: NeighbourhoodType, radius: usize) -> Self {
+        Self {
+            exclude_centre: true,
+            ..Self::new(neighbourhood_type, radius)
         }
     }
 
+    /// Get the number of cache hits and misses of the neighbourhood, to check how effective caching is.
+    ///
+    /// The counts are reset whenever the cache is cleared, which happens when the neighbourhood is used with a board
+    /// of different dimensions or boundary condition.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the number of lookups answered from the cache and the number of lookups that had to be computed.
+    pub fn cache_stats(&self) -> (usize, usize) {
+        (self.cache_hits.load(Ordering::Relaxed), self.cache_misses.load(Ordering::Relaxed))
+    }
+
+    /// Create a new `Neighbourhood` with the given type and radius, checking that it fits on the given board.
+    ///
+    /// Under periodic boundaries, a neighbourhood whose diameter (`2 * radius + 1`) is larger than the width or height
+    /// of the board wraps around onto the same cells more than once, which would count those cells several times.
+    ///
+    /// # Arguments
+    ///
+    /// - `neighbourhood_type`: The type of neighbourhood to use.
+    /// - `radius`: The radius of the neighbourhood.
+    /// - `board`: The board the neighbourhood will be used with.
+    ///
+    /// # Returns
+    ///
+    /// The neighbourhood, or an error if the board is periodic and the neighbourhood would wrap onto the same cells.
+    pub fn new_checked<S: State>(neighbourhood_type: NeighbourhoodType, radius: usize, board: &Board<S>) -> Result<Self, String> {
+        let diameter: usize = 2 * radius + 1;
+        if matches!(board.boundary_condition(), BoundaryCondition::Periodic) && diameter > board.width().min(board.height()) {
+            return Err(format!(
+                "A neighbourhood of radius {} wraps onto the same cells of a periodic {}x{} board",
+                radius,
+                board.width(),
+                board.height()
+            ));
+        }
+        Ok(Self::new(neighbourhood_type, radius))
+    }
+
+    /// Create a new `Neighbourhood` from an arbitrary list of offsets, such as a knight's-move or asymmetric stencil.
+    ///
+    /// The offsets go through the same boundary handling and caching as the preset neighbourhoods, and the neighbourhood
+    /// coordinates are returned in the same order as the offsets. The radius is the largest absolute component of any offset.
+    ///
+    /// # Arguments
+    ///
+    /// - `offsets`: A vector of `(dx, dy)` offsets from the cell. Include `(0, 0)` to include the cell itself.
+    pub fn from_offsets(offsets: Vec<(isize, isize)>) -> Self {
+        let radius: usize = OffsetProvider { offsets: offsets.clone() }.radius();
+        Self::new(NeighbourhoodType::Custom(offsets), radius)
+    }
+
+    /// Create a new `Neighbourhood` from a `NeighbourhoodProvider`, such as a `HexagonalProvider`.
+    ///
+    /// # Arguments
+    ///
+    /// - `provider`: The provider of the shape of the neighbourhood. The radius is taken from the provider.
+    pub fn from_provider<P: NeighbourhoodProvider + 'static>(provider: P) -> Self {
+        let radius: usize = provider.radius();
+        Self::new(NeighbourhoodType::Provider(Box::new(provider)), radius)
+    }
+
+    /// Get the `(dx, dy)` offsets of the cells in the neighbourhood of a cell, in the order the neighbourhood is returned.
+    ///
+    /// The square neighbourhoods (`VonNeumann`, `Moore` and `Circular`) are ordered by `dx` and then `dy`, and include
+    /// the cell itself at `(0, 0)` unless `exclude_centre` is set, which removes `(0, 0)` from every type of
+    /// neighbourhood. This is the order that weights passed to `get_weighted_states` must follow.
+    pub fn offsets(&self, x: usize, y: usize) -> Vec<(isize, isize)> {
+        let radius: usize = self.radius;
+        let mut offsets: Vec<(isize, isize)> = match &self.neighbourhood_type {
+            NeighbourhoodType::VonNeumann => VonNeumannProvider { radius }.offsets(x, y),
+            NeighbourhoodType::Moore => MooreProvider { radius }.offsets(x, y),
+            NeighbourhoodType::Circular => CircularProvider { radius }.offsets(x, y),
+            NeighbourhoodType::Custom(offsets) => offsets.clone(),
+            NeighbourhoodType::Provider(provider) => provider.offsets(x, y),
+        };
+        if self.exclude_centre {
+            offsets.retain(|&offset| offset != (0, 0));
+        }
+        offsets
+    }
+
     /// Get the neighbourhood of a cell on a board.
     ///
+    /// Under periodic boundaries, a neighbourhood whose diameter (`2 * radius + 1`) is larger than the width or height
+    /// of the board wraps around onto the same cells more than once, so those cells appear several times. Use
+    /// `Neighbourhood::new_checked` to reject such neighbourhoods up front.
+    ///
     /// # Arguments
     ///
     /// - `board`: The board to get the neighbourhood from.
@@ -61,79 +338,69 @@ impl Neighbourhood {
     ///
     /// A vector of the coordinates of the cells in the neighbourhood.
     pub fn get_neighbourhood_coords<S: State>(
-        &mut self,
+        &self,
         board: &Board<S>,
         x: usize,
         y: usize,
     ) -> Vec<Option<(usize, usize)>> {
-        let boundary_condition: BoundaryCondition<S> = board.boundary_condition();
-        let mut neighbourhood: Vec<Option<(usize, usize)>> =
-            Vec::with_capacity((2 * self.radius + 1) * (2 * self.radius + 1) - 1);
-        let (width, height) = (board.width(), board.height());
+        let board_key: ((usize, usize), BoundaryKind, bool) =
+            (board.dimensions(), BoundaryKind::from(&board.boundary_condition()), self.exclude_centre);
 
-        // Clear the cache if the board dimensions have changed
-        if self.board_cache != ((width, height), boundary_condition.to_string()) {
-            self.neighbour_cache.clear();
-            self.board_cache = ((width, height), boundary_condition.to_string());
+        // Check if the neighbourhood is in the cache. If it is, return the cached neighbourhood.
+        {
+            let cache = self.cache.read().unwrap();
+            if cache.board == board_key {
+                if let Some(neighbours) = cache.neighbours.get(&(x, y)) {
+                    self.cache_hits.fetch_add(1, Ordering::Relaxed);
+                    return neighbours.clone();
+                }
+            }
         }
 
-        // Check if the neighbourhood is in the cache. If it is, return the cached neighbourhood.
-        if let Some(neighbours) = self.neighbour_cache.get(&(x, y)) {
-            return neighbours.clone();
+        let neighbourhood: Vec<Option<(usize, usize)>> = self
+            .offsets(x, y)
+            .into_iter()
+            .map(|(dx, dy)| Self::resolve_offset(board, x, y, dx, dy))
+            .collect();
+
+        let mut cache = self.cache.write().unwrap();
+
+        // Clear the cache if the board dimensions or the centre exclusion have changed
+        if cache.board != board_key {
+            cache.neighbours.clear();
+            cache.board = board_key;
+            self.cache_hits.store(0, Ordering::Relaxed);
+            self.cache_misses.store(0, Ordering::Relaxed);
         }
 
-        match self.neighbourhood_type {
-            NeighbourhoodType::VonNeumann => {
-                for i in (x as isize - self.radius as isize)..=(x as isize + self.radius as isize) {
-                    for j in
-                        (y as isize - self.radius as isize)..=(y as isize + self.radius as isize)
-                    {
-                        if (i - x as isize).abs() + (j - y as isize).abs() > self.radius as isize {
-                            continue;
-                        }
-                        match boundary_condition {
-                            BoundaryCondition::Periodic => {
-                                let nx = i.rem_euclid(width as isize) as usize;
-                                let ny = j.rem_euclid(height as isize) as usize;
-                                neighbourhood.push(Some((nx, ny)));
-                            }
-                            BoundaryCondition::Fixed(_) => {
-                                if i < 0 || j < 0 || i >= width as isize || j >= height as isize {
-                                    neighbourhood.push(None);
-                                } else {
-                                    neighbourhood.push(Some((i as usize, j as usize)));
-                                }
-                            }
-                        }
-                    }
-                }
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        cache.neighbours.insert((x, y), neighbourhood.clone());
+        neighbourhood
+    }
+
+    /// Resolve the cell at an offset from a cell according to the boundary condition of the board.
+    ///
+    /// # Returns
+    ///
+    /// The coordinates of the cell, or None if it is outside a board with fixed boundaries.
+    pub(crate) fn resolve_offset<S: State>(board: &Board<S>, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let (width, height) = (board.width(), board.height());
+        let (i, j) = (x as isize + dx, y as isize + dy);
+        match board.boundary_condition() {
+            BoundaryCondition::Periodic => {
+                let nx = i.rem_euclid(width as isize) as usize;
+                let ny = j.rem_euclid(height as isize) as usize;
+                Some((nx, ny))
             }
-            NeighbourhoodType::Moore => {
-                for i in (x as isize - self.radius as isize)..=(x as isize + self.radius as isize) {
-                    for j in
-                        (y as isize - self.radius as isize)..=(y as isize + self.radius as isize)
-                    {
-                        match boundary_condition {
-                            BoundaryCondition::Periodic => {
-                                let nx = i.rem_euclid(width as isize) as usize;
-                                let ny = j.rem_euclid(height as isize) as usize;
-                                neighbourhood.push(Some((nx, ny)));
-                            }
-                            BoundaryCondition::Fixed(_) => {
-                                if i < 0 || j < 0 || i >= width as isize || j >= height as isize {
-                                    neighbourhood.push(None);
-                                } else {
-                                    neighbourhood.push(Some((i as usize, j as usize)));
-                                }
-                            }
-                        }
-                    }
+            BoundaryCondition::Fixed(_) => {
+                if i < 0 || j < 0 || i >= width as isize || j >= height as isize {
+                    None
+                } else {
+                    Some((i as usize, j as usize))
                 }
             }
+            BoundaryCondition::Reflective => Some((reflect(i, width), reflect(j, height))),
         }
-
-        self.neighbour_cache.insert((x, y), neighbourhood.clone());
-        neighbourhood
     }
 
     /// Get the states of the cells in the neighbourhood of a cell on a board.
@@ -154,7 +421,7 @@ impl Neighbourhood {
     /// If a cell is out of bounds, the state will be `None`.
     /// The order of the states is the same as the order of the cells in the neighbourhood.
     pub fn get_neighbourhood_states<S: State>(
-        &mut self,
+        &self,
         board: &Board<S>,
         x: usize,
         y: usize,
@@ -190,7 +457,7 @@ impl Neighbourhood {
     /// 
     /// A vector of tuples containing the states and relative coordinates of the cells in the neighbourhood.
     pub fn get_neighbourhood_states_coords<S: State>(
-        &mut self,
+        &self,
         board: &Board<S>,
         x: usize,
         y: usize,
@@ -214,4 +481,214 @@ impl Neighbourhood {
         }
         neighbourhood_states_and_coords
     }
+
+    /// Count the cells in the neighbourhood of a cell on a board whose states satisfy a predicate.
+    ///
+    /// The cell itself (the offset `(0, 0)`) is never counted, even when the neighbourhood includes it, so
+    /// the count is the number of matching neighbours. As in `get_neighbourhood_states`, cells outside a board with
+    /// fixed boundaries take the fixed state, and cells without a state are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to count the neighbours on.
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `pred`: A function that returns whether a state should be counted, such as whether a cell is alive.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Returns
+    ///
+    /// The number of neighbours of the cell whose states satisfy the predicate.
+    pub fn count_matching<S: State>(&self, board: &Board<S>, x: usize, y: usize, pred: impl Fn(&S) -> bool) -> usize {
+        self.offsets(x, y)
+            .into_iter()
+            .zip(self.get_neighbourhood_states(board, x, y))
+            .filter(|&(offset, state)| offset != (0, 0) && state.is_some_and(|state| pred(&state)))
+            .count()
+    }
+
+    /// Get the states of the cells in the neighbourhood of a cell on a board, each paired with a weight.
+    ///
+    /// The weights are matched to the neighbourhood in the order given by `offsets`, so weights can express rules that
+    /// depend on the direction or distance of each neighbour, such as convolution kernels.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to get the neighbourhood states from.
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `weights`: The weight of each cell in the neighbourhood, in the order given by `offsets`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the states of the cells in the neighbourhood and their weights, as in `get_neighbourhood_states`, or
+    /// an error if the number of weights is not the size of the neighbourhood.
+    pub fn get_weighted_states<S: State>(
+        &self,
+        board: &Board<S>,
+        x: usize,
+        y: usize,
+        weights: &[f64],
+    ) -> Result<Vec<(Option<S>, f64)>, String> {
+        let states: Vec<Option<S>> = self.get_neighbourhood_states(board, x, y);
+        if states.len() != weights.len() {
+            return Err(format!(
+                "Expected {} weights for the neighbourhood of ({}, {}), but got {}",
+                states.len(),
+                x,
+                y,
+                weights.len()
+            ));
+        }
+        Ok(states.into_iter().zip(weights.iter().copied()).collect())
+    }
+
+    /// Get the weighted sum of the values of the cells in the neighbourhood of a cell on a board.
+    ///
+    /// Cells without a state, which are outside a board without fixed boundaries, do not contribute to the sum.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to get the neighbourhood states from.
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `weights`: The weight of each cell in the neighbourhood, in the order given by `offsets`.
+    /// - `value`: A function that returns the numeric value of a state, such as 1.0 for alive and 0.0 for dead.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the value of each cell multiplied by its weight, or an error if the number of weights is not the
+    /// size of the neighbourhood.
+    pub fn get_weighted_sum<S: State, F: Fn(S) -> f64>(
+        &self,
+        board: &Board<S>,
+        x: usize,
+        y: usize,
+        weights: &[f64],
+        value: F,
+    ) -> Result<f64, String> {
+        Ok(self
+            .get_weighted_states(board, x, y, weights)?
+            .into_iter()
+            .filter_map(|(state, weight)| state.map(|state| value(state) * weight))
+            .sum())
+    }
+
+    /// Get the average value of the cells in the neighbourhood of a cell on a board with continuous states.
+    ///
+    /// This is the averaging kernel of continuous automata: each cell with a state contributes equally, cells outside
+    /// a board with fixed boundaries take the fixed state, and cells without a state are left out of the average.
+    /// Use `Neighbourhood::new_excluding_centre` to average over the neighbours only, and `get_weighted_sum` with
+    /// `ContinuousState::value` for kernels with unequal weights.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to get the neighbourhood states from.
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of continuous state that each cell in the board can have.
+    ///
+    /// # Returns
+    ///
+    /// The mean value of the cells in the neighbourhood, or None if no cell in the neighbourhood has a state.
+    pub fn get_neighbourhood_average<S: ContinuousState>(&self, board: &Board<S>, x: usize, y: usize) -> Option<f64> {
+        let values: Vec<f64> = self
+            .get_neighbourhood_states(board, x, y)
+            .into_iter()
+            .flatten()
+            .map(ContinuousState::value)
+            .collect();
+        match values.len() {
+            0 => None,
+            n => Some(values.iter().sum::<f64>() / n as f64),
+        }
+    }
+
+    /// Get the `(dx, dy)` offsets of the cells between two radii of a cell, ordered by `dx` and then `dy`.
+    ///
+    /// Distances are measured with the metric of the neighbourhood type: Manhattan distance for `VonNeumann`, Chebyshev
+    /// distance for `Moore`, and Euclidean distance for `Circular`. Custom and provider neighbourhoods keep their own
+    /// offsets whose Chebyshev distance is between the radii.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `inner_radius`: The smallest distance of a cell in the ring.
+    /// - `outer_radius`: The largest distance of a cell in the ring.
+    pub fn ring_offsets(&self, x: usize, y: usize, inner_radius: usize, outer_radius: usize) -> Vec<(isize, isize)> {
+        let (inner, outer): (isize, isize) = (inner_radius as isize, outer_radius as isize);
+        let within = |distance: isize| inner <= distance && distance <= outer;
+        match &self.neighbourhood_type {
+            NeighbourhoodType::VonNeumann => square_offsets(outer_radius)
+                .filter(|(dx, dy)| within(dx.abs() + dy.abs()))
+                .collect(),
+            NeighbourhoodType::Moore => square_offsets(outer_radius)
+                .filter(|(dx, dy)| within(dx.abs().max(dy.abs())))
+                .collect(),
+            NeighbourhoodType::Circular => square_offsets(outer_radius)
+                .filter(|(dx, dy)| inner * inner <= dx * dx + dy * dy && dx * dx + dy * dy <= outer * outer)
+                .collect(),
+            NeighbourhoodType::Custom(_) | NeighbourhoodType::Provider(_) => self
+                .offsets(x, y)
+                .into_iter()
+                .filter(|(dx, dy)| within(dx.abs().max(dy.abs())))
+                .collect(),
+        }
+    }
+
+    /// Get the states of the cells between two radii of a cell on a board, such as an annulus around the cell.
+    ///
+    /// This allows a rule to query several distances with a single neighbourhood, such as the immediate neighbours
+    /// and a wider ring. Rings are not cached, unlike the neighbourhood itself.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The board to get the ring states from.
+    /// - `x`: The x-coordinate of the cell.
+    /// - `y`: The y-coordinate of the cell.
+    /// - `inner_radius`: The smallest distance of a cell in the ring, or 0 to include the cell itself.
+    /// - `outer_radius`: The largest distance of a cell in the ring.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the states of the cells in the ring, in the order given by `ring_offsets`. As with
+    /// `get_neighbourhood_states`, cells outside a board with fixed boundaries take the fixed state.
+    pub fn get_ring_states<S: State>(
+        &self,
+        board: &Board<S>,
+        x: usize,
+        y: usize,
+        inner_radius: usize,
+        outer_radius: usize,
+    ) -> Vec<Option<S>> {
+        self.ring_offsets(x, y, inner_radius, outer_radius)
+            .into_iter()
+            .map(|(dx, dy)| match Self::resolve_offset(board, x, y, dx, dy) {
+                Some((nx, ny)) => board.get(nx, ny),
+                None => match board.boundary_condition() {
+                    BoundaryCondition::Fixed(val) => Some(val),
+                    _ => None,
+                },
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,364 @@
+use super::board::{Board, BoundaryCondition};
+use super::error::ParseError;
+use super::state::common_states::GameOfLifeState;
+use super::state::State;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The maximum length of a line in the body of an RLE pattern written by `to_rle`.
+const RLE_LINE_LENGTH: usize = 70;
+
+impl Board<GameOfLifeState> {
+    /// Create a board from a pattern in RLE (Run Length Encoded) format.
+    ///
+    /// The pattern consists of optional `#` comment lines, a header of the form `x = <width>, y = <height>` (optionally
+    /// followed by `, rule = ...`, which is ignored), and a body of `b` (dead), `o` (alive) and `$` (end of row) tokens,
+    /// each optionally preceded by a run count, terminated by `!`. Cells not covered by the pattern are `Dead`.
+    ///
+    /// # Arguments
+    ///
+    /// - `rle`: The pattern in RLE format.
+    /// - `boundary_condition`: The boundary condition of the board.
+    ///
+    /// # Returns
+    ///
+    /// The board described by the pattern, or an error if the pattern is malformed.
+    pub fn from_rle(rle: &str, boundary_condition: BoundaryCondition<GameOfLifeState>) -> Result<Self, ParseError> {
+        let mut lines = rle
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+
+        let header: &str = lines.next().ok_or_else(|| ParseError {
+            message: "Missing RLE header".to_string(),
+        })?;
+        let (width, height) = parse_rle_header(header)?;
+
+        let mut cells: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; width]; height];
+        let (mut x, mut y): (usize, usize) = (0, 0);
+        let mut count: Option<usize> = None;
+        let mut terminated: bool = false;
+
+        for c in lines.flat_map(|line| line.chars()) {
+            if c.is_whitespace() {
+                continue;
+            }
+            if let Some(digit) = c.to_digit(10) {
+                let next: Option<usize> = count
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(digit as usize));
+                count = Some(next.ok_or_else(|| ParseError {
+                    message: "Run count is too large".to_string(),
+                })?);
+                continue;
+            }
+
+            let run: usize = count.take().unwrap_or(1);
+            if run == 0 {
+                return Err(ParseError {
+                    message: format!("Run count of zero before {:?}", c),
+                });
+            }
+
+            match c {
+                'b' | 'o' => {
+                    if y >= height || x + run > width {
+                        return Err(ParseError {
+                            message: format!("Run of {} {:?} at ({}, {}) exceeds the {}x{} pattern", run, c, x, y, width, height),
+                        });
+                    }
+                    if c == 'o' {
+                        cells[y][x..x + run].fill(GameOfLifeState::Alive);
+                    }
+                    x += run;
+                }
+                '$' => {
+                    y += run;
+                    x = 0;
+                }
+                '!' => {
+                    terminated = true;
+                    break;
+                }
+                _ => {
+                    return Err(ParseError {
+                        message: format!("Unexpected character {:?} in RLE body", c),
+                    })
+                }
+            }
+        }
+
+        if count.is_some() {
+            return Err(ParseError {
+                message: "Run count is not followed by a tag".to_string(),
+            });
+        }
+        if !terminated {
+            return Err(ParseError {
+                message: "RLE body is not terminated by '!'".to_string(),
+            });
+        }
+
+        Ok(Board::new(cells, boundary_condition))
+    }
+
+    /// Encode the board as a pattern in RLE (Run Length Encoded) format.
+    ///
+    /// The header records the dimensions of the board and the Game of Life rule. Trailing dead cells in a row and
+    /// trailing empty rows are omitted, and body lines are wrapped at 70 characters.
+    ///
+    /// # Returns
+    ///
+    /// The board encoded as an RLE pattern, which `from_rle` reads back into the same board.
+    pub fn to_rle(&self) -> String {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut pending_rows: usize = 0;
+
+        for y in 0..self.height() {
+            if y > 0 {
+                pending_rows += 1;
+            }
+
+            // Collect the runs of the row, dropping the trailing run of dead cells
+            let mut runs: Vec<(usize, char)> = Vec::new();
+            for x in 0..self.width() {
+                let tag: char = match self.get(x, y) {
+                    Some(GameOfLifeState::Alive) => 'o',
+                    _ => 'b',
+                };
+                match runs.last_mut() {
+                    Some((run, last)) if *last == tag => *run += 1,
+                    _ => runs.push((1, tag)),
+                }
+            }
+            if let Some((_, 'b')) = runs.last() {
+                runs.pop();
+            }
+
+            if runs.is_empty() {
+                continue;
+            }
+            if pending_rows > 0 {
+                tokens.push(rle_token(pending_rows, '$'));
+                pending_rows = 0;
+            }
+            tokens.extend(runs.into_iter().map(|(run, tag)| rle_token(run, tag)));
+        }
+        tokens.push("!".to_string());
+
+        let mut rle: String = format!("x = {}, y = {}, rule = B3/S23\n", self.width(), self.height());
+        let mut line_length: usize = 0;
+        for token in tokens {
+            if line_length + token.len() > RLE_LINE_LENGTH {
+                rle.push('\n');
+                line_length = 0;
+            }
+            line_length += token.len();
+            rle.push_str(&token);
+        }
+        rle.push('\n');
+        rle
+    }
+
+    /// Create a board from a pattern in plaintext (`.cells`) format.
+    ///
+    /// Each line of the pattern is a row of the board, with `.` for a dead cell and `O` for a live cell. Lines starting
+    /// with `!` are comments and are ignored. The width of the board is the length of the longest row, and shorter
+    /// rows are padded with `Dead` cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `plaintext`: The pattern in plaintext format.
+    /// - `boundary_condition`: The boundary condition of the board.
+    ///
+    /// # Returns
+    ///
+    /// The board described by the pattern, or an error if the pattern is empty or contains an unexpected character.
+    pub fn from_plaintext(plaintext: &str, boundary_condition: BoundaryCondition<GameOfLifeState>) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<GameOfLifeState>> = plaintext
+            .lines()
+            .filter(|line| !line.starts_with('!'))
+            .map(|line| {
+                line.trim_end()
+                    .chars()
+                    .map(|c| match c {
+                        '.' => Ok(GameOfLifeState::Dead),
+                        'O' => Ok(GameOfLifeState::Alive),
+                        _ => Err(ParseError {
+                            message: format!("Unexpected character {:?} in plaintext pattern", c),
+                        }),
+                    })
+                    .collect::<Result<Vec<GameOfLifeState>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<GameOfLifeState>>, ParseError>>()?;
+
+        // Blank lines within the pattern are empty rows, but trailing blank lines are not part of the pattern
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+
+        let width: usize = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        if width == 0 {
+            return Err(ParseError {
+                message: "Plaintext pattern has no cells".to_string(),
+            });
+        }
+        rows.iter_mut().for_each(|row| row.resize(width, GameOfLifeState::Dead));
+
+        Ok(Board::new(rows, boundary_condition))
+    }
+
+    /// Encode the board as a pattern in plaintext (`.cells`) format.
+    ///
+    /// # Returns
+    ///
+    /// The board encoded with `.` for dead cells and `O` for live cells, one row per line.
+    pub fn to_plaintext(&self) -> String {
+        let mut plaintext: String = String::with_capacity((self.width() + 1) * self.height());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                plaintext.push(match self.get(x, y) {
+                    Some(GameOfLifeState::Alive) => 'O',
+                    _ => '.',
+                });
+            }
+            plaintext.push('\n');
+        }
+        plaintext
+    }
+}
+
+impl<S: State> Board<S> {
+    /// Read a board from a file containing a pattern in RLE (Run Length Encoded) format.
+    ///
+    /// Live cells in the pattern are given the `alive` state and dead cells the `dead` state, so any two-state
+    /// automaton can be loaded from an RLE file.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the RLE file.
+    /// - `boundary_condition`: The boundary condition of the board.
+    /// - `alive`: The state of live cells in the pattern.
+    /// - `dead`: The state of dead cells in the pattern.
+    ///
+    /// # Returns
+    ///
+    /// The board described by the file, or an error if the file cannot be read. A malformed pattern gives an error
+    /// of kind `InvalidData`.
+    pub fn from_rle_file<P: AsRef<Path>>(path: P, boundary_condition: BoundaryCondition<S>, alive: S, dead: S) -> io::Result<Self> {
+        let rle: String = fs::read_to_string(&path)?;
+        let pattern: Board<GameOfLifeState> = Board::from_rle(&rle, BoundaryCondition::Periodic).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to parse {}: {}", path.as_ref().display(), error.message),
+            )
+        })?;
+
+        let cells: Vec<Vec<S>> = (0..pattern.height())
+            .map(|y| {
+                (0..pattern.width())
+                    .map(|x| match pattern.get(x, y) {
+                        Some(GameOfLifeState::Alive) => alive,
+                        _ => dead,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Board::new(cells, boundary_condition))
+    }
+
+    /// Write the board to a file as a pattern in RLE (Run Length Encoded) format.
+    ///
+    /// Cells in the `alive` state are written as live cells and cells in the `dead` state as dead cells. If the file
+    /// already exists, its leading `#` comment lines (such as the pattern name and author) are kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The path of the RLE file.
+    /// - `alive`: The state written as a live cell.
+    /// - `dead`: The state written as a dead cell.
+    ///
+    /// # Returns
+    ///
+    /// An error if the file cannot be written, or if a cell is in neither the `alive` nor the `dead` state.
+    pub fn to_rle_file<P: AsRef<Path>>(&self, path: P, alive: S, dead: S) -> io::Result<()> {
+        let mut cells: Vec<Vec<GameOfLifeState>> = Vec::with_capacity(self.height());
+        for y in 0..self.height() {
+            let mut row: Vec<GameOfLifeState> = Vec::with_capacity(self.width());
+            for x in 0..self.width() {
+                row.push(match self.get(x, y) {
+                    Some(state) if state == alive => GameOfLifeState::Alive,
+                    Some(state) if state == dead => GameOfLifeState::Dead,
+                    state => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("Cell ({}, {}) in state {:?} is neither alive nor dead", x, y, state),
+                        ))
+                    }
+                });
+            }
+            cells.push(row);
+        }
+        let pattern: Board<GameOfLifeState> = Board::new(cells, BoundaryCondition::Periodic);
+
+        // Keep the comment lines of an existing pattern
+        let mut rle: String = match fs::read_to_string(&path) {
+            Ok(existing) => existing
+                .lines()
+                .take_while(|line| line.trim_start().starts_with('#'))
+                .map(|line| format!("{}\n", line))
+                .collect(),
+            Err(_) => String::new(),
+        };
+        rle.push_str(&pattern.to_rle());
+
+        fs::write(path, rle)
+    }
+}
+
+/// Parse the `x = <width>, y = <height>` header of an RLE pattern.
+///
+/// The `rule` field is always last and its value may itself contain commas, such as the bounded grid in
+/// `rule = B3/S23:T10,10`, so parsing stops at it and the rest of the line is ignored.
+fn parse_rle_header(header: &str) -> Result<(usize, usize), ParseError> {
+    let (mut width, mut height): (Option<usize>, Option<usize>) = (None, None);
+
+    for field in header.split(',') {
+        let (key, value) = field.split_once('=').ok_or_else(|| ParseError {
+            message: format!("Expected a field of the form <key> = <value> in RLE header, got {:?}", field.trim()),
+        })?;
+        let dimension: &mut Option<usize> = match key.trim() {
+            "x" => &mut width,
+            "y" => &mut height,
+            "rule" => break,
+            _ => continue,
+        };
+        *dimension = match value.trim().parse::<usize>() {
+            Ok(n) if n > 0 => Some(n),
+            _ => {
+                return Err(ParseError {
+                    message: format!("Invalid dimension {:?} in RLE header", value.trim()),
+                })
+            }
+        };
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height)),
+        _ => Err(ParseError {
+            message: format!("RLE header {:?} must specify both x and y", header),
+        }),
+    }
+}
+
+/// Format a run of `run` cells with the given tag as an RLE token, omitting a count of one.
+fn rle_token(run: usize, tag: char) -> String {
+    if run == 1 {
+        tag.to_string()
+    } else {
+        format!("{}{}", run, tag)
+    }
+}
@@ -1,11 +1,14 @@
 use super::{board::Board, error::OutOfBoundsSetError, state::State};
+use rand::{rngs::StdRng, Rng};
 
 /// A trait that defines a rule for updating the state of a cell in a cellular automaton.
 ///
+/// Rules must implement `Clone` so that boxed rules (and the automata holding them) can be duplicated.
+///
 /// # Type Parameters
 ///
 /// - `S`: The type of state that each cell in the board can have.
-pub trait Rule<S: State>: Send + Sync {
+pub trait Rule<S: State>: Send + Sync + CloneRule<S> {
     /// Apply the rule to the cell at the given coordinates on the board.
     ///
     /// # Arguments
@@ -18,6 +21,128 @@ pub trait Rule<S: State>: Send + Sync {
     ///
     /// A vector of deltas to the board, or an error if the coordinates are out of bounds.
     fn delta(&self, coord: (usize, usize), board: &Board<S>) -> Result<Vec<Delta<S>>, OutOfBoundsSetError>;
+
+    /// Apply the rule to the cell at the given coordinates on the board at the given time step.
+    ///
+    /// Automata call this method rather than `delta`, so time-dependent rules can override it. The default
+    /// implementation ignores the time step and delegates to `delta`, so rules that do not depend on time only need
+    /// to implement `delta`.
+    ///
+    /// # Arguments
+    ///
+    /// - `coord`: A tuple containing the x and y coordinates of the cell.
+    ///
+    /// - `board`: A reference to the board of cells.
+    ///
+    /// - `step`: The time step of the automaton applying the rule, before the step is taken.
+    ///
+    /// # Returns
+    ///
+    /// A vector of deltas to the board, or an error if the coordinates are out of bounds.
+    fn delta_at(&self, coord: (usize, usize), board: &Board<S>, step: usize) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+        let _ = step;
+        self.delta(coord, board)
+    }
+
+    /// Apply the rule to the cell at the given coordinates at the given time step, drawing any randomness from `rng`.
+    ///
+    /// Automata with a seed call this method rather than `delta_at`, passing a generator derived from the seed, the
+    /// time step and the cell, so stochastic rules are reproducible. The default implementation ignores the generator
+    /// and delegates to `delta_at`. Stochastic rules are usually written as an `RngRule` and wrapped in a
+    /// `StochasticRule` rather than overriding this method.
+    ///
+    /// # Arguments
+    ///
+    /// - `coord`: A tuple containing the x and y coordinates of the cell.
+    ///
+    /// - `board`: A reference to the board of cells.
+    ///
+    /// - `step`: The time step of the automaton applying the rule, before the step is taken.
+    ///
+    /// - `rng`: The random number generator for this cell at this time step.
+    ///
+    /// # Returns
+    ///
+    /// A vector of deltas to the board, or an error if the coordinates are out of bounds.
+    fn delta_with_rng(&self, coord: (usize, usize), board: &Board<S>, step: usize, rng: &mut StdRng) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+        let _ = rng;
+        self.delta_at(coord, board, step)
+    }
+}
+
+/// A trait that defines a stochastic rule, which draws its randomness from a random number generator it is given.
+///
+/// Wrap an `RngRule` in a `StochasticRule` to use it as a `Rule`. When the automaton has a seed, the generator is
+/// derived from the seed, the time step and the cell, so runs are reproducible; otherwise a thread-local generator
+/// is used.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+pub trait RngRule<S: State>: Send + Sync {
+    /// Apply the rule to the cell at the given coordinates on the board.
+    ///
+    /// # Arguments
+    ///
+    /// - `coord`: A tuple containing the x and y coordinates of the cell.
+    ///
+    /// - `board`: A reference to the board of cells.
+    ///
+    /// - `rng`: The random number generator to draw any randomness from.
+    ///
+    /// # Returns
+    ///
+    /// A vector of deltas to the board, or an error if the coordinates are out of bounds.
+    fn delta_rng(&self, coord: (usize, usize), board: &Board<S>, rng: &mut impl Rng) -> Result<Vec<Delta<S>>, OutOfBoundsSetError>;
+}
+
+/// A trait that allows a rule to be cloned behind a trait object.
+///
+/// This trait is implemented automatically for every rule that implements `Clone`, and is what allows
+/// `Box<dyn Rule<S>>` to implement `Clone`.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+pub trait CloneRule<S: State> {
+    /// Clone the rule into a new boxed trait object.
+    fn clone_box(&self) -> Box<dyn Rule<S>>;
+}
+
+impl<S: State, R: Rule<S> + Clone + 'static> CloneRule<S> for R {
+    fn clone_box(&self) -> Box<dyn Rule<S>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<S: State> Clone for Box<dyn Rule<S>> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+/// A trait that defines a rule for updating a 2x2 block of cells at once, for block (Margolus) cellular automata.
+///
+/// Block rules do not fit the per-cell `Rule::delta` model, as every cell of a block is updated together. They are
+/// applied by a `BlockAutomaton`, which partitions the board into 2x2 blocks whose origin shifts by one cell
+/// diagonally on alternate steps.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+pub trait BlockRule<S: State>: Send + Sync {
+    /// Compute the next states of a 2x2 block of cells.
+    ///
+    /// # Arguments
+    ///
+    /// - `block`: The states of the block, indexed as `block[dy][dx]` from the top-left cell of the block.
+    ///
+    /// - `step_parity`: The parity of the time step (0 or 1), which is also the offset of the partition.
+    ///
+    /// # Returns
+    ///
+    /// The next states of the block, indexed in the same way.
+    fn block_delta(&self, block: [[S; 2]; 2], step_parity: usize) -> [[S; 2]; 2];
 }
 
 /// A struct that represents a change to the state of a cell in a cellular automaton.
@@ -58,6 +183,26 @@ impl<S: State> Delta<S> {
         Self { x, y, state }
     }
 
+    /// Create a new `Delta` for possibly out-of-bounds coordinates, mapped onto the board by its boundary condition.
+    ///
+    /// This lets rules that move cells to other coordinates wrap or reflect the move when the delta is created, rather
+    /// than relying on the board's edge write policy when it is applied.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: The x-coordinate of the cell, which may be negative or past the right edge.
+    /// - `y`: The y-coordinate of the cell, which may be negative or past the bottom edge.
+    /// - `state`: The new state of the cell.
+    /// - `board`: The board whose boundary condition is used to map the coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A new `Delta` for the cell the coordinates map to, or None if the coordinates are outside a board with fixed
+    /// boundaries, in which case the write should be dropped.
+    pub fn clamped(x: isize, y: isize, state: S, board: &Board<S>) -> Option<Self> {
+        board.normalise_coord(x, y).map(|(x, y)| Self { x, y, state })
+    }
+
     /// Apply the delta to the board.
     pub fn apply(&self, board: &mut Board<S>) -> Result<(), OutOfBoundsSetError> {
         board.set(self.x, self.y, self.state)
@@ -66,15 +211,55 @@ impl<S: State> Delta<S> {
 
 /// Rules for common cellular automata.
 pub mod common_rules {
+    use std::collections::HashSet;
     use std::vec;
 
-    use super::{Rule, Delta};
-    use crate::components::board::Board;
-    use crate::components::error::OutOfBoundsSetError;
+    use super::{Rule, RngRule, Delta};
+    use rand::rngs::StdRng;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use crate::automaton::Automaton;
+    use crate::components::board::{Board, BoundaryCondition, Rect};
+    use crate::components::error::{AutomatonError, OutOfBoundsSetError, ParseError};
     use crate::components::neighbourhood::{Neighbourhood, NeighbourhoodType};
     use crate::components::state::common_states::{
-        AntDirection, CellColour, GameOfLifeState, LangtonsAntState,
+        AntDirection, CellColour, CyclicState, GameOfLifeState, GenerationsState, LangtonsAntState, TotalisticState, TurmiteState,
     };
+    use crate::components::state::State;
+
+    /// The offsets of the Moore neighbourhood of radius 1, excluding the cell itself.
+    const MOORE_OFFSETS: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+    /// Get the state of the cell at an offset from a cell, resolved by the boundary condition of the board.
+    ///
+    /// The radius 1 rules look up their neighbours directly instead of through a cached `Neighbourhood`. Resolving an
+    /// offset costs about as much as a cache lookup, and a cache shared by every instance of a rule would hold the
+    /// neighbourhoods of the largest board until the process exits and be rebuilt whenever the board size changes.
+    ///
+    /// # Returns
+    ///
+    /// The state of the cell, the fixed state if it is outside a board with fixed boundaries, or None if the
+    /// board has no such cell.
+    fn state_at<S: State>(board: &Board<S>, coord: (usize, usize), dx: isize, dy: isize) -> Option<S> {
+        match Neighbourhood::resolve_offset(board, coord.0, coord.1, dx, dy) {
+            Some((x, y)) => board.get(x, y),
+            None => match board.boundary_condition() {
+                BoundaryCondition::Fixed(state) => Some(state),
+                _ => None,
+            },
+        }
+    }
+
+    /// Count the cells in the Moore neighbourhood of radius 1 of a cell whose states satisfy a predicate, as in
+    /// `Neighbourhood::count_matching`.
+    fn count_moore_matching<S: State>(board: &Board<S>, coord: (usize, usize), pred: impl Fn(&S) -> bool) -> usize {
+        MOORE_OFFSETS
+            .iter()
+            .filter(|&&(dx, dy)| state_at(board, coord, dx, dy).is_some_and(|state| pred(&state)))
+            .count()
+    }
+
+    #[derive(Clone)]
     pub struct GameOfLifeRule;
 
     impl Rule<GameOfLifeState> for GameOfLifeRule {
@@ -83,23 +268,13 @@ pub mod common_rules {
             coord: (usize, usize),
             board: &Board<GameOfLifeState>,
         ) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
-            let mut num_alive: u16 = 0;
-            let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
-
             let curr_state: GameOfLifeState = board
                 .get(coord.0, coord.1)
                 .expect("The rule should not be applied on cells outside the board");
-            let neighbours: Vec<Option<GameOfLifeState>> =
-                neighbourhood.get_neighbourhood_states(board, coord.0, coord.1);
-
-            neighbours.iter().for_each(|x| match x {
-                Some(GameOfLifeState::Alive) => num_alive += 1,
-                _ => {}
-            });
+            let num_alive: usize = count_moore_matching(board, coord, |&state| state == GameOfLifeState::Alive);
 
             let new_state: GameOfLifeState = match curr_state {
                 GameOfLifeState::Alive => {
-                    num_alive -= 1; //subtract cell from neighbourhood
                     if num_alive < 2 {
                         GameOfLifeState::Dead
                     } else if num_alive == 2 || num_alive == 3 {
@@ -121,6 +296,531 @@ pub mod common_rules {
         }
     }
 
+    /// A life-like rule, defined by the numbers of live neighbours that cause a dead cell to be born and a live cell to survive.
+    ///
+    /// The rule uses a Moore neighbourhood of radius 1 and counts live neighbours excluding the cell itself.
+    /// Conway's Game of Life is `B3/S23`, HighLife is `B36/S23` and Seeds is `B2/S`.
+    ///
+    /// # Fields
+    ///
+    /// - `birth`: The numbers of live neighbours that cause a dead cell to become alive.
+    /// - `survival`: The numbers of live neighbours that allow a live cell to stay alive.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct LifeLikeRule {
+        birth: HashSet<u8>,
+        survival: HashSet<u8>,
+    }
+
+    impl LifeLikeRule {
+        /// Create a new `LifeLikeRule` with the given birth and survival counts.
+        ///
+        /// # Arguments
+        ///
+        /// - `birth`: The numbers of live neighbours that cause a dead cell to become alive.
+        /// - `survival`: The numbers of live neighbours that allow a live cell to stay alive.
+        pub fn new(birth: HashSet<u8>, survival: HashSet<u8>) -> Self {
+            Self { birth, survival }
+        }
+
+        /// Create a new `LifeLikeRule` from a rule string in B/S notation, such as `"B36/S23"`.
+        ///
+        /// The birth and survival parts may appear in either order and the letters are case-insensitive.
+        /// Each part lists the neighbour counts (0 to 8) as digits, and may be empty (as in Seeds, `"B2/S"`).
+        ///
+        /// # Arguments
+        ///
+        /// - `notation`: The rule string.
+        ///
+        /// # Returns
+        ///
+        /// The parsed rule, or an error if the string is not valid B/S notation.
+        pub fn from_bs(notation: &str) -> Result<Self, ParseError> {
+            let parts: Vec<&str> = notation.trim().split('/').collect();
+            if parts.len() != 2 {
+                return Err(ParseError {
+                    message: format!("Expected a rule of the form B<digits>/S<digits>, got {:?}", notation),
+                });
+            }
+
+            let mut birth: Option<HashSet<u8>> = None;
+            let mut survival: Option<HashSet<u8>> = None;
+            for part in parts {
+                let mut chars = part.chars();
+                let target: &mut Option<HashSet<u8>> = match chars.next() {
+                    Some('B') | Some('b') => &mut birth,
+                    Some('S') | Some('s') => &mut survival,
+                    _ => {
+                        return Err(ParseError {
+                            message: format!("Expected a part starting with B or S, got {:?}", part),
+                        })
+                    }
+                };
+                if target.is_some() {
+                    return Err(ParseError {
+                        message: format!("Duplicate part {:?} in rule {:?}", part, notation),
+                    });
+                }
+
+                let mut counts: HashSet<u8> = HashSet::new();
+                for c in chars {
+                    match c.to_digit(10) {
+                        Some(count) if count <= 8 => {
+                            counts.insert(count as u8);
+                        }
+                        _ => {
+                            return Err(ParseError {
+                                message: format!("Invalid neighbour count {:?} in rule {:?}", c, notation),
+                            })
+                        }
+                    }
+                }
+                *target = Some(counts);
+            }
+
+            match (birth, survival) {
+                (Some(birth), Some(survival)) => Ok(Self::new(birth, survival)),
+                _ => Err(ParseError {
+                    message: format!("Rule {:?} must have both a B and an S part", notation),
+                }),
+            }
+        }
+
+        /// Get the numbers of live neighbours that cause a dead cell to become alive.
+        pub fn birth(&self) -> &HashSet<u8> {
+            &self.birth
+        }
+
+        /// Get the numbers of live neighbours that allow a live cell to stay alive.
+        pub fn survival(&self) -> &HashSet<u8> {
+            &self.survival
+        }
+    }
+
+    impl Rule<GameOfLifeState> for LifeLikeRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<GameOfLifeState>,
+        ) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+            let curr_state: GameOfLifeState = board
+                .get(coord.0, coord.1)
+                .expect("The rule should not be applied on cells outside the board");
+            let num_alive: u8 = count_moore_matching(board, coord, |&state| state == GameOfLifeState::Alive) as u8;
+
+            let new_state: GameOfLifeState = match curr_state {
+                GameOfLifeState::Alive => {
+                    if self.survival.contains(&num_alive) {
+                        GameOfLifeState::Alive
+                    } else {
+                        GameOfLifeState::Dead
+                    }
+                }
+                GameOfLifeState::Dead => {
+                    if self.birth.contains(&num_alive) {
+                        GameOfLifeState::Alive
+                    } else {
+                        GameOfLifeState::Dead
+                    }
+                }
+            };
+
+            Ok(vec![Delta::new(coord.0, coord.1, new_state)])
+        }
+    }
+
+    /// A "Generations" rule, a life-like rule where live cells that fail to survive pass through refractory (dying)
+    /// states before they die.
+    ///
+    /// The rule uses a Moore neighbourhood of radius 1 and counts the live neighbours of each cell, excluding the cell
+    /// itself. A dead cell is born if its count is in `birth`, and a live cell stays alive if its count is in `survival`.
+    /// Otherwise a live cell starts dying, and ages by one state each generation until it dies after `states - 1`.
+    /// Dying cells do not count as live neighbours and cannot be born. Brian's Brain has birth `{2}`, no survival
+    /// counts and 3 states.
+    ///
+    /// # Fields
+    ///
+    /// - `birth`: The numbers of live neighbours that cause a dead cell to become alive.
+    /// - `survival`: The numbers of live neighbours that allow a live cell to stay alive.
+    /// - `states`: The total number of states, including dead and alive.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct GenerationsRule {
+        birth: HashSet<u8>,
+        survival: HashSet<u8>,
+        states: u8,
+    }
+
+    impl GenerationsRule {
+        /// Create a new `GenerationsRule` with the given birth and survival counts and number of states.
+        ///
+        /// # Arguments
+        ///
+        /// - `birth`: The numbers of live neighbours that cause a dead cell to become alive.
+        /// - `survival`: The numbers of live neighbours that allow a live cell to stay alive.
+        /// - `states`: The total number of states, including dead and alive. With 2 states, the rule is life-like.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are fewer than 2 states.
+        pub fn new(birth: HashSet<u8>, survival: HashSet<u8>, states: u8) -> Self {
+            if states < 2 {
+                panic!("A Generations rule must have at least 2 states");
+            }
+            Self { birth, survival, states }
+        }
+
+        /// Create the Brian's Brain rule, with birth on 2 live neighbours, no survival and 3 states.
+        pub fn brians_brain() -> Self {
+            Self::new(HashSet::from([2]), HashSet::new(), 3)
+        }
+
+        /// Get the numbers of live neighbours that cause a dead cell to become alive.
+        pub fn birth(&self) -> &HashSet<u8> {
+            &self.birth
+        }
+
+        /// Get the numbers of live neighbours that allow a live cell to stay alive.
+        pub fn survival(&self) -> &HashSet<u8> {
+            &self.survival
+        }
+
+        /// Get the total number of states, including dead and alive.
+        pub fn states(&self) -> u8 {
+            self.states
+        }
+    }
+
+    impl Rule<GenerationsState> for GenerationsRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<GenerationsState>,
+        ) -> Result<Vec<Delta<GenerationsState>>, OutOfBoundsSetError> {
+            let curr_state: GenerationsState = board
+                .get(coord.0, coord.1)
+                .expect("The rule should not be applied on cells outside the board");
+            let num_alive: u8 = count_moore_matching(board, coord, |&state| state == GenerationsState::ALIVE) as u8;
+
+            let new_state: GenerationsState = match curr_state {
+                GenerationsState::DEAD => match self.birth.contains(&num_alive) {
+                    true => GenerationsState::ALIVE,
+                    false => GenerationsState::DEAD,
+                },
+                GenerationsState::ALIVE => match (self.survival.contains(&num_alive), self.states > 2) {
+                    (true, _) => GenerationsState::ALIVE,
+                    (false, true) => GenerationsState(2),
+                    (false, false) => GenerationsState::DEAD,
+                },
+                GenerationsState(age) if age.checked_add(1).is_some_and(|next| next < self.states) => GenerationsState(age + 1),
+                _ => GenerationsState::DEAD,
+            };
+
+            Ok(vec![Delta::new(coord.0, coord.1, new_state)])
+        }
+    }
+
+    /// A totalistic rule over `k` numeric states, where the next state of a cell depends on its current state and the
+    /// sum of the states of its neighbours.
+    ///
+    /// The neighbour sum is taken over the cells in the neighbourhood, excluding the cell itself, with each cell
+    /// counting as its numeric value. Cells past a fixed boundary count as the fixed state, and cells past other
+    /// boundaries are handled as by `Neighbourhood`. The next state is looked up in a table keyed by
+    /// `(current_state, neighbour_sum)`, and pairs missing from the table lead to the quiescent state 0. This makes
+    /// the rule outer totalistic; life-like rules are the special case with 2 states and a Moore neighbourhood of radius 1.
+    ///
+    /// # Fields
+    ///
+    /// - `k`: The number of states, from 0 to `k - 1`.
+    /// - `neighbourhood`: The neighbourhood summed over, shared between clones of the rule so its cache is reused.
+    /// - `table`: The next state for each pair of current state and neighbour sum.
+    #[derive(Clone)]
+    pub struct TotalisticRule {
+        k: u8,
+        neighbourhood: Arc<Neighbourhood>,
+        table: HashMap<(u8, u32), u8>,
+    }
+
+    impl TotalisticRule {
+        /// Create a new `TotalisticRule` from a lookup table.
+        ///
+        /// # Arguments
+        ///
+        /// - `k`: The number of states, from 0 to `k - 1`.
+        /// - `neighbourhood_type`: The type of neighbourhood to sum over.
+        /// - `radius`: The radius of the neighbourhood.
+        /// - `table`: The next state for each pair of current state and neighbour sum. Missing pairs lead to state 0.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are fewer than 2 states, or the table contains a state that is not less than `k`.
+        pub fn new(k: u8, neighbourhood_type: NeighbourhoodType, radius: usize, table: HashMap<(u8, u32), u8>) -> Self {
+            Self::with_neighbourhood(k, Neighbourhood::new(neighbourhood_type, radius), table)
+        }
+
+        /// Create a new `TotalisticRule` from a neighbourhood and a lookup table, checking that the states are valid.
+        fn with_neighbourhood(k: u8, neighbourhood: Neighbourhood, table: HashMap<(u8, u32), u8>) -> Self {
+            if k < 2 {
+                panic!("A totalistic rule must have at least 2 states");
+            }
+            if let Some(((state, sum), next)) = table.iter().find(|((state, _), next)| *state >= k || **next >= k) {
+                panic!("The table entry ({}, {}) -> {} uses a state outside 0..{}", state, sum, next, k);
+            }
+            Self { k, neighbourhood: Arc::new(neighbourhood), table }
+        }
+
+        /// Create a new `TotalisticRule` whose table is given by a function of the current state and neighbour sum.
+        ///
+        /// The function is evaluated for every state and every neighbour sum the neighbourhood can produce.
+        ///
+        /// # Arguments
+        ///
+        /// - `k`: The number of states, from 0 to `k - 1`.
+        /// - `neighbourhood_type`: The type of neighbourhood to sum over.
+        /// - `radius`: The radius of the neighbourhood.
+        /// - `f`: A function that returns the next state from the current state and the neighbour sum.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are fewer than 2 states, or the function returns a state that is not less than `k`.
+        pub fn from_fn<F: Fn(u8, u32) -> u8>(k: u8, neighbourhood_type: NeighbourhoodType, radius: usize, f: F) -> Self {
+            let neighbourhood: Neighbourhood = Neighbourhood::new(neighbourhood_type, radius);
+            let num_neighbours: u32 = neighbourhood.offsets(0, 0).iter().filter(|&&offset| offset != (0, 0)).count() as u32;
+            let max_sum: u32 = num_neighbours * k.saturating_sub(1) as u32;
+
+            let table: HashMap<(u8, u32), u8> = (0..k)
+                .flat_map(|state| (0..=max_sum).map(move |sum| (state, sum)))
+                .map(|(state, sum)| ((state, sum), f(state, sum)))
+                .collect();
+            Self::with_neighbourhood(k, neighbourhood, table)
+        }
+
+        /// Get the number of states of the rule.
+        pub fn k(&self) -> u8 {
+            self.k
+        }
+
+        /// Get the next state for the given current state and neighbour sum.
+        pub fn next_state(&self, state: u8, neighbour_sum: u32) -> u8 {
+            self.table.get(&(state, neighbour_sum)).copied().unwrap_or(0)
+        }
+    }
+
+    impl Rule<TotalisticState> for TotalisticRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<TotalisticState>,
+        ) -> Result<Vec<Delta<TotalisticState>>, OutOfBoundsSetError> {
+            let curr_state: TotalisticState = board
+                .get(coord.0, coord.1)
+                .expect("The rule should not be applied on cells outside the board");
+            let neighbours: Vec<Option<TotalisticState>> =
+                self.neighbourhood.get_neighbourhood_states(board, coord.0, coord.1);
+
+            // The offsets are in the same order as the neighbourhood, so the cell itself can be skipped
+            let neighbour_sum: u32 = self.neighbourhood
+                .offsets(coord.0, coord.1)
+                .iter()
+                .zip(neighbours.iter())
+                .filter(|(&offset, _)| offset != (0, 0))
+                .filter_map(|(_, state)| state.map(|state| state.0 as u32))
+                .sum();
+
+            let new_state: TotalisticState = TotalisticState(self.next_state(curr_state.0, neighbour_sum));
+            Ok(vec![Delta::new(coord.0, coord.1, new_state)])
+        }
+    }
+
+    /// A cyclic cellular automaton rule, in which each state is eaten by the next state in the cycle.
+    ///
+    /// A cell in state `k` advances to `(k + 1) % num_states` if at least `threshold` of its neighbours, excluding the
+    /// cell itself, are already in that state, and otherwise keeps its state. Cells past a fixed boundary count as the
+    /// fixed state. From random initial states, the rule forms the rotating spirals cyclic automata are known for.
+    ///
+    /// # Fields
+    ///
+    /// - `num_states`: The number of states in the cycle, from 0 to `num_states - 1`.
+    /// - `threshold`: The number of neighbours in the next state needed for a cell to advance.
+    /// - `neighbourhood`: The neighbourhood counted over, shared between clones of the rule so its cache is reused.
+    #[derive(Clone)]
+    pub struct CyclicRule {
+        num_states: u8,
+        threshold: usize,
+        neighbourhood: Arc<Neighbourhood>,
+    }
+
+    impl CyclicRule {
+        /// Create a new `CyclicRule`.
+        ///
+        /// # Arguments
+        ///
+        /// - `num_states`: The number of states in the cycle, from 0 to `num_states - 1`.
+        /// - `threshold`: The number of neighbours in the next state needed for a cell to advance.
+        /// - `neighbourhood_type`: The type of neighbourhood to count over.
+        /// - `radius`: The radius of the neighbourhood.
+        ///
+        /// # Panics
+        ///
+        /// Panics if there are fewer than 2 states.
+        pub fn new(num_states: u8, threshold: usize, neighbourhood_type: NeighbourhoodType, radius: usize) -> Self {
+            if num_states < 2 {
+                panic!("A cyclic rule must have at least 2 states");
+            }
+            Self { num_states, threshold, neighbourhood: Arc::new(Neighbourhood::new(neighbourhood_type, radius)) }
+        }
+
+        /// Get the number of states in the cycle.
+        pub fn num_states(&self) -> u8 {
+            self.num_states
+        }
+
+        /// Get the number of neighbours in the next state needed for a cell to advance.
+        pub fn threshold(&self) -> usize {
+            self.threshold
+        }
+    }
+
+    impl Rule<CyclicState> for CyclicRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<CyclicState>,
+        ) -> Result<Vec<Delta<CyclicState>>, OutOfBoundsSetError> {
+            let curr_state: CyclicState = board
+                .get(coord.0, coord.1)
+                .expect("The rule should not be applied on cells outside the board");
+            let next_state: CyclicState = CyclicState(((curr_state.0 as u16 + 1) % self.num_states as u16) as u8);
+
+            let count: usize = self.neighbourhood.count_matching(board, coord.0, coord.1, |&state| state == next_state);
+            if count >= self.threshold {
+                Ok(vec![Delta::new(coord.0, coord.1, next_state)])
+            } else {
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// An elementary (one-dimensional) cellular automaton rule, identified by its Wolfram rule number.
+    ///
+    /// The rule operates on a board with a height of 1, where each generation is a single row. The new state of
+    /// each cell is looked up in the 8-bit rule table from the states of its left neighbour, itself and its right
+    /// neighbour, read as a 3-bit number with `Alive` as 1 and `Dead` as 0. Cells past the edges wrap around
+    /// under a periodic boundary condition and take the fixed state under a fixed boundary condition.
+    ///
+    /// # Fields
+    ///
+    /// - `rule_number`: The Wolfram rule number, such as 30 or 110.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct ElementaryRule {
+        rule_number: u8,
+    }
+
+    impl ElementaryRule {
+        /// Create a new `ElementaryRule` with the given Wolfram rule number.
+        pub fn new(rule_number: u8) -> Self {
+            Self { rule_number }
+        }
+
+        /// Get the Wolfram rule number of the rule.
+        pub fn rule_number(&self) -> u8 {
+            self.rule_number
+        }
+
+        /// Evolve a single-row board for the given number of generations and collect every generation.
+        ///
+        /// Stacking the returned rows top to bottom gives the classic space-time diagram of the automaton.
+        ///
+        /// # Arguments
+        ///
+        /// - `board`: The board to evolve. It must have a height of 1.
+        /// - `generations`: The number of generations to evolve the board for.
+        ///
+        /// # Returns
+        ///
+        /// A vector of `generations + 1` rows, starting with the initial row, or an error if the board could not be evolved.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the board does not have a height of 1.
+        pub fn evolve_rows(
+            &self,
+            board: &mut Board<GameOfLifeState>,
+            generations: usize,
+        ) -> Result<Vec<Vec<GameOfLifeState>>, AutomatonError> {
+            if board.height() != 1 {
+                panic!("Elementary rules require a board with a height of 1");
+            }
+
+            let read_row = |board: &Board<GameOfLifeState>| -> Vec<GameOfLifeState> {
+                (0..board.width()).map(|x| board.get(x, 0).unwrap()).collect()
+            };
+
+            let mut rows: Vec<Vec<GameOfLifeState>> = Vec::with_capacity(generations + 1);
+            rows.push(read_row(board));
+
+            let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(board, vec![Box::new(*self)]);
+            for _ in 0..generations {
+                automaton.evolve(1)?;
+                rows.push(read_row(automaton.board()));
+            }
+
+            Ok(rows)
+        }
+    }
+
+    impl Rule<GameOfLifeState> for ElementaryRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<GameOfLifeState>,
+        ) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+            let bit = |dx: isize| -> u8 {
+                match state_at(board, coord, dx, 0) {
+                    Some(GameOfLifeState::Alive) => 1,
+                    _ => 0,
+                }
+            };
+            let pattern: u8 = (bit(-1) << 2) | (bit(0) << 1) | bit(1);
+
+            let new_state: GameOfLifeState = match (self.rule_number >> pattern) & 1 {
+                1 => GameOfLifeState::Alive,
+                _ => GameOfLifeState::Dead,
+            };
+
+            Ok(vec![Delta::new(coord.0, coord.1, new_state)])
+        }
+    }
+
+    /// A turn made by an ant, relative to the direction it is facing.
+    ///
+    /// The turns are:
+    /// - NoTurn: The ant keeps its direction (`N` in turmite notation).
+    /// - Right: The ant turns 90 degrees clockwise (`R`).
+    /// - UTurn: The ant turns around (`U`).
+    /// - Left: The ant turns 90 degrees anticlockwise (`L`).
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    pub enum Turn {
+        NoTurn,
+        Right,
+        UTurn,
+        Left,
+    }
+
+    impl Turn {
+        /// Get the direction an ant faces after making the turn.
+        pub fn apply(self, direction: AntDirection) -> AntDirection {
+            const CLOCKWISE: [AntDirection; 4] = [AntDirection::Up, AntDirection::Right, AntDirection::Down, AntDirection::Left];
+            let index: usize = CLOCKWISE.iter().position(|&d| d == direction).unwrap();
+            let quarter_turns: usize = match self {
+                Turn::NoTurn => 0,
+                Turn::Right => 1,
+                Turn::UTurn => 2,
+                Turn::Left => 3,
+            };
+            CLOCKWISE[(index + quarter_turns) % 4]
+        }
+    }
+
+    #[derive(Clone)]
     pub struct LangtonsAntRule;
 
     impl Rule<LangtonsAntState> for LangtonsAntRule {
@@ -144,18 +844,8 @@ pub mod common_rules {
             
             // Update the cell's state based on the ant's direction and the cell's colour.
             let new_direction: AntDirection = match old_state.colour {
-                CellColour::White => match direction {
-                    AntDirection::Up => AntDirection::Right,
-                    AntDirection::Right => AntDirection::Down,
-                    AntDirection::Down => AntDirection::Left,
-                    AntDirection::Left => AntDirection::Up,
-                },
-                CellColour::Black => match direction {
-                    AntDirection::Up => AntDirection::Left,
-                    AntDirection::Left => AntDirection::Down,
-                    AntDirection::Down => AntDirection::Right,
-                    AntDirection::Right => AntDirection::Up,
-                },
+                CellColour::White => Turn::Right.apply(direction),
+                CellColour::Black => Turn::Left.apply(direction),
             };
 
             // Flip the colour of the old cell and remove the ant.
@@ -169,11 +859,9 @@ pub mod common_rules {
                 ant_direction: None,
             };
 
-            let (nx, ny) = match new_direction {
-                AntDirection::Up => (coord.0, coord.1.wrapping_sub(1)),
-                AntDirection::Right => (coord.0 + 1, coord.1),
-                AntDirection::Down => (coord.0, coord.1 + 1),
-                AntDirection::Left => (coord.0.wrapping_sub(1), coord.1),
+            // An ant that steps off a board with fixed boundaries leaves it
+            let Some((nx, ny)) = board.neighbour(coord.0, coord.1, new_direction) else {
+                return Ok(vec![Delta::new(coord.0, coord.1, updated_old_cell)]);
             };
 
             let mut next_cell: LangtonsAntState = board.get(nx, ny).ok_or(OutOfBoundsSetError {
@@ -192,4 +880,288 @@ pub mod common_rules {
             Ok(vec![Delta::new(coord.0, coord.1, updated_old_cell), Delta::new(nx, ny, next_cell)])
         }
     }
+
+    /// A rule for turmites, which generalise Langton's Ant to any number of cell colours and ant states.
+    ///
+    /// At each step, an ant looks up the colour of its cell and its own state in a transition table, recolours the
+    /// cell, turns, changes state, and moves one cell forward. Ants whose colour and state have no entry in the table
+    /// halt. Cells without an ant are left unchanged. An ant that steps off a board with fixed boundaries leaves it.
+    ///
+    /// # Fields
+    ///
+    /// - `table`: The transitions, mapping `(cell colour, ant state)` to `(new cell colour, turn, new ant state)`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TurmiteRule {
+        table: HashMap<(u8, u8), (u8, Turn, u8)>,
+    }
+
+    impl TurmiteRule {
+        /// Create a new `TurmiteRule` from a transition table.
+        ///
+        /// # Arguments
+        ///
+        /// - `table`: The transitions, mapping `(cell colour, ant state)` to `(new cell colour, turn, new ant state)`.
+        pub fn new(table: HashMap<(u8, u8), (u8, Turn, u8)>) -> Self {
+            Self { table }
+        }
+
+        /// Create a new single-state `TurmiteRule` from a Langton-style turn string, such as `"RL"` or `"LRRRRRLLR"`.
+        ///
+        /// The string has one letter per colour: an ant on a cell of colour `i` makes the `i`-th turn and recolours
+        /// the cell to the next colour, wrapping back to 0. Turns are written `L`, `R`, `N` (no turn) and `U` (U-turn),
+        /// case-insensitively. `"RL"` is Langton's Ant.
+        ///
+        /// # Arguments
+        ///
+        /// - `turns`: The turn string, with between 1 and 256 letters.
+        ///
+        /// # Returns
+        ///
+        /// The parsed rule, or an error if the string is empty, too long or contains a letter that is not a turn.
+        pub fn from_turns(turns: &str) -> Result<Self, ParseError> {
+            let turns: Vec<char> = turns.trim().chars().collect();
+            if turns.is_empty() || turns.len() > 256 {
+                return Err(ParseError {
+                    message: format!("Expected between 1 and 256 turns, got {}", turns.len()),
+                });
+            }
+
+            let num_colours: usize = turns.len();
+            let mut table: HashMap<(u8, u8), (u8, Turn, u8)> = HashMap::with_capacity(num_colours);
+            for (colour, letter) in turns.into_iter().enumerate() {
+                let turn: Turn = match letter.to_ascii_uppercase() {
+                    'N' => Turn::NoTurn,
+                    'R' => Turn::Right,
+                    'U' => Turn::UTurn,
+                    'L' => Turn::Left,
+                    _ => {
+                        return Err(ParseError {
+                            message: format!("Invalid turn {:?}, expected one of L, R, N or U", letter),
+                        })
+                    }
+                };
+                table.insert((colour as u8, 0), (((colour + 1) % num_colours) as u8, turn, 0));
+            }
+            Ok(Self::new(table))
+        }
+
+        /// Get the transition table of the rule.
+        pub fn table(&self) -> &HashMap<(u8, u8), (u8, Turn, u8)> {
+            &self.table
+        }
+    }
+
+    impl Rule<TurmiteState> for TurmiteRule {
+        fn delta(
+            &self,
+            coord: (usize, usize),
+            board: &Board<TurmiteState>,
+        ) -> Result<Vec<Delta<TurmiteState>>, OutOfBoundsSetError> {
+            let out_of_bounds = |(x, y): (usize, usize)| OutOfBoundsSetError {
+                x,
+                y,
+                width: board.width(),
+                height: board.height(),
+            };
+            let old_state: TurmiteState = board.get(coord.0, coord.1).ok_or(out_of_bounds(coord))?;
+
+            // Only the cell with the ant changes, and ants without a transition halt.
+            let Some(direction) = old_state.ant_direction else {
+                return Ok(vec![]);
+            };
+            let Some(&(new_colour, turn, new_ant_state)) = self.table.get(&(old_state.colour, old_state.ant_state)) else {
+                return Ok(vec![]);
+            };
+
+            let new_direction: AntDirection = turn.apply(direction);
+            // Resolve the step by the boundary condition, so ants wrap around periodic boards and leave fixed ones
+            let Some((nx, ny)) = board.neighbour(coord.0, coord.1, new_direction) else {
+                return Ok(vec![Delta::new(coord.0, coord.1, TurmiteState::cell(new_colour))]);
+            };
+            let next_cell: TurmiteState = board.get(nx, ny).ok_or(out_of_bounds((nx, ny)))?;
+
+            Ok(vec![
+                Delta::new(coord.0, coord.1, TurmiteState::cell(new_colour)),
+                Delta::new(nx, ny, TurmiteState::ant(next_cell.colour, new_direction, new_ant_state)),
+            ])
+        }
+    }
+
+    /// A rule that applies different rules in different regions of the board.
+    ///
+    /// For each cell, the rule delegates to the first region that contains the cell. Cells in no region are updated
+    /// by the default rule, or left unchanged if there is no default rule.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Fields
+    ///
+    /// - `regions`: The regions of the board and the rule applied in each, in order of priority.
+    /// - `default`: The rule applied to cells in no region, if any.
+    #[derive(Clone)]
+    pub struct RegionalRule<S: State> {
+        regions: Vec<(Rect, Box<dyn Rule<S>>)>,
+        default: Option<Box<dyn Rule<S>>>,
+    }
+
+    impl<S: State> RegionalRule<S> {
+        /// Create a new `RegionalRule` from a list of regions and an optional default rule.
+        ///
+        /// # Arguments
+        ///
+        /// - `regions`: The regions of the board and the rule applied in each. If regions overlap, the first one listed wins.
+        /// - `default`: The rule applied to cells in no region, or None to leave those cells unchanged.
+        pub fn new(regions: Vec<(Rect, Box<dyn Rule<S>>)>, default: Option<Box<dyn Rule<S>>>) -> Self {
+            Self { regions, default }
+        }
+
+        /// Get the regions of the board and the rule applied in each.
+        pub fn regions(&self) -> &Vec<(Rect, Box<dyn Rule<S>>)> {
+            &self.regions
+        }
+
+        /// Get the rule applied to cells in no region, if any.
+        pub fn default_rule(&self) -> Option<&dyn Rule<S>> {
+            self.default.as_deref()
+        }
+
+        /// Get the rule applied to the cell at the given coordinates, if any.
+        fn rule_at(&self, coord: (usize, usize)) -> Option<&dyn Rule<S>> {
+            self.regions
+                .iter()
+                .find(|(region, _)| region.contains(coord.0, coord.1))
+                .map(|(_, rule)| rule.as_ref())
+                .or(self.default.as_deref())
+        }
+    }
+
+    impl<S: State> Rule<S> for RegionalRule<S> {
+        fn delta(&self, coord: (usize, usize), board: &Board<S>) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            match self.rule_at(coord) {
+                Some(rule) => rule.delta(coord, board),
+                None => Ok(vec![]),
+            }
+        }
+
+        fn delta_at(&self, coord: (usize, usize), board: &Board<S>, step: usize) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            match self.rule_at(coord) {
+                Some(rule) => rule.delta_at(coord, board, step),
+                None => Ok(vec![]),
+            }
+        }
+
+        fn delta_with_rng(&self, coord: (usize, usize), board: &Board<S>, step: usize, rng: &mut StdRng) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            match self.rule_at(coord) {
+                Some(rule) => rule.delta_with_rng(coord, board, step, rng),
+                None => Ok(vec![]),
+            }
+        }
+    }
+
+    /// A rule built from an ordered pipeline of sub-rules, so that several rules can be packaged and reused as one.
+    ///
+    /// For each cell, the deltas of every inner rule are concatenated in the order the rules are listed. All inner rules
+    /// read the same board, so a later rule does not see the changes of an earlier one. When the deltas are applied by
+    /// an `Automaton`, conflicting writes to the same cell are resolved by its `ConflictPolicy`, so under the default
+    /// policy the last rule listed wins.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `S`: The type of state that each cell in the board can have.
+    ///
+    /// # Fields
+    ///
+    /// - `rules`: The inner rules, in the order their deltas are returned.
+    #[derive(Clone)]
+    pub struct CompositeRule<S: State> {
+        rules: Vec<Box<dyn Rule<S>>>,
+    }
+
+    impl<S: State> CompositeRule<S> {
+        /// Create a new `CompositeRule` from a list of rules.
+        ///
+        /// # Arguments
+        ///
+        /// - `rules`: The inner rules, in the order their deltas are returned.
+        pub fn new(rules: Vec<Box<dyn Rule<S>>>) -> Self {
+            Self { rules }
+        }
+
+        /// Get the inner rules of the composite rule.
+        pub fn rules(&self) -> &Vec<Box<dyn Rule<S>>> {
+            &self.rules
+        }
+    }
+
+    impl<S: State> Rule<S> for CompositeRule<S> {
+        fn delta(&self, coord: (usize, usize), board: &Board<S>) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            let mut deltas: Vec<Delta<S>> = Vec::new();
+            for rule in &self.rules {
+                deltas.extend(rule.delta(coord, board)?);
+            }
+            Ok(deltas)
+        }
+
+        fn delta_at(&self, coord: (usize, usize), board: &Board<S>, step: usize) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            let mut deltas: Vec<Delta<S>> = Vec::new();
+            for rule in &self.rules {
+                deltas.extend(rule.delta_at(coord, board, step)?);
+            }
+            Ok(deltas)
+        }
+
+        fn delta_with_rng(&self, coord: (usize, usize), board: &Board<S>, step: usize, rng: &mut StdRng) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            let mut deltas: Vec<Delta<S>> = Vec::new();
+            for rule in &self.rules {
+                deltas.extend(rule.delta_with_rng(coord, board, step, rng)?);
+            }
+            Ok(deltas)
+        }
+    }
+
+    /// A rule that wraps an `RngRule`, so that stochastic rules can be applied by an automaton.
+    ///
+    /// When the automaton has a seed, the wrapped rule draws from a generator derived from the seed, the index of the
+    /// rule, the time step and the cell, as in `rng::cell_rng`, so the same seed always produces the same run, even
+    /// when cells are evaluated in parallel. Without a seed, the wrapped rule draws from a thread-local generator.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: The type of the wrapped stochastic rule.
+    ///
+    /// # Fields
+    ///
+    /// - `rule`: The wrapped stochastic rule.
+    #[derive(Clone, Debug)]
+    pub struct StochasticRule<R> {
+        rule: R,
+    }
+
+    impl<R> StochasticRule<R> {
+        /// Create a new `StochasticRule` wrapping the given stochastic rule.
+        ///
+        /// # Arguments
+        ///
+        /// - `rule`: The stochastic rule to wrap.
+        pub fn new(rule: R) -> Self {
+            Self { rule }
+        }
+
+        /// Get the wrapped stochastic rule.
+        pub fn rule(&self) -> &R {
+            &self.rule
+        }
+    }
+
+    impl<S: State, R: RngRule<S> + Clone + 'static> Rule<S> for StochasticRule<R> {
+        fn delta(&self, coord: (usize, usize), board: &Board<S>) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            self.rule.delta_rng(coord, board, &mut rand::thread_rng())
+        }
+
+        fn delta_with_rng(&self, coord: (usize, usize), board: &Board<S>, _step: usize, rng: &mut StdRng) -> Result<Vec<Delta<S>>, OutOfBoundsSetError> {
+            self.rule.delta_rng(coord, board, rng)
+        }
+    }
 }
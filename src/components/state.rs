@@ -1,22 +1,125 @@
+use crate::components::board::Colour;
 use std::fmt::Debug;
 
 /// The `State` trait is used to define the possible states of a cell in a cellular automaton.
-pub trait State: Clone + Copy + PartialEq + Eq + Debug + Send + Sync + 'static {}
+///
+/// States only need to be `PartialEq`, so floating-point numbers can be states (see `ContinuousState`). Discrete states
+/// should also derive `Eq` and `Hash`, which methods that count or hash states, such as `Board::count_states`, require.
+pub trait State: Clone + Copy + PartialEq + Debug + Send + Sync + 'static {
+    /// Get the single character used to show the state when a board is printed, if it has one.
+    ///
+    /// `Board`'s `Display` implementation and `Board::display_compact` use this character for each cell, and fall back
+    /// to the `Debug` representation of the state if there is none. The default implementation returns `None`. States
+    /// that implement `Glyph` should override it to return `Some(self.glyph())`.
+    fn display_glyph(&self) -> Option<char> {
+        None
+    }
+}
+
+/// The `Glyph` trait is used to define a single character for each state, for compact terminal output.
+///
+/// `Display` cannot check whether a state implements `Glyph`, so states should also override `State::display_glyph`
+/// to return their glyph.
+pub trait Glyph: State {
+    /// Get the character used to show the state.
+    fn glyph(&self) -> char;
+}
+
+/// The `ContinuousState` trait is used to define states that hold a real value, for continuous cellular automata such
+/// as SmoothLife and Lenia.
+///
+/// The trait is implemented for `f32` and `f64`, so `Board<f64>` can be used directly. Continuous states are not `Eq`,
+/// so they cannot be used with methods that count or hash states.
+pub trait ContinuousState: State {
+    /// Get the value of the state.
+    fn value(self) -> f64;
+
+    /// Create a state from a value.
+    fn from_value(value: f64) -> Self;
+}
+
+impl State for f64 {}
+impl ContinuousState for f64 {
+    fn value(self) -> f64 {
+        self
+    }
+
+    fn from_value(value: f64) -> Self {
+        value
+    }
+}
+
+impl State for f32 {}
+impl ContinuousState for f32 {
+    fn value(self) -> f64 {
+        self as f64
+    }
+
+    fn from_value(value: f64) -> Self {
+        value as f32
+    }
+}
+
+// Continuous states are shown in greyscale, from black at 0.0 to white at 1.0 and above
+impl From<f64> for Colour {
+    fn from(value: f64) -> Colour {
+        Colour::black().lerp(Colour::white(), value)
+    }
+}
+
+impl From<f32> for Colour {
+    fn from(value: f32) -> Colour {
+        Colour::from(value as f64)
+    }
+}
+
+/// The `DiscreteState` trait is used to define states that can be combined with an exclusive-or.
+///
+/// This is used by second-order (reversible) cellular automata, where the next state of a cell is its
+/// neighbourhood's result combined with the cell's previous state. The operation must be its own inverse,
+/// so that `a.xor(b).xor(b) == a`.
+pub trait DiscreteState: State {
+    /// Combine this state with another state using an exclusive-or.
+    fn xor(self, other: Self) -> Self;
+}
 
 pub mod common_states {
-    use super::State;
+    use super::{DiscreteState, Glyph, State};
     use crate::components::board::Colour;
 
     /// State representation for the Game of Life cellular automaton.
     /// 
     /// Implements Into<Colour> for visualisation purposes.
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum GameOfLifeState {
         Dead,
         Alive,
     }
 
-    impl State for GameOfLifeState {}
+    impl State for GameOfLifeState {
+        fn display_glyph(&self) -> Option<char> {
+            Some(self.glyph())
+        }
+    }
+
+    impl Glyph for GameOfLifeState {
+        fn glyph(&self) -> char {
+            match self {
+                GameOfLifeState::Dead => '.',
+                GameOfLifeState::Alive => '#',
+            }
+        }
+    }
+    impl DiscreteState for GameOfLifeState {
+        fn xor(self, other: Self) -> Self {
+            if self == other {
+                GameOfLifeState::Dead
+            } else {
+                GameOfLifeState::Alive
+            }
+        }
+    }
     impl Into<Colour> for GameOfLifeState {
         fn into(self) -> Colour {
             match self {
@@ -27,7 +130,8 @@ pub mod common_states {
     }
 
     /// State representation for Langton's Ant facing direction.
-    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum AntDirection {
         Up,
         Right,
@@ -36,7 +140,8 @@ pub mod common_states {
     }
 
     /// State representation for Langton's Ant cell colour.
-    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum CellColour {
         White,
         Black,
@@ -53,13 +158,49 @@ pub mod common_states {
     ///
     /// - `colour`: The colour of the cell.
     /// - `ant_direction`: The direction the ant is facing, if present.
-    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct LangtonsAntState {
         pub colour: CellColour,
         pub ant_direction: Option<AntDirection>,
     }
 
-    impl State for LangtonsAntState {}
+    impl State for LangtonsAntState {
+        fn display_glyph(&self) -> Option<char> {
+            Some(self.glyph())
+        }
+    }
+
+    // Cells with an ant show the heading of the ant, and other cells show their colour
+    impl Glyph for LangtonsAntState {
+        fn glyph(&self) -> char {
+            match (self.ant_direction, self.colour) {
+                (Some(AntDirection::Up), _) => '^',
+                (Some(AntDirection::Right), _) => '>',
+                (Some(AntDirection::Down), _) => 'v',
+                (Some(AntDirection::Left), _) => '<',
+                (None, CellColour::White) => '.',
+                (None, CellColour::Black) => '#',
+            }
+        }
+    }
+
+    impl LangtonsAntState {
+        /// Get a colour for the cell that shows the heading of the ant, for use with `Board::to_representation_with`.
+        ///
+        /// Cells without an ant use the colour of the cell. Cells with an ant use a different colour for each heading:
+        /// red for up, green for right, blue for down and yellow for left.
+        pub fn heading_colour(self) -> Colour {
+            match self.ant_direction {
+                Some(AntDirection::Up) => Colour::red(),
+                Some(AntDirection::Right) => Colour::green(),
+                Some(AntDirection::Down) => Colour::blue(),
+                Some(AntDirection::Left) => Colour::new(255, 255, 0),
+                None => self.into(),
+            }
+        }
+    }
+
     impl Into<Colour> for LangtonsAntState {
         fn into(self) -> Colour {
             if let Some(_ant_direction) = self.ant_direction {
@@ -72,4 +213,126 @@ pub mod common_states {
             }
         }
     }
+
+    /// State representation for "Generations" cellular automata such as Brian's Brain, where cells pass through
+    /// refractory (dying) states before they die.
+    ///
+    /// The state is a number: 0 is dead, 1 is alive, and 2 and above are dying, counting up by one each generation.
+    ///
+    /// Implements Into<Colour> for visualisation purposes.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct GenerationsState(pub u8);
+
+    impl GenerationsState {
+        /// The dead state.
+        pub const DEAD: GenerationsState = GenerationsState(0);
+        /// The alive state.
+        pub const ALIVE: GenerationsState = GenerationsState(1);
+    }
+
+    impl State for GenerationsState {}
+    impl From<GenerationsState> for Colour {
+        fn from(state: GenerationsState) -> Colour {
+            match state.0 {
+                0 => Colour::black(),
+                1 => Colour::white(),
+                // Dying cells fade from blue towards black as they age
+                age => Colour::blue().lerp(Colour::black(), 1.0 - 1.0 / (age - 1) as f64),
+            }
+        }
+    }
+
+    /// State representation for totalistic cellular automata, where each cell holds a number from 0 to `k - 1`.
+    ///
+    /// The number is used as the value of the cell when summing a neighbourhood, so 0 is the quiescent (dead) state.
+    ///
+    /// Implements Into<Colour> for visualisation purposes.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TotalisticState(pub u8);
+
+    impl State for TotalisticState {}
+    impl From<TotalisticState> for Colour {
+        fn from(state: TotalisticState) -> Colour {
+            // The quiescent state is black, and the other states cycle through a fixed palette
+            const PALETTE: [(u8, u8, u8); 6] = [(255, 255, 255), (255, 0, 0), (0, 255, 0), (0, 0, 255), (255, 255, 0), (0, 255, 255)];
+            match state.0 {
+                0 => Colour::black(),
+                value => {
+                    let (r, g, b) = PALETTE[(value as usize - 1) % PALETTE.len()];
+                    Colour::new(r, g, b)
+                }
+            }
+        }
+    }
+
+    /// State representation for cyclic cellular automata, where each cell holds a number from 0 to one less than the
+    /// number of states and is eaten by the next state in the cycle.
+    ///
+    /// Implements Into<Colour> for visualisation purposes.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct CyclicState(pub u8);
+
+    impl State for CyclicState {}
+    impl From<CyclicState> for Colour {
+        fn from(state: CyclicState) -> Colour {
+            // Neighbouring states in the cycle get neighbouring colours, so the spirals are visible
+            const PALETTE: [(u8, u8, u8); 8] = [
+                (255, 0, 0), (255, 128, 0), (255, 255, 0), (0, 255, 0), (0, 255, 255), (0, 0, 255), (128, 0, 255), (255, 0, 255),
+            ];
+            let (r, g, b) = PALETTE[state.0 as usize % PALETTE.len()];
+            Colour::new(r, g, b)
+        }
+    }
+
+    /// State representation for turmites, which generalise Langton's Ant to any number of cell colours and ant states.
+    ///
+    /// Implements Into<Colour> for visualisation purposes.
+    ///
+    /// # Fields
+    ///
+    /// - `colour`: The colour of the cell, from 0 to one less than the number of colours.
+    /// - `ant_direction`: The direction the ant is facing, if an ant is on the cell.
+    /// - `ant_state`: The internal state of the ant, if an ant is on the cell. It is 0 for single-state ants.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TurmiteState {
+        pub colour: u8,
+        pub ant_direction: Option<AntDirection>,
+        pub ant_state: u8,
+    }
+
+    impl State for TurmiteState {}
+
+    impl TurmiteState {
+        /// Create a cell of the given colour without an ant.
+        pub fn cell(colour: u8) -> Self {
+            Self { colour, ant_direction: None, ant_state: 0 }
+        }
+
+        /// Create a cell of the given colour with an ant facing the given direction in the given state.
+        pub fn ant(colour: u8, direction: AntDirection, ant_state: u8) -> Self {
+            Self { colour, ant_direction: Some(direction), ant_state }
+        }
+    }
+
+    impl From<TurmiteState> for Colour {
+        fn from(state: TurmiteState) -> Colour {
+            if state.ant_direction.is_some() {
+                return Colour::red();
+            }
+            // The first two colours match Langton's Ant, and the others cycle through a fixed palette
+            const PALETTE: [(u8, u8, u8); 4] = [(0, 0, 255), (0, 255, 0), (255, 255, 0), (0, 255, 255)];
+            match state.colour {
+                0 => Colour::white(),
+                1 => Colour::black(),
+                colour => {
+                    let (r, g, b) = PALETTE[(colour as usize - 2) % PALETTE.len()];
+                    Colour::new(r, g, b)
+                }
+            }
+        }
+    }
 }
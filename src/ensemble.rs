@@ -0,0 +1,111 @@
+use super::automaton::Automaton;
+use super::components::{board::Board, error::AutomatonError, rule::Rule, state::State};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// A struct that represents an ensemble of independent replicate runs of the same automaton, for Monte Carlo studies.
+///
+/// Each replicate has its own board and seed, and is evolved by its own automaton with rules freshly built by the
+/// rule factory, so replicates share no mutable state and can be evolved in parallel. Each automaton is seeded with
+/// the replicate's seed, so stochastic rules (see `RngRule`) are reproducible from the seeds.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of state that each cell in the board can have.
+///
+/// # Fields
+///
+/// - `boards`: The board of each replicate.
+/// - `seeds`: The seed of each replicate, in the same order as the boards.
+/// - `make_rules`: A function that builds the rules for a replicate.
+/// - `curr_time`: The number of time steps the replicates have been evolved by.
+pub struct Ensemble<S: State> {
+    boards: Vec<Board<S>>,
+    seeds: Vec<u64>,
+    make_rules: Arc<dyn Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync>,
+    curr_time: usize,
+}
+
+impl<S: State> Ensemble<S> {
+    /// Create a new `Ensemble` with the given boards, seeds and rule factory.
+    ///
+    /// # Arguments
+    ///
+    /// - `boards`: The starting board of each replicate.
+    ///
+    /// - `seeds`: The seed of each replicate, in the same order as the boards.
+    ///
+    /// - `make_rules`: A function that builds the rules for a replicate. It is called once per replicate per run.
+    ///
+    /// # Returns
+    ///
+    /// A new `Ensemble` with the given replicates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of seeds is not the number of boards.
+    pub fn new<R>(boards: Vec<Board<S>>, seeds: Vec<u64>, make_rules: R) -> Self
+    where
+        R: Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync + 'static,
+    {
+        if boards.len() != seeds.len() {
+            panic!("An ensemble needs one seed per board, but got {} boards and {} seeds", boards.len(), seeds.len());
+        }
+        Self {
+            boards,
+            seeds,
+            make_rules: Arc::new(make_rules),
+            curr_time: 0,
+        }
+    }
+
+    /// Get the board of each replicate.
+    pub fn boards(&self) -> &[Board<S>] {
+        &self.boards
+    }
+
+    /// Get the seed of each replicate.
+    pub fn seeds(&self) -> &[u64] {
+        &self.seeds
+    }
+
+    /// Get the number of time steps the replicates have been evolved by.
+    pub fn curr_time(&self) -> usize {
+        self.curr_time
+    }
+
+    /// Evolve every replicate by the given number of time steps in parallel.
+    ///
+    /// Runs can be continued: a later run resumes each replicate from its board and time step, so evolving by `a`
+    /// and then `b` steps gives the same boards as evolving by `a + b` steps.
+    ///
+    /// # Arguments
+    ///
+    /// - `steps`: The number of time steps to advance each replicate.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the final board of each replicate, or an error if any replicate could not be advanced.
+    /// Each replicate is evolved on a copy of its board, and the copies only replace the boards once every replicate
+    /// has succeeded, so a failed run leaves every replicate and the time step unchanged.
+    pub fn run(&mut self, steps: usize) -> Result<Vec<Board<S>>, AutomatonError> {
+        let curr_time: usize = self.curr_time;
+        let make_rules: &(dyn Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync) = self.make_rules.as_ref();
+        let boards: Vec<Board<S>> = self.boards
+            .par_iter()
+            .zip(self.seeds.par_iter())
+            .map(|(board, &seed)| {
+                let mut board: Board<S> = board.clone();
+                // Each replicate gets its own rules, so replicates share no state
+                let mut automaton: Automaton<'_, S> = Automaton::new(&mut board, make_rules());
+                automaton.set_seed(seed);
+                automaton.set_curr_time(curr_time);
+                automaton.evolve(steps)?;
+                Ok(board)
+            })
+            .collect::<Result<Vec<Board<S>>, AutomatonError>>()?;
+        self.boards = boards;
+        self.curr_time += steps;
+        Ok(self.boards.clone())
+    }
+}
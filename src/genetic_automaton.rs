@@ -25,6 +25,7 @@ use rayon::prelude::*;
 /// - `board`: A reference to the board of cells.
 /// - `population`: A vector of genotypes (genetic rules) to apply to the board.
 /// - `curr_time`: The current time step of the automaton.
+/// - `fitness_history`: The best and mean fitness of the population after each generation, if fitness logging is enabled.
 ///
 /// # Lifetime
 ///
@@ -36,6 +37,8 @@ pub struct GeneticAutomaton<'a, S: State, G: Genotype<S>> {
     population: Population<S, G>,
     /// The current time step of the automaton.
     curr_time: usize,
+    /// The best and mean fitness of the population after each generation, if fitness logging is enabled.
+    fitness_history: Option<Vec<(f64, f64)>>,
 }
 
 impl<'a, S: State, G: Genotype<S>> GeneticAutomaton<'a, S, G> {
@@ -55,6 +58,7 @@ impl<'a, S: State, G: Genotype<S>> GeneticAutomaton<'a, S, G> {
             board,
             population,
             curr_time: 0,
+            fitness_history: None,
         }
     }
 
@@ -85,6 +89,33 @@ impl<'a, S: State, G: Genotype<S>> GeneticAutomaton<'a, S, G> {
         &mut self.population
     }
 
+    /// Start recording the best and mean fitness of the population after every generation.
+    ///
+    /// Logging is off by default because it evaluates the fitness of every genotype against the board after each
+    /// generation. Does nothing if logging is already enabled.
+    pub fn enable_fitness_logging(&mut self) {
+        if self.fitness_history.is_none() {
+            self.fitness_history = Some(Vec::new());
+        }
+    }
+
+    /// Stop recording the fitness of the population and discard the recorded history.
+    pub fn disable_fitness_logging(&mut self) {
+        self.fitness_history = None;
+    }
+
+    /// Get the best and mean fitness of the population recorded after each generation since logging was enabled.
+    ///
+    /// The fitness is evaluated against the board the generation was bred on. Generations with an empty population
+    /// are recorded as `(f64::NAN, f64::NAN)`.
+    ///
+    /// # Returns
+    ///
+    /// A slice of `(best, mean)` pairs in generation order, which is empty if logging is not enabled.
+    pub fn fitness_history(&self) -> &[(f64, f64)] {
+        self.fitness_history.as_deref().unwrap_or(&[])
+    }
+
     /// Apply the rules of the automaton to the board.
     /// 
     /// # Returns
@@ -102,12 +133,7 @@ impl<'a, S: State, G: Genotype<S>> GeneticAutomaton<'a, S, G> {
         for rule in &self.population {
             let rule_deltas: Vec<Delta<S>> = coords
                 .par_iter()
-                .filter_map(|coord| {
-                    match rule.delta(coord.clone(), &self.board) {
-                        Ok(deltas) => Some(deltas),
-                        Err(_) => None,
-                    }
-                })
+                .filter_map(|coord| rule.delta_at(*coord, self.board, self.curr_time).ok())
                 .flatten()
                 .collect();
             deltas.extend(rule_deltas);
@@ -136,7 +162,19 @@ impl<'a, S: State, G: Genotype<S>> GeneticAutomaton<'a, S, G> {
         death_rate: f64,
     ) -> Result<(), OutOfBoundsSetError> {
         self.apply_rules()?;
+        // The board has changed, so fitness scores from the previous generation are stale
+        self.population.clear_fitness_cache();
         let _ = self.population.advance_generation(death_rate, growth_rate, self.board);
+        if let Some(fitness_history) = &mut self.fitness_history {
+            let fitness_scores: Vec<f64> = self.population.fitness_scores(self.board);
+            fitness_history.push(match fitness_scores.len() {
+                0 => (f64::NAN, f64::NAN),
+                n => (
+                    fitness_scores.iter().copied().fold(f64::MIN, f64::max),
+                    fitness_scores.iter().sum::<f64>() / n as f64,
+                ),
+            });
+        }
         self.curr_time += 1;
         Ok(())
     }
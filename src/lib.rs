@@ -1,6 +1,11 @@
 pub mod components;
 pub mod automaton;
 pub mod genetic_automaton;
+pub mod block_automaton;
+pub mod ensemble;
+pub mod analysis;
+pub mod stats;
+pub mod rng;
 mod ui;
 
 #[cfg(test)]
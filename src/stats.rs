@@ -0,0 +1,126 @@
+use super::components::{
+    board::{Board, Rect},
+    state::State,
+};
+use rayon::prelude::*;
+
+/// Get the coordinates and states of the cells of a board as a parallel iterator.
+///
+/// # Arguments
+///
+/// - `board`: The board to iterate over.
+///
+/// # Returns
+///
+/// A parallel iterator over tuples of the form `((x, y), state)`, using the row-major layout of `Board::as_slice`.
+fn par_cells<S: State>(board: &Board<S>) -> impl ParallelIterator<Item = ((usize, usize), &S)> {
+    let width: usize = board.width();
+    board
+        .as_slice()
+        .par_iter()
+        .enumerate()
+        .map(move |(i, state)| ((i % width, i / width), state))
+}
+
+/// Compute the fraction of cells on a board that match a predicate, such as the density of live cells.
+///
+/// # Arguments
+///
+/// - `board`: The board to measure.
+/// - `predicate`: A function that returns whether a cell is counted.
+///
+/// # Returns
+///
+/// The fraction of cells that match the predicate, between 0.0 and 1.0, or 0.0 if the board has no cells.
+pub fn density<S: State, F: Fn(&S) -> bool + Sync>(board: &Board<S>, predicate: F) -> f64 {
+    let total: usize = board.as_slice().len();
+    if total == 0 {
+        return 0.0;
+    }
+    let matching: usize = board.as_slice().par_iter().filter(|state| predicate(state)).count();
+    matching as f64 / total as f64
+}
+
+/// Compute the centre of mass of the cells on a board that match a predicate.
+///
+/// Every matching cell has the same weight, so the centre of mass is the mean of their coordinates. Coordinates are
+/// not wrapped around the edges, so a pattern that straddles the edge of a periodic board is measured as two halves.
+///
+/// # Arguments
+///
+/// - `board`: The board to measure.
+/// - `predicate`: A function that returns whether a cell is counted.
+///
+/// # Returns
+///
+/// The centre of mass as `(x, y)`, or None if no cell matches the predicate.
+pub fn centre_of_mass<S: State, F: Fn(&S) -> bool + Sync>(board: &Board<S>, predicate: F) -> Option<(f64, f64)> {
+    let (count, sum_x, sum_y): (usize, usize, usize) = par_cells(board)
+        .filter(|(_, state)| predicate(state))
+        .map(|((x, y), _)| (1, x, y))
+        .reduce(|| (0, 0, 0), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2));
+
+    if count == 0 {
+        return None;
+    }
+    Some((sum_x as f64 / count as f64, sum_y as f64 / count as f64))
+}
+
+/// Compute the smallest region of a board that contains every cell not in the background state.
+///
+/// # Arguments
+///
+/// - `board`: The board to measure.
+/// - `background`: The background state, such as the dead state.
+///
+/// # Returns
+///
+/// The bounding box of the non-background cells, or None if every cell is in the background state.
+pub fn bounding_box<S: State>(board: &Board<S>, background: S) -> Option<Rect> {
+    let (min_x, min_y, max_x, max_y): (usize, usize, usize, usize) = par_cells(board)
+        .filter(|(_, state)| **state != background)
+        .map(|((x, y), _)| (x, y, x, y))
+        .reduce(
+            || (usize::MAX, usize::MAX, 0, 0),
+            |a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)),
+        );
+
+    if min_x == usize::MAX {
+        return None;
+    }
+    Some(Rect::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+}
+
+/// Count the cells in each row of a board that match a predicate.
+///
+/// # Arguments
+///
+/// - `board`: The board to measure.
+/// - `predicate`: A function that returns whether a cell is counted.
+///
+/// # Returns
+///
+/// A vector with the number of matching cells in each row, from top to bottom.
+pub fn row_profile<S: State, F: Fn(&S) -> bool + Sync>(board: &Board<S>, predicate: F) -> Vec<usize> {
+    (0..board.height())
+        .into_par_iter()
+        .map(|y| (0..board.width()).filter(|&x| predicate(&board.get(x, y).unwrap())).count())
+        .collect()
+}
+
+/// Count the cells in each column of a board that match a predicate.
+///
+/// # Arguments
+///
+/// - `board`: The board to measure.
+/// - `predicate`: A function that returns whether a cell is counted.
+///
+/// # Returns
+///
+/// A vector with the number of matching cells in each column, from left to right.
+pub fn column_profile<S: State, F: Fn(&S) -> bool + Sync>(board: &Board<S>, predicate: F) -> Vec<usize> {
+    (0..board.width())
+        .into_par_iter()
+        .map(|x| (0..board.height()).filter(|&y| predicate(&board.get(x, y).unwrap())).count())
+        .collect()
+}
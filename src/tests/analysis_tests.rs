@@ -0,0 +1,32 @@
+use crate::analysis::detect_spaceship;
+use crate::components::board::{Board, BoundaryCondition};
+use crate::components::rule::common_rules::GameOfLifeRule;
+use crate::components::state::common_states::GameOfLifeState;
+use super::life_board;
+
+#[test]
+fn test_detect_spaceship_glider() {
+    let glider: &[(usize, usize)] = &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    let board: Board<GameOfLifeState> = life_board(10, 10, BoundaryCondition::Periodic, glider);
+    assert_eq!(detect_spaceship(&board, &GameOfLifeRule, 8), Some((4, 1, 1)));
+
+    // The period cannot be found if it is longer than the largest period checked
+    assert_eq!(detect_spaceship(&board, &GameOfLifeRule, 3), None);
+
+    // A glider far from the edges of a fixed board moves the same way
+    let board: Board<GameOfLifeState> = life_board(10, 10, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(4, 3), (5, 4), (3, 5), (4, 5), (5, 5)]);
+    assert_eq!(detect_spaceship(&board, &GameOfLifeRule, 4), Some((4, 1, 1)));
+}
+
+#[test]
+fn test_detect_spaceship_ignores_still_lifes_and_oscillators() {
+    let block: Board<GameOfLifeState> = life_board(10, 10, BoundaryCondition::Periodic, &[(4, 4), (5, 4), (4, 5), (5, 5)]);
+    assert_eq!(detect_spaceship(&block, &GameOfLifeRule, 4), None);
+
+    let blinker: Board<GameOfLifeState> = life_board(10, 10, BoundaryCondition::Periodic, &[(3, 4), (4, 4), (5, 4)]);
+    assert_eq!(detect_spaceship(&blinker, &GameOfLifeRule, 4), None);
+
+    // An empty board equals itself under every shift, which is not a spaceship
+    let empty: Board<GameOfLifeState> = life_board(10, 10, BoundaryCondition::Periodic, &[]);
+    assert_eq!(detect_spaceship(&empty, &GameOfLifeRule, 4), None);
+}
@@ -1,9 +1,12 @@
-use crate::automaton::Automaton;
+use crate::automaton::{Automaton, ConflictPolicy};
 use crate::components::board::Board;
 use crate::components::state::common_states::GameOfLifeState;
 use crate::components::board::BoundaryCondition;
-use crate::components::rule::Rule;
+use crate::components::error::{AutomatonError, OutOfBoundsSetError};
+use crate::components::rule::{Delta, Rule};
 use crate::components::rule::common_rules::GameOfLifeRule;
+use std::ops::ControlFlow;
+use super::life_board;
 
 #[test]
 fn test_automaton_new() {
@@ -153,4 +156,733 @@ fn test_automaton_evolve_with_print_game_of_life_ten_steps() {
 
     let _ = automaton.evolve_with_print(10, 20).unwrap();
     assert_eq!(automaton.curr_time(), 10);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_automaton_step_reporting_still_life() {
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 4]; 4];
+    initial_state[1][1] = GameOfLifeState::Alive;
+    initial_state[1][2] = GameOfLifeState::Alive;
+    initial_state[2][1] = GameOfLifeState::Alive;
+    initial_state[2][2] = GameOfLifeState::Alive;
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule {})];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    assert_eq!(automaton.step_reporting().unwrap(), 0);
+    assert_eq!(automaton.curr_time(), 1);
+}
+
+#[test]
+fn test_automaton_evolve_reporting_glider() {
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 8]; 8];
+    initial_state[0][1] = GameOfLifeState::Alive;
+    initial_state[1][2] = GameOfLifeState::Alive;
+    initial_state[2][0] = GameOfLifeState::Alive;
+    initial_state[2][1] = GameOfLifeState::Alive;
+    initial_state[2][2] = GameOfLifeState::Alive;
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule {})];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    let changed: Vec<usize> = automaton.evolve_reporting(8).unwrap();
+
+    assert_eq!(changed.len(), 8);
+    assert!(changed.iter().all(|&count| count > 0));
+    assert_eq!(automaton.curr_time(), 8);
+}
+
+#[test]
+fn test_automaton_evolve_reversible_recovers_initial_board() {
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 6]; 6];
+    initial_state[1][2] = GameOfLifeState::Alive;
+    initial_state[2][3] = GameOfLifeState::Alive;
+    initial_state[3][1] = GameOfLifeState::Alive;
+    initial_state[3][2] = GameOfLifeState::Alive;
+    initial_state[4][4] = GameOfLifeState::Alive;
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let initial_board: Board<GameOfLifeState> = board.clone();
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule {})];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    automaton.evolve_reversible(6).unwrap();
+    assert_ne!(automaton.board(), &initial_board);
+
+    automaton.reverse();
+    automaton.evolve_reversible(6).unwrap();
+
+    assert_eq!(automaton.board(), &initial_board);
+    assert_eq!(automaton.curr_time(), 12);
+}
+
+#[test]
+fn test_automaton_clone_with_board() {
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 6]; 6];
+    initial_state[1][2] = GameOfLifeState::Alive;
+    initial_state[2][3] = GameOfLifeState::Alive;
+    initial_state[3][1] = GameOfLifeState::Alive;
+    initial_state[3][2] = GameOfLifeState::Alive;
+    initial_state[3][3] = GameOfLifeState::Alive;
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let mut board_copy: Board<GameOfLifeState> = board.clone();
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule {}), Box::new(GameOfLifeRule {})];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+    automaton.evolve(1).unwrap();
+    board_copy.clone_from(automaton.board());
+
+    let mut automaton_copy: Automaton<'_, GameOfLifeState> = automaton.clone_with_board(&mut board_copy);
+    automaton.evolve(5).unwrap();
+    automaton_copy.evolve(5).unwrap();
+
+    assert_eq!(automaton_copy.rules().len(), 2);
+    assert_eq!(automaton_copy.curr_time(), automaton.curr_time());
+    assert_eq!(automaton_copy.board(), automaton.board());
+}
+
+#[derive(Clone)]
+struct SetAllRule {
+    state: GameOfLifeState,
+}
+
+impl Rule<GameOfLifeState> for SetAllRule {
+    fn delta(&self, coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        Ok(vec![Delta::new(coord.0, coord.1, self.state)])
+    }
+}
+
+fn order_sensitive_rules() -> Vec<Box<dyn Rule<GameOfLifeState>>> {
+    vec![
+        Box::new(SetAllRule { state: GameOfLifeState::Alive }),
+        Box::new(SetAllRule { state: GameOfLifeState::Dead }),
+    ]
+}
+
+#[test]
+fn test_automaton_shuffled_rule_order_seeds_differ() {
+    let mut outcomes: Vec<GameOfLifeState> = Vec::new();
+    for seed in 0..16 {
+        let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+        let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, order_sensitive_rules());
+        automaton.enable_shuffled_rule_order(seed);
+        automaton.evolve(1).unwrap();
+        outcomes.push(automaton.board().get(0, 0).unwrap());
+    }
+
+    assert!(outcomes.contains(&GameOfLifeState::Alive));
+    assert!(outcomes.contains(&GameOfLifeState::Dead));
+}
+
+#[test]
+fn test_automaton_shuffled_rule_order_reproducible() {
+    let run = |seed: u64| -> Vec<GameOfLifeState> {
+        let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+        let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, order_sensitive_rules());
+        automaton.enable_shuffled_rule_order(seed);
+        (0..20).map(|_| {
+            automaton.evolve(1).unwrap();
+            automaton.board().get(1, 1).unwrap()
+        }).collect()
+    };
+
+    assert_eq!(run(7), run(7));
+}
+
+#[test]
+fn test_automaton_unshuffled_rule_order_last_rule_wins() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, order_sensitive_rules());
+    automaton.enable_shuffled_rule_order(3);
+    automaton.disable_shuffled_rule_order();
+
+    for _ in 0..10 {
+        automaton.evolve(1).unwrap();
+        assert_eq!(automaton.board().get(1, 1), Some(GameOfLifeState::Dead));
+    }
+}
+
+/// Evolve a board by one step with the given rules, computing and applying the deltas serially.
+fn evolve_serially(board: &mut Board<GameOfLifeState>, rules: &[Box<dyn Rule<GameOfLifeState>>]) {
+    let coords: Vec<(usize, usize)> = board.iter_coords().collect();
+    let mut deltas: Vec<Delta<GameOfLifeState>> = Vec::new();
+    for rule in rules {
+        for coord in coords.iter() {
+            deltas.extend(rule.delta(*coord, board).unwrap());
+        }
+    }
+    deltas.iter().for_each(|delta| delta.apply(board).unwrap());
+}
+
+/// Build a square board of the given size with a fixed pseudo-random pattern.
+fn scattered_board(size: usize) -> Board<GameOfLifeState> {
+    let initial_state: Vec<Vec<GameOfLifeState>> = (0..size)
+        .map(|y| (0..size).map(|x| match (x * 7 + y * 13 + x * y) % 5 {
+            0 | 1 => GameOfLifeState::Alive,
+            _ => GameOfLifeState::Dead,
+        }).collect())
+        .collect();
+    Board::new(initial_state, BoundaryCondition::Periodic)
+}
+
+#[test]
+fn test_automaton_parallel_apply_rules_matches_serial() {
+    let mut board: Board<GameOfLifeState> = scattered_board(64);
+    let mut serial_board: Board<GameOfLifeState> = board.clone();
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule)];
+
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, rules.clone());
+    for _ in 0..3 {
+        automaton.evolve(1).unwrap();
+        evolve_serially(&mut serial_board, &rules);
+        assert_eq!(*automaton.board(), serial_board);
+    }
+}
+
+#[test]
+#[ignore = "benchmark; run with `cargo test --release -- --ignored --nocapture`"]
+fn bench_automaton_apply_rules_500x500() {
+    let mut board: Board<GameOfLifeState> = scattered_board(500);
+    let mut serial_board: Board<GameOfLifeState> = board.clone();
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule)];
+
+    let start: std::time::Instant = std::time::Instant::now();
+    evolve_serially(&mut serial_board, &rules);
+    let serial: std::time::Duration = start.elapsed();
+
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, rules);
+    let start: std::time::Instant = std::time::Instant::now();
+    automaton.evolve(1).unwrap();
+    let parallel: std::time::Duration = start.elapsed();
+
+    println!("500x500 Game of Life step: serial {:?}, parallel {:?}", serial, parallel);
+    assert_eq!(*automaton.board(), serial_board);
+}
+
+/// A rule that writes a state to a single target cell, from the cell at `source`.
+#[derive(Clone)]
+struct SetCellRule {
+    source: (usize, usize),
+    target: (usize, usize),
+    state: GameOfLifeState,
+}
+
+impl Rule<GameOfLifeState> for SetCellRule {
+    fn delta(&self, coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        if coord != self.source {
+            return Ok(vec![]);
+        }
+        Ok(vec![Delta::new(self.target.0, self.target.1, self.state)])
+    }
+}
+
+/// Two rules that both write cell (1, 1), the second through a periodic wrap, and a rule that writes cell (0, 0).
+fn conflicting_rules() -> Vec<Box<dyn Rule<GameOfLifeState>>> {
+    vec![
+        Box::new(SetCellRule { source: (0, 0), target: (1, 1), state: GameOfLifeState::Alive }),
+        Box::new(SetCellRule { source: (2, 2), target: (4, 4), state: GameOfLifeState::Dead }),
+        Box::new(SetCellRule { source: (0, 0), target: (0, 0), state: GameOfLifeState::Alive }),
+    ]
+}
+
+#[test]
+fn test_automaton_conflict_policy_last_wins() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, conflicting_rules());
+
+    assert_eq!(automaton.conflict_policy(), ConflictPolicy::LastWins);
+    automaton.evolve(1).unwrap();
+
+    assert_eq!(automaton.board().get(1, 1), Some(GameOfLifeState::Dead));
+    assert_eq!(automaton.board().get(0, 0), Some(GameOfLifeState::Alive));
+}
+
+#[test]
+fn test_automaton_conflict_policy_first_wins() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, conflicting_rules());
+    automaton.set_conflict_policy(ConflictPolicy::FirstWins);
+
+    assert_eq!(automaton.step_reporting().unwrap(), 2);
+    assert_eq!(automaton.board().get(1, 1), Some(GameOfLifeState::Alive));
+    assert_eq!(automaton.board().get(0, 0), Some(GameOfLifeState::Alive));
+}
+
+#[test]
+fn test_automaton_conflict_policy_error() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let initial_board: Board<GameOfLifeState> = board.clone();
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, conflicting_rules());
+    automaton.set_conflict_policy(ConflictPolicy::Error);
+
+    assert_eq!(automaton.evolve(1).unwrap_err(), AutomatonError::Conflict { x: 1, y: 1 });
+    assert_eq!(*automaton.board(), initial_board);
+    assert_eq!(automaton.curr_time(), 0);
+}
+
+#[test]
+fn test_automaton_history_rewind() {
+    let mut board: Board<GameOfLifeState> = Board::from_rle("x = 6, y = 6\nbo$2bo$3o!", BoundaryCondition::Periodic).unwrap();
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(GameOfLifeRule)]);
+
+    assert!(automaton.history().is_empty());
+    assert!(automaton.rewind_to(0).is_err());
+
+    automaton.enable_history();
+    automaton.evolve(5).unwrap();
+
+    assert_eq!(automaton.history().len(), 6);
+    assert_eq!(automaton.history()[5], *automaton.board());
+
+    let step_2: Board<GameOfLifeState> = automaton.history()[2].clone();
+    automaton.rewind_to(2).unwrap();
+
+    assert_eq!(*automaton.board(), step_2);
+    assert_eq!(automaton.curr_time(), 2);
+    assert_eq!(automaton.history().len(), 3);
+    assert!(automaton.rewind_to(3).is_err());
+
+    // Evolving again from the rewound step reproduces the same boards
+    automaton.evolve(1).unwrap();
+    let mut replay_board: Board<GameOfLifeState> = step_2.clone();
+    let mut replay: Automaton<'_, GameOfLifeState> = Automaton::new(&mut replay_board, vec![Box::new(GameOfLifeRule)]);
+    replay.evolve(1).unwrap();
+
+    assert_eq!(automaton.board(), replay.board());
+}
+
+#[test]
+fn test_automaton_rule_mut_tweaks_rule() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> =
+        vec![Box::new(SetCellRule { source: (0, 0), target: (1, 1), state: GameOfLifeState::Alive })];
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board().get(1, 1), Some(GameOfLifeState::Alive));
+
+    // Retarget the rule in place and check the next evolution uses the new target.
+    let rule: &mut Box<dyn Rule<GameOfLifeState>> = automaton.rule_mut(0).unwrap();
+    *rule = Box::new(SetCellRule { source: (0, 0), target: (2, 2), state: GameOfLifeState::Alive });
+    assert!(automaton.rule_mut(1).is_none());
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board().get(2, 2), Some(GameOfLifeState::Alive));
+
+    // Removing every rule through rules_mut leaves the board unchanged.
+    automaton.rules_mut().clear();
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.rules().len(), 0);
+    assert_eq!(automaton.board().get(0, 0), Some(GameOfLifeState::Dead));
+}
+
+#[test]
+fn test_automaton_evolve_tracking_peak() {
+    // A row of five cells grows to a peak of 20 cells after 5 steps before settling at 12.
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 9]; 9];
+    initial_state[4][2..7].fill(GameOfLifeState::Alive);
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(GameOfLifeRule)]);
+
+    let alive = |state: GameOfLifeState| state == GameOfLifeState::Alive;
+    assert_eq!(automaton.evolve_tracking_peak(8, alive).unwrap(), (20, 5));
+    assert_eq!(automaton.curr_time(), 8);
+    assert_eq!(automaton.board().count_where(|&state| alive(state)), 12);
+
+    // A run that never exceeds its starting count peaks at step 0.
+    assert_eq!(automaton.evolve_tracking_peak(2, alive).unwrap(), (12, 0));
+}
+
+#[test]
+fn test_automaton_evolve_until_stable() {
+    // A block is a still life, so the first step changes nothing.
+    let mut block: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2), (2, 3), (3, 3)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut block, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_stable(10).unwrap(), Some(1));
+    assert_eq!(automaton.curr_time(), 1);
+
+    // A blinker oscillates forever and is never reported as stable.
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_stable(10).unwrap(), None);
+    assert_eq!(automaton.curr_time(), 10);
+
+    // A pattern that dies out stabilises once it is empty.
+    let mut pair: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut pair, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_stable(10).unwrap(), Some(2));
+
+    // Stabilising on the last allowed step is distinguished from never stabilising.
+    let mut pair: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut pair, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_stable(1).unwrap(), None);
+    assert_eq!(automaton.evolve_until_stable(1).unwrap(), Some(1));
+}
+
+#[test]
+fn test_automaton_evolve_until_cycle() {
+    // The blinker repeats its initial board after two steps.
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_cycle(10, 2).unwrap(), Some(2));
+
+    // A period of 1 only detects still lifes.
+    assert_eq!(automaton.evolve_until_cycle(10, 1).unwrap(), None);
+    assert_eq!(automaton.curr_time(), 12);
+
+    let mut block: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2), (2, 3), (3, 3)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut block, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_cycle(10, 2).unwrap(), Some(1));
+
+    // Finding a cycle on the last allowed step is distinguished from finding none.
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_cycle(1, 2).unwrap(), None);
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_cycle(2, 2).unwrap(), Some(2));
+}
+
+#[test]
+fn test_board_fingerprint_detects_oscillation() {
+    // A blinker's fingerprints cycle with period 2.
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut fingerprints: Vec<u64> = vec![blinker.fingerprint()];
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    for _ in 0..4 {
+        automaton.evolve(1).unwrap();
+        fingerprints.push(automaton.board().fingerprint());
+    }
+    assert_ne!(fingerprints[0], fingerprints[1]);
+    assert_eq!(fingerprints[0], fingerprints[2]);
+    assert_eq!(fingerprints[1], fingerprints[3]);
+    assert_eq!(fingerprints[0], fingerprints[4]);
+
+    // A block's fingerprint stays constant.
+    let mut block: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2), (2, 3), (3, 3)]);
+    let initial: u64 = block.fingerprint();
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut block, vec![Box::new(GameOfLifeRule)]);
+    automaton.evolve(3).unwrap();
+    assert_eq!(automaton.board().fingerprint(), initial);
+}
+
+#[test]
+fn test_automaton_evolve_until_repeat() {
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_repeat(10).unwrap(), Some((2, 2)));
+
+    let mut block: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(2, 2), (3, 2), (2, 3), (3, 3)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut block, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_repeat(10).unwrap(), Some((1, 1)));
+
+    // A glider never repeats a board in its first few steps.
+    let mut glider: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut glider, vec![Box::new(GameOfLifeRule)]);
+    assert_eq!(automaton.evolve_until_repeat(3).unwrap(), None);
+}
+
+#[test]
+fn test_automaton_evolve_with_callback() {
+    let mut blinker: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut blinker, vec![Box::new(GameOfLifeRule)]);
+
+    // The callback sees every step and the board after it.
+    let mut seen: Vec<(usize, usize)> = Vec::new();
+    let steps: usize = automaton
+        .evolve_with_callback(4, |step, board| {
+            seen.push((step, board.count_where(|&state| state == GameOfLifeState::Alive)));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    assert_eq!(steps, 4);
+    assert_eq!(seen, vec![(1, 3), (2, 3), (3, 3), (4, 3)]);
+
+    // Breaking stops evolution after the current step.
+    let mut calls: usize = 0;
+    let steps: usize = automaton
+        .evolve_with_callback(10, |step, _board| {
+            calls += 1;
+            match step >= 6 {
+                true => ControlFlow::Break(()),
+                false => ControlFlow::Continue(()),
+            }
+        })
+        .unwrap();
+    assert_eq!(steps, 2);
+    assert_eq!(calls, 2);
+    assert_eq!(automaton.curr_time(), 6);
+}
+
+#[cfg(feature = "gif")]
+#[test]
+fn test_automaton_export_gif() {
+    let path = std::env::temp_dir().join(format!("live_iron_export_{}.gif", std::process::id()));
+    let mut glider: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Fixed(GameOfLifeState::Dead), &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut glider, vec![Box::new(GameOfLifeRule)]);
+    automaton.export_gif(4, &path, 3, 100).unwrap();
+    assert_eq!(automaton.curr_time(), 4);
+    let bytes: Vec<u8> = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[..6], b"GIF89a");
+    // The logical screen is the board size scaled by the cell size, stored little-endian.
+    assert_eq!(&bytes[6..10], &[18, 0, 18, 0]);
+    assert_eq!(bytes.last(), Some(&0x3B));
+
+    // Boards a single cell wide or tall are still exported at the scaled size.
+    let path = std::env::temp_dir().join(format!("live_iron_export_row_{}.gif", std::process::id()));
+    let mut row: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Alive; 5]], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut row, vec![Box::new(GameOfLifeRule)]);
+    automaton.export_gif(2, &path, 2, 50).unwrap();
+    let bytes: Vec<u8> = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[..10], b"GIF89a\x0a\x00\x02\x00");
+
+    let path = std::env::temp_dir().join(format!("live_iron_export_column_{}.gif", std::process::id()));
+    let mut column: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead]; 4], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut column, vec![Box::new(GameOfLifeRule)]);
+    automaton.export_gif(1, &path, 1, 0).unwrap();
+    let bytes: Vec<u8> = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[..10], b"GIF89a\x01\x00\x04\x00");
+}
+
+/// A block rule that rotates each block by a half turn, swapping its diagonally opposite cells. It is its own inverse.
+#[derive(Clone)]
+struct HalfTurnBlockRule;
+
+impl crate::components::rule::BlockRule<GameOfLifeState> for HalfTurnBlockRule {
+    fn block_delta(&self, block: [[GameOfLifeState; 2]; 2], _step_parity: usize) -> [[GameOfLifeState; 2]; 2] {
+        [[block[1][1], block[1][0]], [block[0][1], block[0][0]]]
+    }
+}
+
+#[test]
+fn test_block_automaton_margolus_reversible() {
+    use crate::block_automaton::BlockAutomaton;
+
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    board.set(0, 0, GameOfLifeState::Alive).unwrap();
+    board.set(3, 3, GameOfLifeState::Alive).unwrap();
+    board.set(2, 1, GameOfLifeState::Alive).unwrap();
+    let initial: Board<GameOfLifeState> = board.clone();
+
+    let mut automaton: BlockAutomaton<'_, GameOfLifeState> = BlockAutomaton::new(&mut board, Box::new(HalfTurnBlockRule));
+    automaton.evolve(1);
+    // Even steps partition the board from the origin
+    let alive: Vec<(usize, usize)> = automaton.board().iter_coords().filter(|&(x, y)| automaton.board().get(x, y) == Some(GameOfLifeState::Alive)).collect();
+    assert_eq!(alive, vec![(3, 0), (1, 1), (2, 2)]);
+
+    automaton.evolve(1);
+    // Odd steps are offset by one cell, and the block at (3, 3) wraps around the edges of the board
+    let alive: Vec<(usize, usize)> = automaton.board().iter_coords().filter(|&(x, y)| automaton.board().get(x, y) == Some(GameOfLifeState::Alive)).collect();
+    assert_eq!(alive, vec![(1, 1), (2, 2), (0, 3)]);
+    assert_eq!(automaton.curr_time(), 2);
+
+    automaton.evolve_backward(2, &HalfTurnBlockRule);
+    assert_eq!(automaton.curr_time(), 0);
+    assert_eq!(automaton.board(), &initial);
+}
+
+#[test]
+#[should_panic]
+fn test_block_automaton_rejects_odd_dimensions() {
+    use crate::block_automaton::BlockAutomaton;
+
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 4], BoundaryCondition::Periodic);
+    let _automaton: BlockAutomaton<'_, GameOfLifeState> = BlockAutomaton::new(&mut board, Box::new(HalfTurnBlockRule));
+}
+
+#[test]
+fn test_automaton_builder() {
+    use crate::automaton::AutomatonBuilder;
+
+    let mut initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 5]; 5];
+    initial_state[2][1] = GameOfLifeState::Alive;
+    initial_state[2][2] = GameOfLifeState::Alive;
+    initial_state[2][3] = GameOfLifeState::Alive;
+    let mut manual_board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let mut built_board: Board<GameOfLifeState> = manual_board.clone();
+
+    let mut manual: Automaton<'_, GameOfLifeState> = Automaton::new(&mut manual_board, vec![Box::new(GameOfLifeRule)]);
+    manual.set_conflict_policy(ConflictPolicy::FirstWins);
+    manual.enable_history();
+
+    let mut built: Automaton<'_, GameOfLifeState> = Automaton::builder()
+        .board(&mut built_board)
+        .rule(Box::new(GameOfLifeRule))
+        .conflict_policy(ConflictPolicy::FirstWins)
+        .with_history(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(built.rules().len(), manual.rules().len());
+    assert_eq!(built.conflict_policy(), manual.conflict_policy());
+    assert_eq!(built.history(), manual.history());
+
+    manual.evolve(3).unwrap();
+    built.evolve(3).unwrap();
+    assert_eq!(built.curr_time(), manual.curr_time());
+    assert_eq!(built.board(), manual.board());
+    assert_eq!(built.history(), manual.history());
+
+    // A board is required
+    let missing_board: Result<Automaton<'_, GameOfLifeState>, String> = AutomatonBuilder::new().rule(Box::new(GameOfLifeRule)).build();
+    assert!(missing_board.is_err());
+}
+
+/// A time-dependent rule that flips the cell at the origin only on odd generations.
+#[derive(Clone)]
+struct OddGenerationFlipRule;
+
+impl Rule<GameOfLifeState> for OddGenerationFlipRule {
+    fn delta(&self, _coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        Ok(vec![])
+    }
+
+    fn delta_at(&self, coord: (usize, usize), board: &Board<GameOfLifeState>, step: usize) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        if coord != (0, 0) || step.is_multiple_of(2) {
+            return Ok(vec![]);
+        }
+        let flipped: GameOfLifeState = match board.get(0, 0) {
+            Some(GameOfLifeState::Alive) => GameOfLifeState::Dead,
+            _ => GameOfLifeState::Alive,
+        };
+        Ok(vec![Delta::new(0, 0, flipped)])
+    }
+}
+
+#[test]
+fn test_automaton_rules_read_generation() {
+    use crate::components::rule::common_rules::CompositeRule;
+
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(OddGenerationFlipRule)]);
+    assert_eq!(automaton.evolve_reporting(4).unwrap(), vec![0, 1, 0, 1]);
+    assert_eq!(automaton.board().get(0, 0), Some(GameOfLifeState::Dead));
+    automaton.evolve(2).unwrap();
+    assert_eq!(automaton.board().get(0, 0), Some(GameOfLifeState::Alive));
+
+    // Rules that wrap other rules pass the generation on
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+    let composite: CompositeRule<GameOfLifeState> = CompositeRule::new(vec![Box::new(OddGenerationFlipRule)]);
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(composite)]);
+    assert_eq!(automaton.evolve_reporting(4).unwrap(), vec![0, 1, 0, 1]);
+}
+
+#[test]
+fn test_automaton_rejected_deltas() {
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![
+        Box::new(SetCellRule { source: (0, 0), target: (1, 1), state: GameOfLifeState::Alive }),
+        Box::new(SetCellRule { source: (2, 2), target: (3, 2), state: GameOfLifeState::Alive }),
+    ];
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+    assert!(automaton.rejected_deltas().is_empty());
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board().get(1, 1), Some(GameOfLifeState::Alive));
+    assert_eq!(automaton.rejected_deltas(), &[OutOfBoundsSetError { x: 3, y: 2, width: 3, height: 3 }]);
+
+    // Rejected deltas are reported under every conflict policy
+    automaton.set_conflict_policy(ConflictPolicy::FirstWins);
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.rejected_deltas().len(), 1);
+
+    // Without rejected deltas, the report is cleared
+    automaton.rules_mut().pop();
+    automaton.evolve(1).unwrap();
+    assert!(automaton.rejected_deltas().is_empty());
+}
+
+/// A rule that moves every cell one step to the right, which is only correct if every cell reads the previous step.
+#[derive(Clone)]
+struct ShiftRightRule;
+
+impl Rule<GameOfLifeState> for ShiftRightRule {
+    fn delta(&self, coord: (usize, usize), board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        let (x, y): (usize, usize) = coord;
+        let left: GameOfLifeState = board.get((x + board.width() - 1) % board.width(), y).unwrap();
+        Ok(vec![Delta::new(x, y, left)])
+    }
+}
+
+#[test]
+fn test_automaton_evolve_synchronous_reads_previous_step() {
+    let (a, d): (GameOfLifeState, GameOfLifeState) = (GameOfLifeState::Alive, GameOfLifeState::Dead);
+    let initial: Board<GameOfLifeState> = Board::new(vec![vec![a, d, d, d, d]], BoundaryCondition::Periodic);
+
+    // Reading a partially updated board would smear the live cell along the whole row
+    let mut board: Board<GameOfLifeState> = initial.clone();
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(ShiftRightRule)]);
+    automaton.enable_history();
+    automaton.evolve_synchronous(3).unwrap();
+    assert_eq!(automaton.curr_time(), 3);
+    assert_eq!(automaton.history().len(), 4);
+    assert!(automaton.board().cells_eq(&Board::new(vec![vec![d, d, d, a, d]], BoundaryCondition::Periodic)));
+
+    // The delta path gives the same result
+    let mut delta_board: Board<GameOfLifeState> = initial.clone();
+    Automaton::new(&mut delta_board, vec![Box::new(ShiftRightRule)]).evolve(3).unwrap();
+    assert!(board.cells_eq(&delta_board));
+
+    // The live cell wraps back to the start of the row
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(ShiftRightRule)]);
+    automaton.evolve_synchronous(2).unwrap();
+    assert!(automaton.board().cells_eq(&initial));
+
+    // Without rules, time advances and the board is left unchanged, as in `evolve`
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![]);
+    automaton.evolve_synchronous(2).unwrap();
+    assert_eq!(automaton.curr_time(), 2);
+    assert!(automaton.board().cells_eq(&initial));
+}
+
+/// A glider near the top-left corner of an otherwise dead periodic board of the given size.
+const GLIDER: [(usize, usize); 5] = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+
+#[test]
+fn test_automaton_active_set_matches_full_iteration() {
+    let mut board: Board<GameOfLifeState> = life_board(100, 100, BoundaryCondition::Periodic, &GLIDER);
+    let mut full_board: Board<GameOfLifeState> = board.clone();
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(GameOfLifeRule)]);
+    let mut full: Automaton<GameOfLifeState> = Automaton::new(&mut full_board, vec![Box::new(GameOfLifeRule)]);
+
+    automaton.enable_active_set(1);
+    assert_eq!(automaton.active_cells(), None);
+    for _ in 0..20 {
+        let changed: usize = automaton.step_reporting().unwrap();
+        assert_eq!(changed, full.step_reporting().unwrap());
+        assert_eq!(automaton.board(), full.board());
+    }
+
+    // Only the cells around the glider remain active
+    let active: &[(usize, usize)] = automaton.active_cells().unwrap();
+    assert!(active.len() < 50);
+    assert!(active.windows(2).all(|pair| (pair[0].1, pair[0].0) < (pair[1].1, pair[1].0)));
+
+    // Changing the rules makes every cell active again
+    automaton.rules_mut();
+    assert_eq!(automaton.active_cells(), None);
+    automaton.disable_active_set();
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.active_cells(), None);
+}
+
+#[test]
+#[ignore = "benchmark; run with `cargo test --release -- --ignored --nocapture`"]
+fn bench_automaton_active_set_500x500() {
+    let mut board: Board<GameOfLifeState> = life_board(500, 500, BoundaryCondition::Periodic, &GLIDER);
+    let mut full_board: Board<GameOfLifeState> = board.clone();
+
+    let mut full: Automaton<GameOfLifeState> = Automaton::new(&mut full_board, vec![Box::new(GameOfLifeRule)]);
+    let start: std::time::Instant = std::time::Instant::now();
+    full.evolve(20).unwrap();
+    let full_elapsed: std::time::Duration = start.elapsed();
+
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(GameOfLifeRule)]);
+    automaton.enable_active_set(1);
+    let start: std::time::Instant = std::time::Instant::now();
+    automaton.evolve(20).unwrap();
+    let active_elapsed: std::time::Duration = start.elapsed();
+
+    println!("500x500 glider, 20 steps: every cell {:?}, active set {:?}", full_elapsed, active_elapsed);
+    assert_eq!(automaton.board(), full.board());
+}
@@ -1,4 +1,8 @@
-use crate::components::{board::Board, state::common_states::GameOfLifeState, error::OutOfBoundsSetError, board::BoundaryCondition};
+use crate::components::{board::{Axis, Board, BoardRepresentation, Colour}, state::{common_states::{AntDirection, CellColour, GameOfLifeState, GenerationsState, LangtonsAntState}, Glyph, State}, error::{OutOfBoundsSetError, ParseError}, board::BoundaryCondition};
+use crate::automaton::Automaton;
+use crate::components::rule::common_rules::GameOfLifeRule;
+use crate::components::neighbourhood::{Neighbourhood, NeighbourhoodType};
+use std::collections::HashMap;
 
 #[test]
 fn test_board_new_no_panic() {
@@ -187,4 +191,828 @@ fn test_board_iter_coords() {
     });
 
     assert_eq!(coords, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 2), (2, 2)]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_board_xor_with() {
+    let board: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Dead],
+    ], BoundaryCondition::Periodic);
+    let other: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Dead],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Alive],
+    ], BoundaryCondition::Periodic);
+
+    let expected: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+    ], BoundaryCondition::Periodic);
+
+    assert_eq!(board.xor_with(&other), expected);
+    assert_eq!(board.xor_with(&other).xor_with(&other), board);
+}
+
+#[test]
+fn test_board_display_compact_blinker() {
+    let board: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Dead],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Alive, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Dead],
+    ], BoundaryCondition::Periodic);
+
+    let expected: &str = "...\n###\n...\n";
+
+    assert_eq!(board.display_compact(), expected);
+}
+
+#[test]
+fn test_board_display_compact_single_character_states() {
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Glyph {
+        O,
+        X,
+    }
+    impl State for Glyph {}
+
+    let board: Board<Glyph> = Board::new(vec![
+        vec![Glyph::O, Glyph::X, Glyph::O],
+        vec![Glyph::O, Glyph::X, Glyph::O],
+        vec![Glyph::O, Glyph::X, Glyph::O],
+    ], BoundaryCondition::Periodic);
+
+    assert_eq!(board.display_compact(), "OXO\nOXO\nOXO\n");
+}
+
+#[test]
+fn test_board_percolates_spanning_path() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 0],
+        vec![0, 1, 1, 0],
+        vec![0, 0, 1, 0],
+        vec![0, 0, 1, 1],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+
+    assert!(board.percolates(|s| s == GameOfLifeState::Alive, Axis::Vertical));
+    assert!(!board.percolates(|s| s == GameOfLifeState::Alive, Axis::Horizontal));
+}
+
+#[test]
+fn test_board_percolates_non_spanning_cluster() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 0],
+        vec![0, 1, 0, 0],
+        vec![0, 0, 1, 0],
+        vec![0, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+
+    // The two clusters only touch diagonally, which is not 4-connected
+    assert!(!board.percolates(|s| s == GameOfLifeState::Alive, Axis::Vertical));
+}
+
+#[test]
+fn test_board_get_many() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0],
+        vec![1, 0, 0],
+        vec![0, 0, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let coords: Vec<(usize, usize)> = vec![(1, 0), (0, 1), (2, 1), (1, 2)];
+
+    let expected: Vec<Option<GameOfLifeState>> = coords.iter().map(|&(x, y)| board.get(x, y)).collect();
+
+    assert_eq!(board.get_many(&coords), expected);
+    assert_eq!(board.get_many(&coords)[..2], [Some(GameOfLifeState::Alive), Some(GameOfLifeState::Alive)]);
+    assert_eq!(board.get_many(&[(3, 0), (0, 0)]), vec![None, Some(GameOfLifeState::Dead)]);
+}
+
+#[test]
+fn test_board_set_out_of_bounds_reflective() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Reflective);
+
+    board.set(3, 4, GameOfLifeState::Alive).unwrap();
+    board.set(usize::MAX, 0, GameOfLifeState::Alive).unwrap();
+
+    // x = 3 mirrors to x = 2, y = 4 mirrors to y = 1, and x = -1 mirrors to x = 0
+    assert_eq!(board.get(2, 1), Some(GameOfLifeState::Alive));
+    assert_eq!(board.get(0, 0), Some(GameOfLifeState::Alive));
+    assert_eq!(board.display_compact().matches('#').count(), 2);
+}
+
+#[test]
+fn test_board_from_rle_glider() {
+    let rle: &str = "#N Glider\n#C A comment line\nx = 4, y = 4, rule = B3/S23\nbo$2bo$3o!\n";
+    let board: Board<GameOfLifeState> = Board::from_rle(rle, BoundaryCondition::Periodic).unwrap();
+
+    let expected: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 0],
+        vec![0, 0, 1, 0],
+        vec![1, 1, 1, 0],
+        vec![0, 0, 0, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    assert_eq!(board, Board::new(expected, BoundaryCondition::Periodic));
+}
+
+#[test]
+fn test_board_from_rle_bounded_grid_rule() {
+    // Golly writes bounded grids into the rule, whose value contains a comma.
+    let rle: &str = "x = 3, y = 3, rule = B3/S23:T10,10\nbo$2bo$3o!\n";
+    let board: Board<GameOfLifeState> = Board::from_rle(rle, BoundaryCondition::Periodic).unwrap();
+
+    assert_eq!((board.width(), board.height()), (3, 3));
+    assert_eq!(board.count_states()[&GameOfLifeState::Alive], 5);
+}
+
+#[test]
+fn test_board_rle_round_trip() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 1, 0, 0],
+        vec![0, 0, 0, 1, 0],
+        vec![0, 1, 1, 1, 0],
+        vec![0, 0, 0, 0, 0],
+        vec![0, 0, 0, 0, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let rle: String = board.to_rle();
+
+    assert_eq!(rle, "x = 5, y = 6, rule = B3/S23\n$2bo$3bo$b3o!\n");
+    assert_eq!(Board::from_rle(&rle, BoundaryCondition::Fixed(GameOfLifeState::Dead)).unwrap(), board);
+}
+
+#[test]
+fn test_board_from_rle_malformed() {
+    let malformed: [&str; 6] = [
+        "bo$2bo$3o!",
+        "x = 3, y = 3\nbo$2bo$3o",
+        "x = 3, y = 3\nbo$0bo$3o!",
+        "x = 3, y = 3\nbo$2bo$4o!",
+        "x = 3, y = 3\nbo$2bo$3o$o!",
+        "x = 3, y = 3\nbo$2bq$3o!",
+    ];
+
+    for rle in malformed {
+        let result: Result<Board<GameOfLifeState>, ParseError> = Board::from_rle(rle, BoundaryCondition::Periodic);
+        assert!(result.is_err(), "{:?} should not parse", rle);
+    }
+}
+
+#[test]
+fn test_board_from_plaintext_blinker_evolves() {
+    let plaintext: &str = "!Name: Blinker\n!A period 2 oscillator\n.....\n..O\n..O\n..O\n.....\n";
+    let mut board: Board<GameOfLifeState> = Board::from_plaintext(plaintext, BoundaryCondition::Fixed(GameOfLifeState::Dead)).unwrap();
+
+    assert_eq!((board.width(), board.height()), (5, 5));
+    assert_eq!(board.to_plaintext(), ".....\n..O..\n..O..\n..O..\n.....\n");
+
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(GameOfLifeRule)]);
+    automaton.evolve(1).unwrap();
+
+    assert_eq!(automaton.board().to_plaintext(), ".....\n.....\n.OOO.\n.....\n.....\n");
+}
+
+#[test]
+fn test_board_from_plaintext_malformed() {
+    assert!(Board::from_plaintext("!Only a comment\n", BoundaryCondition::Periodic).is_err());
+    assert!(Board::from_plaintext(".O.\n.X.\n", BoundaryCondition::Periodic).is_err());
+}
+
+#[test]
+fn test_board_swap() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![1, 0, 0],
+        vec![0, 0, 0],
+        vec![0, 0, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
+    board.swap((0, 0), (2, 1)).unwrap();
+
+    assert_eq!(board.get(0, 0), Some(GameOfLifeState::Dead));
+    assert_eq!(board.get(2, 1), Some(GameOfLifeState::Alive));
+
+    // Periodic coordinates wrap around before swapping
+    board.swap((5, 4), (3, 3)).unwrap();
+
+    assert_eq!(board.get(2, 1), Some(GameOfLifeState::Dead));
+    assert_eq!(board.get(0, 0), Some(GameOfLifeState::Alive));
+
+    let mut fixed_board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    assert_eq!(fixed_board.swap((0, 0), (3, 0)).unwrap_err(), OutOfBoundsSetError { x: 3, y: 0, width: 3, height: 3 });
+    assert_eq!(fixed_board.get(0, 0), Some(GameOfLifeState::Alive));
+}
+
+#[test]
+fn test_board_shrink_to_fit() {
+    // Building the board from nested rows grows the cell vector as it is flattened, leaving excess capacity
+    let initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 5]; 5];
+    let mut board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Periodic);
+    let capacity_before: usize = board.capacity();
+
+    board.shrink_to_fit();
+
+    assert!(capacity_before > board.capacity());
+    assert_eq!(board.capacity(), board.width() * board.height());
+}
+
+#[test]
+fn test_board_rle_file_round_trip() {
+    let path: std::path::PathBuf = std::env::temp_dir().join(format!("live_iron_glider_{}.rle", std::process::id()));
+    let board: Board<GameOfLifeState> = Board::from_rle("x = 5, y = 5\n$2bo$3bo$b3o!", BoundaryCondition::Periodic).unwrap();
+
+    // Comment lines of an existing file are kept when it is overwritten
+    std::fs::write(&path, "#N Glider\n#O Richard K. Guy\nx = 1, y = 1\no!\n").unwrap();
+    board.to_rle_file(&path, GameOfLifeState::Alive, GameOfLifeState::Dead).unwrap();
+    let contents: String = std::fs::read_to_string(&path).unwrap();
+    let read_board: Board<GameOfLifeState> = Board::from_rle_file(&path, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(contents.starts_with("#N Glider\n#O Richard K. Guy\nx = 5, y = 5"));
+    assert_eq!(read_board, board);
+    let missing: std::io::Error = Board::from_rle_file(&path, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead).unwrap_err();
+    assert_eq!(missing.kind(), std::io::ErrorKind::NotFound);
+
+    // A malformed pattern is reported as invalid data
+    std::fs::write(&path, "x = 2\nbo!\n").unwrap();
+    let malformed: std::io::Error = Board::from_rle_file(&path, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(malformed.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_board_random_alive_seeded() {
+    let board_a: Board<GameOfLifeState> = Board::random_alive(16, 12, 0.4, 42);
+    let board_b: Board<GameOfLifeState> = Board::random_alive(16, 12, 0.4, 42);
+    let board_c: Board<GameOfLifeState> = Board::random_alive(16, 12, 0.4, 43);
+
+    assert_eq!((board_a.width(), board_a.height()), (16, 12));
+    assert_eq!(board_a, board_b);
+    assert_ne!(board_a, board_c);
+    assert!(Board::random_alive(4, 4, 0.0, 42).none_match(|s| s == GameOfLifeState::Alive));
+    assert!(Board::random_alive(4, 4, 1.0, 42).none_match(|s| s == GameOfLifeState::Dead));
+}
+
+#[test]
+fn test_board_random_with_seeded() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let build = |seed: u64| -> Board<GameOfLifeState> {
+        let mut rng: StdRng = StdRng::seed_from_u64(seed);
+        Board::random_with(8, 8, BoundaryCondition::Reflective, &mut rng, |rng| match rng.gen_range(0..3) {
+            0 => GameOfLifeState::Alive,
+            _ => GameOfLifeState::Dead,
+        })
+    };
+
+    assert_eq!(build(7), build(7));
+    assert_ne!(build(7), build(8));
+    assert_eq!(build(7).boundary_condition(), BoundaryCondition::Reflective);
+}
+
+#[test]
+fn test_board_from_fn_checkerboard() {
+    let checkerboard = |x: usize, y: usize| {
+        if (x + y) % 2 == 1 {
+            GameOfLifeState::Dead
+        } else {
+            GameOfLifeState::Alive
+        }
+    };
+    let board: Board<GameOfLifeState> = Board::from_fn(4, 3, BoundaryCondition::Periodic, checkerboard);
+    let expected: Board<GameOfLifeState> = Board::new(
+        (0..3).map(|y| (0..4).map(|x| checkerboard(x, y)).collect()).collect(),
+        BoundaryCondition::Periodic,
+    );
+
+    assert_eq!(board.width(), 4);
+    assert_eq!(board.height(), 3);
+    assert_eq!(board, expected);
+    assert_eq!(board.get(1, 0), Some(GameOfLifeState::Dead));
+    assert_eq!(board.get(2, 2), Some(GameOfLifeState::Alive));
+}
+
+#[test]
+fn test_board_count_states_and_count_where() {
+    let board: Board<GameOfLifeState> = Board::from_fn(4, 3, BoundaryCondition::Periodic, |x, y| match x < y {
+        true => GameOfLifeState::Alive,
+        false => GameOfLifeState::Dead,
+    });
+
+    let counts: HashMap<GameOfLifeState, usize> = board.count_states();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&GameOfLifeState::Alive], 3);
+    assert_eq!(counts[&GameOfLifeState::Dead], 9);
+    assert_eq!(board.count_where(|state| *state == GameOfLifeState::Alive), 3);
+    assert_eq!(board.count_where(|_| true), 12);
+
+    // States with no cells are left out of the counts.
+    let empty: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 2]; 2], BoundaryCondition::Periodic);
+    assert_eq!(empty.count_states().get(&GameOfLifeState::Alive), None);
+    assert_eq!(empty.count_where(|state| *state == GameOfLifeState::Alive), 0);
+}
+
+#[test]
+fn test_board_get_mut() {
+    let ant: LangtonsAntState = LangtonsAntState { colour: CellColour::White, ant_direction: Some(AntDirection::Up) };
+    let empty: LangtonsAntState = LangtonsAntState { colour: CellColour::White, ant_direction: None };
+    let mut initial_state: Vec<Vec<LangtonsAntState>> = vec![vec![empty; 3]; 3];
+    initial_state[1][1] = ant;
+    let mut board: Board<LangtonsAntState> = Board::new(initial_state, BoundaryCondition::Periodic);
+
+    // Turn the ant in place and read the new heading back.
+    board.get_mut(1, 1).unwrap().ant_direction = Some(AntDirection::Left);
+    assert_eq!(board.get(1, 1).unwrap().ant_direction, Some(AntDirection::Left));
+
+    // Periodic boundaries wrap the coordinates.
+    board.get_mut(3, 4).unwrap().colour = CellColour::Black;
+    assert_eq!(board.get(0, 1).unwrap().colour, CellColour::Black);
+
+    // Fixed boundaries return None out of bounds.
+    let mut fixed: Board<LangtonsAntState> = Board::new(vec![vec![empty; 3]; 3], BoundaryCondition::Fixed(empty));
+    assert!(fixed.get_mut(3, 0).is_none());
+    fixed.get_mut(2, 2).unwrap().colour = CellColour::Black;
+    assert_eq!(fixed.get(2, 2).unwrap().colour, CellColour::Black);
+}
+
+#[test]
+fn test_board_to_representation_with_ant_heading() {
+    let directions: [AntDirection; 4] = [AntDirection::Up, AntDirection::Right, AntDirection::Down, AntDirection::Left];
+    let board: Board<LangtonsAntState> = Board::from_fn(5, 1, BoundaryCondition::Periodic, |x, _y| LangtonsAntState {
+        colour: CellColour::Black,
+        ant_direction: directions.get(x).copied(),
+    });
+
+    let representation: BoardRepresentation = board.to_representation_with(LangtonsAntState::heading_colour);
+    let row: &Vec<Colour> = &representation[0];
+
+    // The four headings map to four distinct colours, none of which is a plain cell colour.
+    for i in 0..4 {
+        for j in (i + 1)..4 {
+            assert_ne!(row[i], row[j]);
+        }
+        assert_ne!(row[i], Colour::black());
+        assert_ne!(row[i], Colour::white());
+    }
+    assert_eq!(row[4], Colour::black());
+
+    // The default representation is unchanged.
+    assert_eq!(board.to_representation()[0][..4], [Colour::red(); 4]);
+}
+
+/// A 3x3 board whose cells are alive where `x + 2y` is odd, so every cell can be told apart from its neighbours.
+fn resize_board() -> Board<GameOfLifeState> {
+    Board::from_fn(3, 3, BoundaryCondition::Periodic, |x, y| match (x + 2 * y) % 2 {
+        1 => GameOfLifeState::Alive,
+        _ => GameOfLifeState::Dead,
+    })
+}
+
+#[test]
+fn test_board_resize_grow() {
+    let original: Board<GameOfLifeState> = resize_board();
+    let mut board: Board<GameOfLifeState> = original.clone();
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    assert!(neighbourhood.get_neighbourhood_coords(&board, 0, 0).contains(&Some((2, 0))));
+
+    board.resize(5, 4, GameOfLifeState::Alive);
+    assert_eq!(board.width(), 5);
+    assert_eq!(board.height(), 4);
+    assert_eq!(board.iter_coords().count(), 20);
+    for (x, y) in board.iter_coords() {
+        match x < 3 && y < 3 {
+            true => assert_eq!(board.get(x, y), original.get(x, y)),
+            false => assert_eq!(board.get(x, y), Some(GameOfLifeState::Alive)),
+        }
+    }
+
+    // A shared neighbourhood sees the new dimensions.
+    let coords: Vec<Option<(usize, usize)>> = neighbourhood.get_neighbourhood_coords(&board, 0, 0);
+    assert!(coords.contains(&Some((4, 0))));
+    assert!(!coords.contains(&Some((2, 0))));
+}
+
+#[test]
+fn test_board_resize_shrink() {
+    let original: Board<GameOfLifeState> = resize_board();
+    let mut board: Board<GameOfLifeState> = original.clone();
+
+    board.resize(2, 1, GameOfLifeState::Alive);
+    assert_eq!(board.width(), 2);
+    assert_eq!(board.height(), 1);
+    assert_eq!(board.iter_coords().count(), 2);
+    assert_eq!(board.get(0, 0), original.get(0, 0));
+    assert_eq!(board.get(1, 0), original.get(1, 0));
+    assert_eq!(board.get(2, 0), None);
+    assert_eq!(board.get(0, 1), None);
+}
+
+#[test]
+fn test_board_from_exact_density() {
+    let density: f64 = 0.3;
+    let count: usize = (density * 400.0).floor() as usize;
+    let make = |seed: u64| {
+        Board::from_exact_density(20, 20, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead, count, seed)
+    };
+
+    let board: Board<GameOfLifeState> = make(7);
+    assert_eq!(board.count_where(|state| *state == GameOfLifeState::Alive), count);
+    assert_eq!(board, make(7));
+    assert_ne!(board, make(8));
+    assert_eq!(make(8).count_where(|state| *state == GameOfLifeState::Alive), count);
+
+    // The extremes fill nothing or everything.
+    assert_eq!(
+        Board::from_exact_density(3, 3, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead, 0, 1),
+        Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic)
+    );
+    assert_eq!(
+        Board::from_exact_density(3, 3, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead, 9, 1),
+        Board::new(vec![vec![GameOfLifeState::Alive; 3]; 3], BoundaryCondition::Periodic)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_board_from_exact_density_too_many_cells() {
+    Board::from_exact_density(2, 2, BoundaryCondition::Periodic, GameOfLifeState::Alive, GameOfLifeState::Dead, 5, 1);
+}
+
+#[test]
+fn test_board_subregion() {
+    let board: Board<GameOfLifeState> = Board::from_fn(5, 5, BoundaryCondition::Fixed(GameOfLifeState::Dead), |x, y| {
+        match (x * 7 + y * 3) % 4 {
+            0 => GameOfLifeState::Alive,
+            _ => GameOfLifeState::Dead,
+        }
+    });
+
+    let centre: Board<GameOfLifeState> = board.subregion(1, 1, 3, 3).unwrap();
+    assert_eq!(centre.width(), 3);
+    assert_eq!(centre.height(), 3);
+    assert_eq!(centre.boundary_condition(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    for (x, y) in centre.iter_coords() {
+        assert_eq!(centre.get(x, y), board.get(x + 1, y + 1));
+    }
+
+    // Windows that extend past the edge are rejected rather than clamped.
+    assert_eq!(
+        board.subregion(3, 0, 3, 2),
+        Err(OutOfBoundsSetError { x: 5, y: 1, width: 5, height: 5 })
+    );
+    assert!(board.subregion(0, 4, 1, 2).is_err());
+    assert_eq!(board.subregion(0, 0, 5, 5).unwrap(), board);
+}
+
+#[test]
+fn test_board_from_quadrant() {
+    let (a, d): (GameOfLifeState, GameOfLifeState) = (GameOfLifeState::Alive, GameOfLifeState::Dead);
+    let board: Board<GameOfLifeState> = Board::from_quadrant(vec![vec![a, d], vec![a, a]], BoundaryCondition::Periodic);
+
+    let expected: Board<GameOfLifeState> = Board::new(
+        vec![
+            vec![a, d, d, a],
+            vec![a, a, a, a],
+            vec![a, a, a, a],
+            vec![a, d, d, a],
+        ],
+        BoundaryCondition::Periodic,
+    );
+    assert_eq!(board, expected);
+
+    // Each quadrant mirrors its neighbours.
+    for (x, y) in board.iter_coords() {
+        assert_eq!(board.get(x, y), board.get(3 - x, y));
+        assert_eq!(board.get(x, y), board.get(x, 3 - y));
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_board_save_png() {
+    let path = std::env::temp_dir().join(format!("live_iron_board_{}.png", std::process::id()));
+    let board: Board<GameOfLifeState> = Board::new(
+        vec![
+            vec![GameOfLifeState::Alive, GameOfLifeState::Dead],
+            vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+        ],
+        BoundaryCondition::Periodic,
+    );
+    board.save_png(&path, 4).unwrap();
+    let image = image::open(&path).unwrap().to_rgb8();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(image.dimensions(), (8, 8));
+    let alive: Colour = GameOfLifeState::Alive.into();
+    let dead: Colour = GameOfLifeState::Dead.into();
+    // Each corner pixel takes the colour of the corner cell it belongs to.
+    assert_eq!(image.get_pixel(0, 0).0, [alive.r, alive.g, alive.b]);
+    assert_eq!(image.get_pixel(7, 0).0, [dead.r, dead.g, dead.b]);
+    assert_eq!(image.get_pixel(0, 7).0, [dead.r, dead.g, dead.b]);
+    assert_eq!(image.get_pixel(7, 7).0, [alive.r, alive.g, alive.b]);
+    // The last pixel of the first cell and the first pixel of the next cell straddle the cell boundary.
+    assert_eq!(image.get_pixel(3, 3).0, [alive.r, alive.g, alive.b]);
+    assert_eq!(image.get_pixel(4, 3).0, [dead.r, dead.g, dead.b]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_board_serde_round_trip() {
+    let board: Board<GameOfLifeState> = Board::random_alive(7, 5, 0.4, 11);
+    let json: String = serde_json::to_string(&board).unwrap();
+    let restored: Board<GameOfLifeState> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, board);
+    assert_eq!((restored.width(), restored.height()), (7, 5));
+
+    // Boundary conditions that carry a state survive the round trip too.
+    let fixed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Alive; 3]; 2], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let restored: Board<GameOfLifeState> = serde_json::from_str(&serde_json::to_string(&fixed).unwrap()).unwrap();
+    assert_eq!(restored, fixed);
+    assert_eq!(restored.boundary_condition(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    let colour: Colour = Colour { r: 12, g: 34, b: 56 };
+    assert_eq!(serde_json::from_str::<Colour>(&serde_json::to_string(&colour).unwrap()).unwrap(), colour);
+}
+
+#[test]
+fn test_board_wrapped_delta() {
+    let periodic: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 10]; 6], BoundaryCondition::Periodic);
+    // The shortest path from the first column to the last crosses the edge.
+    assert_eq!(periodic.wrapped_delta((0, 0), (9, 0)), (-1, 0));
+    assert_eq!(periodic.wrapped_delta((9, 0), (0, 0)), (1, 0));
+    assert_eq!(periodic.wrapped_delta((0, 0), (0, 5)), (0, -1));
+    assert_eq!(periodic.wrapped_delta((2, 1), (8, 4)), (-4, 3));
+    assert_eq!(periodic.wrapped_delta((2, 1), (4, 2)), (2, 1));
+    // Ties go to the positive direction.
+    assert_eq!(periodic.wrapped_delta((0, 0), (5, 3)), (5, 3));
+    assert_eq!(periodic.wrapped_delta((5, 3), (0, 0)), (5, 3));
+
+    let fixed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 10]; 6], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    assert_eq!(fixed.wrapped_delta((0, 0), (9, 5)), (9, 5));
+    assert_eq!(fixed.wrapped_delta((9, 5), (0, 0)), (-9, -5));
+}
+
+#[test]
+fn test_board_normalise_coord() {
+    let periodic: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 3], BoundaryCondition::Periodic);
+    assert_eq!(periodic.normalise_coord(-1, -1), Some((3, 2)));
+    assert_eq!(periodic.normalise_coord(4, 3), Some((0, 0)));
+    assert_eq!(periodic.normalise_coord(-9, 7), Some((3, 1)));
+    assert_eq!(periodic.normalise_coord(2, 1), Some((2, 1)));
+
+    let reflective: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 3], BoundaryCondition::Reflective);
+    assert_eq!(reflective.normalise_coord(-1, -2), Some((0, 1)));
+    assert_eq!(reflective.normalise_coord(4, 3), Some((3, 2)));
+
+    let fixed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    assert_eq!(fixed.normalise_coord(3, 2), Some((3, 2)));
+    assert_eq!(fixed.normalise_coord(-1, 0), None);
+    assert_eq!(fixed.normalise_coord(0, 3), None);
+}
+
+#[test]
+fn test_board_rows_and_columns() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0],
+        vec![1, 1, 0],
+        vec![0, 0, 1],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
+
+    assert_eq!(board.rows().nth(1), Some(&[GameOfLifeState::Alive, GameOfLifeState::Alive, GameOfLifeState::Dead][..]));
+    assert_eq!(board.rows().count(), 3);
+    assert_eq!(board.rows().map(|row| row.to_vec()).collect::<Vec<Vec<GameOfLifeState>>>(), initial_state);
+
+    let columns: Vec<Vec<GameOfLifeState>> = board.columns().collect();
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[2], vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Alive]);
+    for (x, column) in columns.iter().enumerate() {
+        for (y, &state) in column.iter().enumerate() {
+            assert_eq!(Some(state), board.get(x, y));
+        }
+    }
+}
+
+#[test]
+fn test_board_as_slice_is_row_major() {
+    // A non-square board catches a swapped width and height
+    let board: Board<GameOfLifeState> = Board::from_fn(5, 3, BoundaryCondition::Fixed(GameOfLifeState::Dead), |x, y| {
+        if (x * 3 + y) % 4 == 0 { GameOfLifeState::Alive } else { GameOfLifeState::Dead }
+    });
+    let (width, height): (usize, usize) = board.dimensions();
+
+    assert_eq!((width, height), (5, 3));
+    assert_eq!(board.as_slice().len(), width * height);
+    for (x, y) in board.iter_coords() {
+        assert_eq!(Some(board.as_slice()[y * width + x]), board.get(x, y));
+    }
+}
+
+#[test]
+fn test_board_display_uses_glyphs() {
+    let board: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Dead],
+    ], BoundaryCondition::Periodic);
+    assert_eq!(board.to_string(), "+---+---+\n| . | # |\n+---+---+\n| # | . |\n+---+---+\n");
+
+    let ant: LangtonsAntState = LangtonsAntState { colour: CellColour::Black, ant_direction: Some(AntDirection::Left) };
+    assert_eq!(ant.glyph(), '<');
+    assert_eq!(ant.display_glyph(), Some('<'));
+    assert_eq!(GameOfLifeState::Alive.glyph(), '#');
+
+    // States without a glyph fall back to their Debug representation
+    let generations: Board<GenerationsState> = Board::new(vec![vec![GenerationsState::DEAD]], BoundaryCondition::Periodic);
+    assert!(generations.to_string().contains("GenerationsState(0)"));
+    assert_eq!(generations.display_compact(), "GenerationsState(0)\n");
+}
+
+#[test]
+fn test_board_render_ascii_blinker() {
+    let board: Board<GameOfLifeState> = Board::new(vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Dead],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Alive, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Dead],
+    ], BoundaryCondition::Periodic);
+
+    let glyph = |state: &GameOfLifeState| match state {
+        GameOfLifeState::Dead => '.',
+        GameOfLifeState::Alive => '#',
+    };
+
+    assert_eq!(board.render_ascii(glyph), "...\n###\n...");
+}
+
+#[test]
+fn test_board_apply_deltas_reports_rejected() {
+    use crate::components::rule::Delta;
+
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let deltas: Vec<Delta<GameOfLifeState>> = vec![
+        Delta::new(0, 0, GameOfLifeState::Alive),
+        Delta::new(3, 1, GameOfLifeState::Alive),
+        Delta::new(2, 2, GameOfLifeState::Alive),
+        Delta::new(1, 5, GameOfLifeState::Alive),
+    ];
+
+    let (applied, rejected) = board.apply_deltas(&deltas);
+    assert_eq!(applied, 2);
+    assert_eq!(rejected, vec![
+        OutOfBoundsSetError { x: 3, y: 1, width: 3, height: 3 },
+        OutOfBoundsSetError { x: 1, y: 5, width: 3, height: 3 },
+    ]);
+    assert_eq!(board.get(0, 0), Some(GameOfLifeState::Alive));
+    assert_eq!(board.get(2, 2), Some(GameOfLifeState::Alive));
+    assert_eq!(board.count_where(|&state| state == GameOfLifeState::Alive), 2);
+}
+
+#[test]
+fn test_board_cells_eq_ignores_boundary_condition() {
+    let cells: Vec<Vec<GameOfLifeState>> = vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive],
+        vec![GameOfLifeState::Alive, GameOfLifeState::Dead],
+    ];
+    let periodic: Board<GameOfLifeState> = Board::new(cells.clone(), BoundaryCondition::Periodic);
+    let fixed: Board<GameOfLifeState> = Board::new(cells, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    assert_ne!(periodic, fixed);
+    assert!(periodic.cells_eq(&fixed));
+    assert!(fixed.cells_eq(&periodic));
+
+    // The cells and dimensions must still match
+    let mut changed: Board<GameOfLifeState> = fixed.clone();
+    changed.set(0, 0, GameOfLifeState::Alive).unwrap();
+    assert!(!periodic.cells_eq(&changed));
+    let transposed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead, GameOfLifeState::Alive, GameOfLifeState::Alive, GameOfLifeState::Dead]], BoundaryCondition::Periodic);
+    assert!(!periodic.cells_eq(&transposed));
+}
+
+#[test]
+fn test_board_halo_tiles_match_full_board() {
+    let mut full: Board<GameOfLifeState> = Board::random_alive(8, 8, 0.4, 7);
+    let full_fixed: Board<GameOfLifeState> = Board::from_fn(8, 8, BoundaryCondition::Fixed(GameOfLifeState::Dead), |x, y| full.get(x, y).unwrap());
+
+    // A tile cut with a halo of real neighbours evolves its interior exactly as the full board does
+    let mut tile: Board<GameOfLifeState> = full.subregion(1, 1, 6, 6).unwrap();
+    Automaton::new(&mut tile, vec![Box::new(GameOfLifeRule)]).evolve(1).unwrap();
+    Automaton::new(&mut full, vec![Box::new(GameOfLifeRule)]).evolve(1).unwrap();
+    assert!(tile.strip_halo(1).cells_eq(&full.subregion(2, 2, 4, 4).unwrap()));
+
+    // A halo of the fixed state stands in for the edges of a fixed board
+    let mut padded: Board<GameOfLifeState> = full_fixed.with_halo(1, GameOfLifeState::Dead);
+    assert_eq!((padded.width(), padded.height()), (10, 10));
+    assert!(padded.strip_halo(1).cells_eq(&full_fixed));
+    assert_eq!(padded.count_where(|&state| state == GameOfLifeState::Alive), full_fixed.count_where(|&state| state == GameOfLifeState::Alive));
+
+    let mut expected: Board<GameOfLifeState> = full_fixed.clone();
+    Automaton::new(&mut padded, vec![Box::new(GameOfLifeRule)]).evolve(1).unwrap();
+    Automaton::new(&mut expected, vec![Box::new(GameOfLifeRule)]).evolve(1).unwrap();
+    assert!(padded.strip_halo(1).cells_eq(&expected));
+}
+
+#[test]
+fn test_board_edge_write_policy_ignore() {
+    use crate::components::board::EdgeWritePolicy;
+
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    assert_eq!(board.edge_write_policy(), EdgeWritePolicy::Error);
+    assert!(board.set(3, 0, GameOfLifeState::Alive).is_err());
+
+    board.set_edge_write_policy(EdgeWritePolicy::Ignore);
+    let before: Board<GameOfLifeState> = board.clone();
+    assert_eq!(board.set(3, 0, GameOfLifeState::Alive), Ok(()));
+    assert_eq!(board.set(1, usize::MAX, GameOfLifeState::Alive), Ok(()));
+    assert_eq!(board, before);
+
+    // Writes inside the board are unaffected
+    board.set(1, 1, GameOfLifeState::Alive).unwrap();
+    assert_eq!(board.get(1, 1), Some(GameOfLifeState::Alive));
+
+    // The policy is kept by boards derived from this one
+    assert_eq!(board.subregion(0, 0, 2, 2).unwrap().edge_write_policy(), EdgeWritePolicy::Ignore);
+}
+
+#[test]
+fn test_board_stamp_wraps_glider_across_periodic_seam() {
+    let (a, d): (GameOfLifeState, GameOfLifeState) = (GameOfLifeState::Alive, GameOfLifeState::Dead);
+    let glider: Vec<Vec<GameOfLifeState>> = vec![vec![d, a, d], vec![d, d, a], vec![a, a, a]];
+
+    // The glider's top-left corner is two cells from the bottom-right corner, so it wraps in both directions
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![d; 5]; 5], BoundaryCondition::Periodic);
+    board.stamp(3, 3, &glider).unwrap();
+
+    let alive: Vec<(usize, usize)> = board.iter_coords().filter(|&(x, y)| board.get(x, y) == Some(a)).collect();
+    assert_eq!(alive, vec![(0, 0), (3, 0), (4, 0), (4, 3), (0, 4)]);
+    assert_eq!(board.count_where(|&state| state == a), 5);
+
+    // Under a fixed boundary the same stamp fails without writing any cell
+    let mut fixed: Board<GameOfLifeState> = Board::new(vec![vec![d; 5]; 5], BoundaryCondition::Fixed(d));
+    let error: OutOfBoundsSetError = fixed.stamp(3, 3, &glider).unwrap_err();
+    assert_eq!((error.x, error.y), (5, 3));
+    assert_eq!(fixed.count_where(|&state| state == a), 0);
+    fixed.stamp(2, 2, &glider).unwrap();
+    assert_eq!(fixed.get(3, 2), Some(a));
+}
+
+#[test]
+fn test_board_neighbour_respects_boundary_condition() {
+    let periodic: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 3], BoundaryCondition::Periodic);
+    assert_eq!(periodic.neighbour(1, 0, AntDirection::Up), Some((1, 2)));
+    assert_eq!(periodic.neighbour(0, 1, AntDirection::Left), Some((3, 1)));
+    assert_eq!(periodic.neighbour(3, 2, AntDirection::Right), Some((0, 2)));
+    assert_eq!(periodic.neighbour(3, 2, AntDirection::Down), Some((3, 0)));
+
+    let fixed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    assert_eq!(fixed.neighbour(1, 0, AntDirection::Up), None);
+    assert_eq!(fixed.neighbour(0, 1, AntDirection::Left), None);
+    assert_eq!(fixed.neighbour(3, 2, AntDirection::Down), None);
+    assert_eq!(fixed.neighbour(1, 1, AntDirection::Up), Some((1, 0)));
+    assert_eq!(fixed.neighbour(1, 1, AntDirection::Right), Some((2, 1)));
+}
@@ -0,0 +1,86 @@
+use crate::automaton::Automaton;
+use crate::components::{
+    board::{Board, BoundaryCondition},
+    fixed_board::FixedBoard,
+    rule::{common_rules::GameOfLifeRule, Rule},
+    state::common_states::GameOfLifeState,
+};
+
+fn game_of_life<const W: usize, const H: usize>(
+    coord: (usize, usize),
+    board: &FixedBoard<GameOfLifeState, W, H>,
+) -> GameOfLifeState {
+    let mut num_alive: usize = 0;
+    for dy in 0..3 {
+        for dx in 0..3 {
+            if dx == 1 && dy == 1 {
+                continue;
+            }
+            let x: usize = (coord.0 + W + dx - 1) % W;
+            let y: usize = (coord.1 + H + dy - 1) % H;
+            if board.get(x, y) == Some(GameOfLifeState::Alive) {
+                num_alive += 1;
+            }
+        }
+    }
+
+    match (board.get(coord.0, coord.1).unwrap(), num_alive) {
+        (GameOfLifeState::Alive, 2) | (_, 3) => GameOfLifeState::Alive,
+        _ => GameOfLifeState::Dead,
+    }
+}
+
+fn blinker() -> FixedBoard<GameOfLifeState, 5, 5> {
+    let mut board: FixedBoard<GameOfLifeState, 5, 5> = FixedBoard::filled(GameOfLifeState::Dead, BoundaryCondition::Periodic);
+    board.set(2, 1, GameOfLifeState::Alive).unwrap();
+    board.set(2, 2, GameOfLifeState::Alive).unwrap();
+    board.set(2, 3, GameOfLifeState::Alive).unwrap();
+    board
+}
+
+#[test]
+fn test_fixed_board_get_set() {
+    let mut board: FixedBoard<GameOfLifeState, 3, 2> = FixedBoard::filled(GameOfLifeState::Dead, BoundaryCondition::Periodic);
+
+    board.set(4, 3, GameOfLifeState::Alive).unwrap();
+
+    assert_eq!(board.width(), 3);
+    assert_eq!(board.height(), 2);
+    assert_eq!(board.get(1, 1), Some(GameOfLifeState::Alive));
+    assert_eq!(board.get(3, 0), None);
+}
+
+#[test]
+fn test_fixed_board_set_out_of_bounds_fixed() {
+    let mut board: FixedBoard<GameOfLifeState, 3, 3> = FixedBoard::filled(GameOfLifeState::Dead, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    let error = board.set(3, 1, GameOfLifeState::Alive).unwrap_err();
+
+    assert_eq!(error.x, 3);
+    assert_eq!(error.width, 3);
+}
+
+#[test]
+fn test_fixed_board_iter_coords() {
+    let board: FixedBoard<GameOfLifeState, 2, 2> = FixedBoard::filled(GameOfLifeState::Dead, BoundaryCondition::Periodic);
+
+    let coords: Vec<(usize, usize)> = board.iter_coords().collect();
+
+    assert_eq!(coords, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+}
+
+#[test]
+fn test_fixed_board_with_automaton() {
+    let fixed_board: FixedBoard<GameOfLifeState, 5, 5> = blinker();
+    let mut expected: FixedBoard<GameOfLifeState, 5, 5> = fixed_board.clone();
+    expected.step(game_of_life);
+
+    let mut board: Board<GameOfLifeState> = fixed_board.to_board();
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(GameOfLifeRule {})];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+    automaton.evolve(1).unwrap();
+
+    let evolved: FixedBoard<GameOfLifeState, 5, 5> = FixedBoard::from_board(automaton.board()).unwrap();
+    assert_eq!(evolved, expected);
+    assert!(FixedBoard::<GameOfLifeState, 4, 5>::from_board(automaton.board()).is_none());
+}
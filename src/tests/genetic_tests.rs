@@ -0,0 +1,649 @@
+use crate::genetic_automaton::GeneticAutomaton;
+use crate::components::{
+    board::{Board, BoundaryCondition},
+    error::OutOfBoundsSetError,
+    genetic::{
+        crossover_strategy::CrossoverStrategy,
+        fitness::{evaluate_against_target, TargetFitness},
+        genotype::Genotype,
+        island_model::IslandModel,
+        population::Population,
+        selection_strategy::SelectionStrategy,
+    },
+    rule::{Delta, Rule},
+    state::common_states::GameOfLifeState,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A genotype with numeric weights that leaves the board unchanged.
+#[derive(Clone, Debug)]
+struct WeightGenotype {
+    weights: Vec<f64>,
+}
+
+impl Rule<GameOfLifeState> for WeightGenotype {
+    fn delta(&self, _coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        Ok(vec![])
+    }
+}
+
+impl Genotype<GameOfLifeState> for WeightGenotype {
+    fn crossover(&self, other: &Self) -> Self {
+        let weights: Vec<f64> = self.weights.iter().zip(other.weights.iter()).map(|(a, b)| (a + b) / 2.0).collect();
+        WeightGenotype { weights }
+    }
+
+    fn mutate(&mut self, _mutation_rate: f64) {}
+
+    fn fitness(&self, _board: &Board<GameOfLifeState>) -> f64 {
+        // A negative first weight marks a genotype whose evaluation never finishes in reasonable time
+        if self.weights.first().is_some_and(|&w| w < 0.0) {
+            std::thread::sleep(Duration::from_secs(30));
+        }
+        self.weights.iter().sum()
+    }
+
+    fn fitness_until(&self, board: &Board<GameOfLifeState>, deadline: Instant) -> Option<f64> {
+        // The slow evaluation works in small steps and gives up once the deadline has passed
+        if self.weights.first().is_some_and(|&w| w < 0.0) {
+            for _ in 0..3000 {
+                if Instant::now() > deadline {
+                    return None;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            return Some(self.weights.iter().sum());
+        }
+        Some(self.fitness(board))
+    }
+
+    fn genes(&self) -> Vec<f64> {
+        self.weights.clone()
+    }
+}
+
+#[test]
+fn test_genotype_default_distance_is_euclidean() {
+    let a: WeightGenotype = WeightGenotype { weights: vec![1.0, 2.0, 3.0] };
+    let b: WeightGenotype = WeightGenotype { weights: vec![4.0, 6.0, 3.0] };
+
+    let expected: f64 = a.genes().iter().zip(b.genes().iter()).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt();
+
+    assert_eq!(a.distance(&b), 5.0);
+    assert_eq!(a.distance(&b), expected);
+    assert_eq!(b.distance(&a), a.distance(&b));
+    assert_eq!(a.distance(&a), 0.0);
+}
+
+#[test]
+fn test_population_fitness_timeout_caps_slow_genotype() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let genotypes: Vec<WeightGenotype> = vec![
+        WeightGenotype { weights: vec![1.0, 2.0] },
+        WeightGenotype { weights: vec![-1.0, 2.0] },
+    ];
+    let mut population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(2), 0.1);
+    population.enable_fitness_timeout(Duration::from_millis(100), f64::MIN);
+
+    let start: Instant = Instant::now();
+    let scores: Vec<f64> = population.fitness_scores(&board);
+
+    assert!(start.elapsed() < Duration::from_secs(10));
+    assert_eq!(scores, vec![3.0, f64::MIN]);
+    assert_eq!(population.fitness_timeout(), Some(Duration::from_millis(100)));
+}
+
+#[test]
+fn test_population_shrink_removes_selected_genotypes() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let fitnesses: [f64; 5] = [5.0, 2.0, 4.0, 1.0, 3.0];
+    let genotypes: Vec<WeightGenotype> = fitnesses.iter().map(|&fitness| WeightGenotype { weights: vec![fitness] }).collect();
+
+    // A tournament over the whole population deterministically selects the two least fit genotypes, at indices 1 and 3.
+    let mut population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(5), 0.1);
+    population.shrink_population(0.4, &board).unwrap();
+
+    let survivors: Vec<f64> = population.genotypes().iter().map(|genotype| genotype.weights[0]).collect();
+    assert_eq!(survivors, vec![5.0, 4.0, 3.0]);
+}
+
+/// Check that the deaths are distinct indices into the population.
+fn assert_distinct_indices(deaths: &[usize], population_size: usize) {
+    let mut sorted: Vec<usize> = deaths.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    assert_eq!(sorted.len(), deaths.len());
+    assert!(deaths.iter().all(|&index| index < population_size));
+}
+
+#[test]
+fn test_selection_tournament_death_count() {
+    let fitness_scores: [f64; 10] = [5.0, 9.0, 1.0, 7.0, 3.0, 8.0, 2.0, 6.0, 0.0, 4.0];
+
+    let deaths: Vec<usize> = SelectionStrategy::Tournament(3).select_deaths(&fitness_scores, 0.7);
+    assert_eq!(deaths.len(), 3);
+    assert_distinct_indices(&deaths, 10);
+
+    // A tournament over the whole population always selects the least fit survivor.
+    assert_eq!(SelectionStrategy::Tournament(10).select_deaths(&fitness_scores, 0.7), vec![8, 2, 6]);
+    assert!(SelectionStrategy::Tournament(3).select_deaths(&fitness_scores, 1.0).is_empty());
+    assert_eq!(SelectionStrategy::Tournament(3).select_deaths(&fitness_scores, 0.0).len(), 10);
+}
+
+#[test]
+fn test_selection_roulette_wheel_death_count() {
+    let fitness_scores: [f64; 10] = [5.0, 9.0, 1.0, 7.0, 3.0, 8.0, 2.0, 6.0, 0.0, 4.0];
+
+    let deaths: Vec<usize> = SelectionStrategy::RouletteWheel.select_deaths(&fitness_scores, 0.6);
+    assert_eq!(deaths.len(), 4);
+    assert_distinct_indices(&deaths, 10);
+
+    // The fittest individual is never selected while others remain.
+    assert!(!SelectionStrategy::RouletteWheel.select_deaths(&fitness_scores, 0.1).contains(&1));
+
+    // Equal fitness scores still select the right number of individuals.
+    assert_eq!(SelectionStrategy::RouletteWheel.select_deaths(&[1.0; 5], 0.4).len(), 3);
+}
+
+#[test]
+fn test_selection_rank_death_count() {
+    let fitness_scores: [f64; 10] = [5.0, 9.0, 1.0, 7.0, 3.0, 8.0, 2.0, 6.0, 0.0, 4.0];
+
+    let deaths: Vec<usize> = SelectionStrategy::Rank(1.5).select_deaths(&fitness_scores, 0.5);
+    assert_eq!(deaths.len(), 5);
+    assert_distinct_indices(&deaths, 10);
+    assert_eq!(SelectionStrategy::Rank(0.0).select_deaths(&fitness_scores, 0.8).len(), 2);
+}
+
+#[test]
+fn test_selection_truncation_death_count() {
+    let fitness_scores: [f64; 10] = [5.0, 9.0, 1.0, 7.0, 3.0, 8.0, 2.0, 6.0, 0.0, 4.0];
+
+    // Deaths come from outside the top 70%, which is exactly the three least fit individuals.
+    let mut deaths: Vec<usize> = SelectionStrategy::Truncation(0.7).select_deaths(&fitness_scores, 0.7);
+    deaths.sort_unstable();
+    assert_eq!(deaths, vec![2, 6, 8]);
+
+    // Deaths are drawn from the bottom half when fewer are needed.
+    let deaths: Vec<usize> = SelectionStrategy::Truncation(0.5).select_deaths(&fitness_scores, 0.8);
+    assert_eq!(deaths.len(), 2);
+    assert_distinct_indices(&deaths, 10);
+    assert!(deaths.iter().all(|&index| fitness_scores[index] < 5.0));
+
+    // The pool widens when more deaths are needed than there are individuals outside the top percentage.
+    assert_eq!(SelectionStrategy::Truncation(0.9).select_deaths(&fitness_scores, 0.5).len(), 5);
+}
+
+#[test]
+fn test_population_coevolve_scores_round_robin() {
+    // A genotype beats another if its first weight is larger, so the relation is transitive.
+    let strengths: [f64; 4] = [2.0, 4.0, 1.0, 3.0];
+    let genotypes: Vec<WeightGenotype> = strengths.iter().map(|&strength| WeightGenotype { weights: vec![strength] }).collect();
+    let population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(2), 0.1);
+
+    let beats = |a: &WeightGenotype, b: &WeightGenotype| match a.weights[0] > b.weights[0] {
+        true => 1.0,
+        false => 0.0,
+    };
+    let scores: Vec<f64> = population.coevolve_scores(beats);
+
+    assert_eq!(scores, vec![1.0 / 3.0, 1.0, 0.0, 2.0 / 3.0]);
+    let strongest: usize = (0..4).max_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap()).unwrap();
+    assert_eq!(strongest, 1);
+
+    let single: Population<GameOfLifeState, WeightGenotype> =
+        Population::new(vec![WeightGenotype { weights: vec![1.0] }], SelectionStrategy::Tournament(1), 0.1);
+    assert_eq!(single.coevolve_scores(beats), vec![0.0]);
+}
+
+#[test]
+fn test_population_elitism_keeps_best_genotype() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+
+    for _ in 0..20 {
+        let genotypes: Vec<WeightGenotype> = (0..10).map(|i| WeightGenotype { weights: vec![i as f64] }).collect();
+        // Tournaments of a single genotype select deaths uniformly at random, so without elitism the best genotype would often die.
+        let mut population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(1), 0.1);
+        population.set_elitism(1);
+        assert_eq!(population.elitism(), 1);
+
+        for _ in 0..5 {
+            population.advance_generation(0.8, 1.0, &board).unwrap();
+            assert!(population.genotypes().iter().any(|genotype| genotype.weights[0] == 9.0));
+        }
+    }
+}
+
+#[test]
+fn test_population_elitism_ranks_nan_fitness_last() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let genotypes: Vec<WeightGenotype> = [f64::NAN, 1.0, 3.0, 2.0].iter().map(|&weight| WeightGenotype { weights: vec![weight] }).collect();
+    let mut population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::RouletteWheel, 0.1);
+    population.set_elitism(1);
+
+    // The genotype with NaN fitness is not ranked above the fittest genotype, which survives
+    population.shrink_population(0.75, &board).unwrap();
+    assert_eq!(population.genotypes().len(), 1);
+    assert_eq!(population.genotypes()[0].weights, vec![3.0]);
+}
+
+#[test]
+fn test_selection_deaths_protected() {
+    let fitness_scores: [f64; 6] = [5.0, 1.0, 4.0, 0.0, 3.0, 2.0];
+
+    // A tournament over the whole population would select the least fit individual first.
+    let deaths: Vec<usize> = SelectionStrategy::Tournament(6).select_deaths_protected(&fitness_scores, 0.5, &[3]);
+    assert_eq!(deaths, vec![1, 5, 4]);
+
+    // Only the unprotected individuals can die, however many deaths are requested.
+    let mut deaths: Vec<usize> = SelectionStrategy::RouletteWheel.select_deaths_protected(&fitness_scores, 0.0, &[0, 2]);
+    deaths.sort_unstable();
+    assert_eq!(deaths, vec![1, 3, 4, 5]);
+    assert!(SelectionStrategy::Rank(1.0).select_deaths_protected(&fitness_scores, 0.0, &[0, 1, 2, 3, 4, 5]).is_empty());
+}
+
+#[test]
+fn test_selection_stochastic_universal_sampling() {
+    let fitness_scores: [f64; 5] = [100.0, 0.001, 50.0, 0.001, 50.0];
+
+    let mut unfit_picks: usize = 0;
+    for _ in 0..1000 {
+        let (parent1, parent2) = SelectionStrategy::StochasticUniversalSampling.select_parents(&fitness_scores);
+        assert!(parent1 < fitness_scores.len() && parent2 < fitness_scores.len());
+        unfit_picks += [parent1, parent2].iter().filter(|&&parent| fitness_scores[parent] < 1.0).count();
+    }
+    // The unfit individuals hold about 0.001% of the total fitness.
+    assert!(unfit_picks < 5, "unfit individuals were picked {} times", unfit_picks);
+
+    // The pointers are half the total fitness apart, so an individual with half of it is always picked exactly once.
+    for _ in 0..100 {
+        let (parent1, parent2) = SelectionStrategy::StochasticUniversalSampling.select_parents(&[1.0, 2.0, 3.0]);
+        assert!(parent1 < parent2);
+        assert_eq!((parent1 == 2) as usize + (parent2 == 2) as usize, 1);
+    }
+
+    // With zero total fitness, parents are chosen uniformly.
+    let (parent1, parent2) = SelectionStrategy::StochasticUniversalSampling.select_parents(&[0.0, 0.0, 0.0]);
+    assert!(parent1 < 3 && parent2 < 3);
+}
+
+/// A genotype with a vector of genes that honours the crossover strategy, using a fixed random number generator.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct GeneGenotype {
+    genes: Vec<u8>,
+}
+
+impl Rule<GameOfLifeState> for GeneGenotype {
+    fn delta(&self, _coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        Ok(vec![])
+    }
+}
+
+impl Genotype<GameOfLifeState> for GeneGenotype {
+    fn crossover(&self, other: &Self) -> Self {
+        self.crossover_with(other, &CrossoverStrategy::Uniform)
+    }
+
+    fn crossover_with(&self, other: &Self, strategy: &CrossoverStrategy) -> Self {
+        let mut rng: StdRng = StdRng::seed_from_u64(7);
+        GeneGenotype { genes: strategy.crossover_genes(&self.genes, &other.genes, &mut rng) }
+    }
+
+    fn mutate(&mut self, _mutation_rate: f64) {}
+
+    fn fitness(&self, _board: &Board<GameOfLifeState>) -> f64 {
+        self.genes.iter().map(|&gene| gene as f64).sum()
+    }
+}
+
+#[test]
+fn test_crossover_single_point_vs_uniform() {
+    let first: GeneGenotype = GeneGenotype { genes: vec![0; 16] };
+    let second: GeneGenotype = GeneGenotype { genes: vec![1; 16] };
+
+    // Single-point crossover takes a prefix from the first parent and the rest from the second.
+    let child: Vec<u8> = first.crossover_with(&second, &CrossoverStrategy::SinglePoint).genes;
+    let cut: usize = child.iter().position(|&gene| gene == 1).unwrap();
+    assert!(cut > 0);
+    assert!(child[..cut].iter().all(|&gene| gene == 0) && child[cut..].iter().all(|&gene| gene == 1));
+
+    // Two-point crossover takes a single block from the second parent.
+    let child: Vec<u8> = first.crossover_with(&second, &CrossoverStrategy::TwoPoint).genes;
+    let start: usize = child.iter().position(|&gene| gene == 1).unwrap();
+    let end: usize = child.iter().rposition(|&gene| gene == 1).unwrap() + 1;
+    assert!(child[start..end].iter().all(|&gene| gene == 1));
+    assert_eq!(child.iter().filter(|&&gene| gene == 0).count(), 16 - (end - start));
+
+    // Uniform crossover mixes genes from both parents without a single cut.
+    let child: Vec<u8> = first.crossover_with(&second, &CrossoverStrategy::Uniform).genes;
+    assert_eq!(child.len(), 16);
+    let switches: usize = child.windows(2).filter(|pair| pair[0] != pair[1]).count();
+    assert!(switches > 1, "uniform crossover produced {:?}", child);
+
+    // The same random number generator always produces the same child.
+    assert_eq!(first.crossover_with(&second, &CrossoverStrategy::Uniform).genes, child);
+}
+
+#[test]
+fn test_crossover_default_and_population_strategy() {
+    // Genotypes that do not override crossover_with ignore the strategy.
+    let a: WeightGenotype = WeightGenotype { weights: vec![1.0, 3.0] };
+    let b: WeightGenotype = WeightGenotype { weights: vec![3.0, 5.0] };
+    assert_eq!(a.crossover_with(&b, &CrossoverStrategy::TwoPoint).weights, vec![2.0, 4.0]);
+
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let genotypes: Vec<GeneGenotype> = vec![GeneGenotype { genes: vec![0; 8] }, GeneGenotype { genes: vec![1; 8] }];
+    let mut population: Population<GameOfLifeState, GeneGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(2), 0.0);
+    assert_eq!(population.crossover_strategy(), CrossoverStrategy::Uniform);
+    population.set_crossover_strategy(CrossoverStrategy::SinglePoint);
+    population.add_child(&board).unwrap();
+
+    // The parents are distinct, so the child is cut once between a run of one gene and a run of the other.
+    let child: &Vec<u8> = &population.genotypes()[2].genes;
+    assert_eq!(child.windows(2).filter(|pair| pair[0] != pair[1]).count(), 1);
+}
+
+#[test]
+fn test_crossover_genes_short_genes() {
+    let mut rng: StdRng = StdRng::seed_from_u64(1);
+    assert_eq!(CrossoverStrategy::SinglePoint.crossover_genes(&[1], &[2], &mut rng), vec![1]);
+    assert_eq!(CrossoverStrategy::TwoPoint.crossover_genes::<u8, _>(&[], &[], &mut rng), Vec::<u8>::new());
+}
+
+/// A genotype that counts how many times its fitness is evaluated.
+#[derive(Clone, Debug)]
+struct CountingGenotype {
+    fitness: f64,
+    evaluations: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl Rule<GameOfLifeState> for CountingGenotype {
+    fn delta(&self, _coord: (usize, usize), _board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        Ok(vec![])
+    }
+}
+
+impl Genotype<GameOfLifeState> for CountingGenotype {
+    fn crossover(&self, other: &Self) -> Self {
+        CountingGenotype { fitness: (self.fitness + other.fitness) / 2.0, evaluations: self.evaluations.clone() }
+    }
+
+    fn mutate(&mut self, _mutation_rate: f64) {}
+
+    fn fitness(&self, _board: &Board<GameOfLifeState>) -> f64 {
+        self.evaluations.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.fitness
+    }
+}
+
+#[test]
+fn test_population_fitness_cache() {
+    use std::sync::atomic::Ordering;
+
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let evaluations = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let genotypes: Vec<CountingGenotype> = (0..10).map(|i| CountingGenotype { fitness: i as f64, evaluations: evaluations.clone() }).collect();
+    let mut population: Population<GameOfLifeState, CountingGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(3), 0.1);
+
+    // Shrinking evaluates every genotype once, and growing reuses those scores for every child.
+    population.advance_generation(0.2, 0.5, &board).unwrap();
+    assert_eq!(population.len(), 12);
+    assert_eq!(evaluations.load(Ordering::SeqCst), 10);
+
+    // The next generation only evaluates the children, which have not been evaluated yet.
+    population.advance_generation(0.0, 0.0, &board).unwrap();
+    assert_eq!(evaluations.load(Ordering::SeqCst), 10 + 4);
+    assert_eq!(population.fitness_scores(&board).len(), 12);
+    assert_eq!(evaluations.load(Ordering::SeqCst), 10 + 4);
+
+    // Clearing the cache evaluates every genotype again.
+    population.clear_fitness_cache();
+    population.shrink_population(0.0, &board).unwrap();
+    assert_eq!(evaluations.load(Ordering::SeqCst), 10 + 4 + 12);
+
+    // Removed and added genotypes keep the cache in step with the population.
+    population.remove_genotype(0).unwrap();
+    population.add_genotype(CountingGenotype { fitness: 100.0, evaluations: evaluations.clone() });
+    let scores: Vec<f64> = population.fitness_scores(&board);
+    assert_eq!(scores.last(), Some(&100.0));
+    assert_eq!(scores.len(), 12);
+    assert_eq!(evaluations.load(Ordering::SeqCst), 10 + 4 + 12 + 1);
+}
+
+/// Build a population of weight genotypes with the given fitnesses.
+fn weight_population(fitnesses: &[f64]) -> Population<GameOfLifeState, WeightGenotype> {
+    let genotypes: Vec<WeightGenotype> = fitnesses.iter().map(|&fitness| WeightGenotype { weights: vec![fitness] }).collect();
+    Population::new(genotypes, SelectionStrategy::Tournament(2), 0.1)
+}
+
+#[test]
+fn test_island_model_migration() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let islands: Vec<Population<GameOfLifeState, WeightGenotype>> = vec![
+        weight_population(&[1.0, 9.0, 2.0, 8.0]),
+        weight_population(&[10.0, 11.0, 19.0, 18.0]),
+        weight_population(&[29.0, 20.0, 28.0, 21.0]),
+    ];
+    let mut model: IslandModel<GameOfLifeState, WeightGenotype> = IslandModel::new(islands, 1, 2);
+    model.migrate(2, &board);
+
+    // Each island sends its two fittest genotypes to the next island in the ring.
+    let mut fitnesses: Vec<Vec<f64>> = model.islands().iter().map(|island| island.genotypes().iter().map(|genotype| genotype.weights[0]).collect()).collect();
+    fitnesses.iter_mut().for_each(|island| island.sort_by(|a, b| a.partial_cmp(b).unwrap()));
+    assert_eq!(fitnesses, vec![
+        vec![1.0, 2.0, 28.0, 29.0],
+        vec![8.0, 9.0, 10.0, 11.0],
+        vec![18.0, 19.0, 20.0, 21.0],
+    ]);
+}
+
+#[test]
+fn test_island_model_migration_ranks_nan_fitness_last() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let islands: Vec<Population<GameOfLifeState, WeightGenotype>> = vec![
+        weight_population(&[f64::NAN, 1.0, 3.0]),
+        weight_population(&[4.0, 5.0, 6.0]),
+    ];
+    let mut model: IslandModel<GameOfLifeState, WeightGenotype> = IslandModel::new(islands, 1, 1);
+
+    // A genotype with NaN fitness does not panic the ranking and is never sent as the fittest migrant
+    model.migrate(1, &board);
+    let received: Vec<f64> = model.islands()[1].genotypes().iter().map(|genotype| genotype.weights[0]).collect();
+    assert!(received.contains(&3.0));
+    assert!(!received.iter().any(|weight| weight.is_nan()));
+}
+
+#[test]
+fn test_island_model_advance_keeps_islands_balanced() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let islands: Vec<Population<GameOfLifeState, WeightGenotype>> = (0..4)
+        .map(|island| weight_population(&(0..10).map(|i| (island * 10 + i) as f64).collect::<Vec<f64>>()))
+        .collect();
+    let mut model: IslandModel<GameOfLifeState, WeightGenotype> = IslandModel::new(islands, 2, 3);
+
+    // Each generation removes and replaces half of every island, and every second generation exchanges migrants.
+    model.advance_all_generations(5, 0.5, 1.0, &board).unwrap();
+    assert_eq!(model.generation(), 5);
+    assert!(model.islands().iter().all(|island| island.len() == 10));
+
+    // A single island has nowhere to send migrants.
+    let mut model: IslandModel<GameOfLifeState, WeightGenotype> = IslandModel::new(vec![weight_population(&[1.0, 2.0])], 1, 1);
+    model.migrate(1, &board);
+    assert_eq!(model.islands()[0].len(), 2);
+}
+
+#[test]
+fn test_population_diversity() {
+    let distance = |a: &WeightGenotype, b: &WeightGenotype| a.distance(b);
+
+    let identical: Population<GameOfLifeState, WeightGenotype> = weight_population(&[3.0; 6]);
+    assert_eq!(identical.diversity(distance), 0.0);
+    assert_eq!(identical.diversity_sampled(20, 1, distance), 0.0);
+
+    // The pairwise distances of 0, 1, 2 and 3 are 1, 2, 3, 1, 2 and 1, with a mean of 10 / 6.
+    let spread: Population<GameOfLifeState, WeightGenotype> = weight_population(&[0.0, 1.0, 2.0, 3.0]);
+    assert!((spread.diversity(distance) - 10.0 / 6.0).abs() < 1e-12);
+
+    // Sampling many pairs estimates the exact diversity, and the same seed samples the same pairs.
+    let sampled: f64 = spread.diversity_sampled(10_000, 7, distance);
+    assert!((sampled - 10.0 / 6.0).abs() < 0.05, "sampled diversity was {}", sampled);
+    assert_eq!(spread.diversity_sampled(100, 3, distance), spread.diversity_sampled(100, 3, distance));
+
+    assert_eq!(weight_population(&[5.0]).diversity(distance), 0.0);
+    assert_eq!(spread.diversity_sampled(0, 1, distance), 0.0);
+}
+
+#[test]
+fn test_population_adaptive_mutation() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+
+    // Crossover averages weights, so the best fitness never improves and the rate rises towards the maximum.
+    let mut population: Population<GameOfLifeState, WeightGenotype> = weight_population(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    population.set_adaptive_mutation(0.05, 0.5);
+    assert_eq!(population.mutation_rate(), 0.1);
+    population.advance_generation(0.0, 0.0, &board).unwrap();
+    assert_eq!(population.mutation_rate(), 0.1);
+    for _ in 0..5 {
+        let previous_rate: f64 = population.mutation_rate();
+        population.advance_generation(0.5, 1.0, &board).unwrap();
+        assert!(population.mutation_rate() > previous_rate);
+    }
+    assert!(population.mutation_rate() > 0.48 && population.mutation_rate() <= 0.5);
+
+    // An improvement in the best fitness lowers the rate towards the minimum.
+    population.add_genotype(WeightGenotype { weights: vec![100.0] });
+    let previous_rate: f64 = population.mutation_rate();
+    population.advance_generation(0.0, 0.0, &board).unwrap();
+    assert_eq!(population.mutation_rate(), previous_rate - (previous_rate - 0.05) / 2.0);
+
+    population.disable_adaptive_mutation();
+    let previous_rate: f64 = population.mutation_rate();
+    population.advance_generation(0.0, 0.0, &board).unwrap();
+    assert_eq!(population.mutation_rate(), previous_rate);
+}
+
+#[test]
+fn test_population_grow_batch() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let fitnesses: Vec<f64> = (0..20).map(|i| i as f64).collect();
+
+    let grow = |seed: u64| {
+        let mut population: Population<GameOfLifeState, WeightGenotype> = weight_population(&fitnesses);
+        population.set_seed(seed);
+        population.grow_population(0.5, &board).unwrap();
+        population.genotypes().iter().map(|genotype| genotype.weights[0]).collect::<Vec<f64>>()
+    };
+
+    let grown: Vec<f64> = grow(42);
+    assert_eq!(grown.len(), 30);
+    assert_eq!(grown[..20], fitnesses[..]);
+    // Every child is the average of two parents from before growing.
+    assert!(grown[20..].iter().all(|&child| (0.0..=19.0).contains(&child) && (child * 2.0).fract() == 0.0));
+
+    // The same seed selects the same parents, even though the children are created in parallel.
+    assert_eq!(grow(42), grown);
+    assert!((0..5).any(|seed| grow(seed) != grown));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_population_save_load_round_trip() {
+    let path = std::env::temp_dir().join(format!("live_iron_population_{}.json", std::process::id()));
+    let genotypes: Vec<GeneGenotype> = vec![
+        GeneGenotype { genes: vec![1, 2, 3] },
+        GeneGenotype { genes: vec![4, 5, 6] },
+        GeneGenotype { genes: vec![7, 8, 9] },
+    ];
+    let population: Population<GameOfLifeState, GeneGenotype> = Population::new(genotypes.clone(), SelectionStrategy::Truncation(0.5), 0.25);
+
+    population.save(&path).unwrap();
+    let loaded: Population<GameOfLifeState, GeneGenotype> = Population::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.genotypes(), &genotypes);
+    assert_eq!(loaded.mutation_rate(), 0.25);
+    assert!(matches!(loaded.selection_strategy(), SelectionStrategy::Truncation(fraction) if *fraction == 0.5));
+    assert!(Population::<GameOfLifeState, GeneGenotype>::load(std::env::temp_dir().join("live_iron_missing_population.json")).is_err());
+}
+
+#[test]
+fn test_genetic_automaton_fitness_history_records_each_generation() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let genotypes: Vec<WeightGenotype> = vec![
+        WeightGenotype { weights: vec![1.0, 1.0] },
+        WeightGenotype { weights: vec![2.0, 2.0] },
+        WeightGenotype { weights: vec![3.0, 3.0] },
+        WeightGenotype { weights: vec![4.0, 4.0] },
+    ];
+    let population: Population<GameOfLifeState, WeightGenotype> = Population::new(genotypes, SelectionStrategy::Tournament(2), 0.1);
+    let mut automaton: GeneticAutomaton<GameOfLifeState, WeightGenotype> = GeneticAutomaton::new(&mut board, population);
+
+    // Logging is opt-in, so nothing is recorded by default
+    automaton.evolve(2, 0.5, 0.5).unwrap();
+    assert!(automaton.fitness_history().is_empty());
+
+    automaton.enable_fitness_logging();
+    automaton.evolve(5, 0.5, 0.5).unwrap();
+    assert_eq!(automaton.fitness_history().len(), 5);
+    for &(best, mean) in automaton.fitness_history() {
+        assert!(best >= mean);
+        assert!(mean >= 2.0 && best <= 8.0);
+    }
+
+    automaton.disable_fitness_logging();
+    automaton.evolve(1, 0.5, 0.5).unwrap();
+    assert!(automaton.fitness_history().is_empty());
+}
+
+/// A genotype that inverts every cell when its single gene is set, scored against an inverted target.
+#[derive(Clone, Debug)]
+struct InvertGenotype {
+    invert: bool,
+    objective: Arc<TargetFitness<GameOfLifeState>>,
+}
+
+impl Rule<GameOfLifeState> for InvertGenotype {
+    fn delta(&self, coord: (usize, usize), board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        if !self.invert {
+            return Ok(vec![]);
+        }
+        let state: GameOfLifeState = match board.get(coord.0, coord.1) {
+            Some(GameOfLifeState::Alive) => GameOfLifeState::Dead,
+            _ => GameOfLifeState::Alive,
+        };
+        Ok(vec![Delta::new(coord.0, coord.1, state)])
+    }
+}
+
+impl Genotype<GameOfLifeState> for InvertGenotype {
+    fn crossover(&self, other: &Self) -> Self {
+        InvertGenotype { invert: self.invert && other.invert, objective: Arc::clone(&self.objective) }
+    }
+
+    fn mutate(&mut self, _mutation_rate: f64) {}
+
+    fn fitness(&self, _board: &Board<GameOfLifeState>) -> f64 {
+        self.objective.evaluate(self)
+    }
+}
+
+#[test]
+fn test_evaluate_against_target_scores_matching_fraction() {
+    let seed_board: Board<GameOfLifeState> = Board::from_fn(4, 4, BoundaryCondition::Periodic, |x, y| if (x + y) % 3 == 0 { GameOfLifeState::Alive } else { GameOfLifeState::Dead });
+    let target: Board<GameOfLifeState> = Board::from_fn(4, 4, BoundaryCondition::Periodic, |x, y| if (x + y) % 3 == 0 { GameOfLifeState::Dead } else { GameOfLifeState::Alive });
+    let objective: Arc<TargetFitness<GameOfLifeState>> = Arc::new(TargetFitness::new(seed_board.clone(), target.clone(), 1));
+
+    // The inverting genotype reproduces the target exactly, while the identity genotype matches no cell
+    let perfect: InvertGenotype = InvertGenotype { invert: true, objective: Arc::clone(&objective) };
+    let identity: InvertGenotype = InvertGenotype { invert: false, objective: Arc::clone(&objective) };
+    assert_eq!(perfect.fitness(&seed_board), 1.0);
+    assert_eq!(identity.fitness(&seed_board), 0.0);
+
+    // Two steps invert the board back, so half of an evenly split target matches
+    let half: Board<GameOfLifeState> = Board::from_fn(4, 4, BoundaryCondition::Periodic, |x, y| if x < 2 { target.get(x, y).unwrap() } else { seed_board.get(x, y).unwrap() });
+    assert_eq!(evaluate_against_target(&perfect, &seed_board, &half, 2), 0.5);
+    assert_eq!(evaluate_against_target(&perfect, &seed_board, &target, 0), 0.0);
+}
@@ -1,104 +1,296 @@
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 use crate::components::error::OutOfBoundsSetError;
-use crate::components::rule::Delta;
+use crate::components::rule::{Delta, RngRule};
+use crate::components::rule::common_rules::StochasticRule;
+use crate::components::neighbourhood::{Neighbourhood, NeighbourhoodType};
 use crate::components::{
     board::{Board, BoundaryCondition},
     rule::Rule,
     state::State,
 };
 use crate::automaton::Automaton;
+use crate::ensemble::Ensemble;
+use crate::rng::{cell_rng, rule_cell_rng};
 use rand::Rng;
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+enum ForestFireState {
+    Empty,
+    Tree,
+    Burning,
+}
+impl State for ForestFireState {}
+
+/// A forest fire where empty cells grow trees and trees catch fire at random, and burning cells become empty.
+///
+/// The rule is an `RngRule`, so it draws from whichever generator the automaton passes it and runs are reproducible
+/// from the automaton's seed.
+#[derive(Clone)]
+struct ForestFireRule {
+    grow_prob: f64,
+    burn_prob: f64,
+}
+
+impl RngRule<ForestFireState> for ForestFireRule {
+    fn delta_rng(&self, coords: (usize, usize), board: &Board<ForestFireState>, rng: &mut impl Rng) -> Result<Vec<Delta<ForestFireState>>, OutOfBoundsSetError> {
+        let next_state: ForestFireState = match board.get(coords.0, coords.1).unwrap() {
+            ForestFireState::Empty if rng.gen::<f64>() < self.grow_prob => ForestFireState::Tree,
+            ForestFireState::Tree if rng.gen::<f64>() < self.burn_prob => ForestFireState::Burning,
+            ForestFireState::Burning => ForestFireState::Empty,
+            state => state,
+        };
+        Ok(vec![Delta::new(coords.0, coords.1, next_state)])
+    }
+}
+
+/// The forest fire used by the seeded tests, where trees grow often and rarely catch fire.
+fn forest_fire_rule() -> Box<dyn Rule<ForestFireState>> {
+    Box::new(StochasticRule::new(ForestFireRule { grow_prob: 0.3, burn_prob: 0.1 }))
+}
 
 #[test]
 fn test_forest_fire_ca() {
-    // Define ForestFireState
-    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-    enum ForestFireState {
-        Empty,
-        Tree,
-        Burning,
+    let mut board: Board<ForestFireState> = Board::new(vec![vec![ForestFireState::Empty; 10]; 10], BoundaryCondition::Fixed(ForestFireState::Empty));
+    board.set(5, 5, ForestFireState::Burning).unwrap();
+    let initial_counts: HashMap<ForestFireState, usize> = board.count_states();
+
+    // The probabilities are set to 0.0 and 1.0 for testing predictability, and the seed makes the run reproducible
+    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, vec![]);
+    automaton.set_seed(42);
+    automaton.add_rule(Box::new(StochasticRule::new(ForestFireRule { grow_prob: 1.0, burn_prob: 0.0 })));
+    automaton.evolve(10).unwrap();
+
+    // Every empty cell grows a tree and the burning cell burns out and then grows a tree too
+    let final_counts: HashMap<ForestFireState, usize> = board.count_states();
+    assert_ne!(initial_counts, final_counts);
+    assert_eq!(final_counts, HashMap::from([(ForestFireState::Tree, 100)]));
+}
+
+// Fire spreads to adjacent trees and burnt cells become empty, with no regrowth
+#[derive(Clone)]
+struct SpreadingFireRule;
+
+impl Rule<ForestFireState> for SpreadingFireRule {
+    fn delta(&self, coords: (usize, usize), board: &Board<ForestFireState>) -> Result<Vec<Delta<ForestFireState>>, OutOfBoundsSetError> {
+        let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+        let state: ForestFireState = board.get(coords.0, coords.1).unwrap();
+        let next_state: ForestFireState = match state {
+            ForestFireState::Burning => ForestFireState::Empty,
+            ForestFireState::Tree if neighbourhood
+                .get_neighbourhood_states(board, coords.0, coords.1)
+                .contains(&Some(ForestFireState::Burning)) => ForestFireState::Burning,
+            _ => state,
+        };
+        Ok(vec![Delta::new(coords.0, coords.1, next_state)])
     }
-    impl State for ForestFireState {}
+}
+
+/// A row of eight trees between two empty rows, with the leftmost tree burning.
+fn burning_row() -> Board<ForestFireState> {
+    let mut initial_state: Vec<Vec<ForestFireState>> = vec![vec![ForestFireState::Empty; 8]; 3];
+    initial_state[1] = vec![ForestFireState::Tree; 8];
+    initial_state[1][0] = ForestFireState::Burning;
+    Board::new(initial_state, BoundaryCondition::Fixed(ForestFireState::Empty))
+}
+
+#[test]
+fn test_forest_fire_burns_out() {
+    let mut board: Board<ForestFireState> = burning_row();
+    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, vec![Box::new(SpreadingFireRule)]);
 
-    // Define ForestFireRule
-    struct ForestFireRule {
-        burn_prob: f64,
-        grow_prob: f64,
+    let mut steps: usize = 0;
+    while automaton.any_match(|s| s == ForestFireState::Burning) {
+        automaton.evolve(1).unwrap();
+        steps += 1;
+        assert!(steps <= 8, "The fire should burn out after crossing the row of trees");
     }
 
-    impl Rule<ForestFireState> for ForestFireRule {
-        fn delta(&self, coords: (usize, usize), board: &Board<ForestFireState>) -> Result<Vec<Delta<ForestFireState>>, OutOfBoundsSetError> {
-            let mut rng = rand::thread_rng();
-            let mut deltas: Vec<Delta<ForestFireState>> = Vec::new();
-            let state: ForestFireState = board.get(coords.0, coords.1).unwrap();
-
-            match state {
-                ForestFireState::Empty => {
-                    if rng.gen::<f64>() < self.grow_prob {
-                        deltas.push(Delta::new(coords.0,coords.1, ForestFireState::Tree));
-                    }
-                }
-                ForestFireState::Tree => {
-                    if rng.gen::<f64>() < self.burn_prob {
-                        deltas.push(Delta::new(coords.0,coords.1, ForestFireState::Burning));
-                    }
-                }
-                ForestFireState::Burning => {
-                    deltas.push(Delta::new(coords.0,coords.1, ForestFireState::Empty));
-                }
-            }
-            Ok(deltas)
-        }
+    assert_eq!(steps, 8);
+    assert!(automaton.none_match(|s| s == ForestFireState::Burning));
+    assert!(automaton.none_match(|s| s == ForestFireState::Tree));
+}
+
+#[test]
+fn test_forest_fire_evolve_while_burning() {
+    let mut board: Board<ForestFireState> = burning_row();
+    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, vec![Box::new(SpreadingFireRule)]);
+
+    // The step limit stops the fire part of the way along the row.
+    let burning = |board: &Board<ForestFireState>, _steps: usize| board.any_match(|s| s == ForestFireState::Burning);
+    assert_eq!(automaton.evolve_while(3, burning).unwrap(), 3);
+    assert_eq!(automaton.curr_time(), 3);
+    assert_eq!(automaton.board().get(3, 1), Some(ForestFireState::Burning));
+
+    // Without a binding limit, the fire burns until it reaches the end of the row.
+    assert_eq!(automaton.evolve_while(100, burning).unwrap(), 5);
+    assert!(automaton.none_match(|s| s == ForestFireState::Burning));
+    assert_eq!(automaton.evolve_while(100, burning).unwrap(), 0);
+
+    // The predicate also receives the number of steps taken so far.
+    assert_eq!(automaton.evolve_while(100, |_board, steps| steps < 2).unwrap(), 2);
+}
+
+#[test]
+fn test_forest_fire_evolve_with_census() {
+    let mut board: Board<ForestFireState> = burning_row();
+    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, vec![Box::new(SpreadingFireRule)]);
+    let census: Vec<HashMap<ForestFireState, usize>> = automaton.evolve_with_census(10).unwrap();
+
+    assert_eq!(census.len(), 11);
+    assert_eq!(automaton.curr_time(), 10);
+
+    // The fire moves one tree along the row at each step, leaving empty cells behind it
+    let count = |counts: &HashMap<ForestFireState, usize>, state: ForestFireState| counts.get(&state).copied().unwrap_or(0);
+    for (step, counts) in census.iter().enumerate().take(8) {
+        assert_eq!(count(counts, ForestFireState::Burning), 1);
+        assert_eq!(count(counts, ForestFireState::Tree), 7 - step);
+        assert_eq!(count(counts, ForestFireState::Empty), 16 + step);
     }
 
-    // Define initial state
-    let initial_state: Vec<Vec<ForestFireState>> = vec![vec![ForestFireState::Empty; 10]; 10];
-    let mut board: Board<ForestFireState> = Board::new(initial_state, BoundaryCondition::Fixed(ForestFireState::Empty));
-    let _ = board.set(5, 5, ForestFireState::Burning).unwrap();
-
-    // Define rules vector
-    let rules: Vec<Box<dyn Rule<ForestFireState>>> = vec![];
-
-    // Get stats of initial state
-    let mut init_tree_count: i32 = 0;
-    let mut init_burning_count: i32 = 0;
-    let mut init_empty_count: i32 = 0;
-    board.iter_coords().for_each(|coord| {
-        match board.get(coord.0, coord.1).unwrap() {
-            ForestFireState::Tree => init_tree_count += 1,
-            ForestFireState::Burning => init_burning_count += 1,
-            ForestFireState::Empty => init_empty_count += 1,
-        }
-    });
+    // Once the fire has burnt out, every cell is empty
+    assert_eq!(census[8], HashMap::from([(ForestFireState::Empty, 24)]));
+    assert_eq!(census[10], HashMap::from([(ForestFireState::Empty, 24)]));
+}
 
-    // Create automaton
-    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, rules);
+#[test]
+fn test_cell_rng_depends_on_every_argument() {
+    let draw = |seed: u64, step: usize, x: usize, y: usize| cell_rng(seed, step, x, y).gen::<u64>();
+    assert_eq!(draw(1, 2, 3, 4), draw(1, 2, 3, 4));
+    assert_ne!(draw(1, 2, 3, 4), draw(2, 2, 3, 4));
+    assert_ne!(draw(1, 2, 3, 4), draw(1, 3, 3, 4));
+    assert_ne!(draw(1, 2, 3, 4), draw(1, 2, 4, 3));
 
-     // Add rule to automaton (probabilities are set to 0.0 and 1.0 for testing predictability)
-     let forest_fire_rule: ForestFireRule = ForestFireRule {
-        burn_prob: 0.0,
-        grow_prob: 1.0,
-    };
-    automaton.add_rule(Box::new(forest_fire_rule));
+    let draw = |seed: u64, rule: usize, step: usize, x: usize, y: usize| rule_cell_rng(seed, rule, step, x, y).gen::<u64>();
+    assert_eq!(draw(1, 0, 2, 3, 4), draw(1, 0, 2, 3, 4));
+    assert_ne!(draw(1, 0, 2, 3, 4), draw(1, 1, 2, 3, 4));
+    // The next rule of one seed does not repeat the first rule of the next seed
+    assert_ne!(draw(1, 1, 2, 3, 4), draw(2, 0, 2, 3, 4));
+}
+
+/// Run a forest fire seeded through the automaton for 10 steps and return the board after each step.
+fn seeded_forest_fire(seed: u64) -> Vec<Board<ForestFireState>> {
+    let mut board: Board<ForestFireState> = Board::new(vec![vec![ForestFireState::Empty; 10]; 10], BoundaryCondition::Periodic);
+    let mut automaton: Automaton<ForestFireState> = Automaton::new(&mut board, vec![forest_fire_rule()]);
+    automaton.set_seed(seed);
+
+    let mut trajectory: Vec<Board<ForestFireState>> = Vec::new();
+    automaton
+        .evolve_with_callback(10, |_, board| {
+            trajectory.push(board.clone());
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    trajectory
+}
+
+#[test]
+fn test_forest_fire_seed_reproducible() {
+    let run: Vec<Board<ForestFireState>> = seeded_forest_fire(42);
+    assert_eq!(run.len(), 10);
+    assert_eq!(run, seeded_forest_fire(42));
+    assert_ne!(run, seeded_forest_fire(43));
+    // Trees grow, so the run is not trivially empty
+    assert!(run.last().unwrap().any_match(|state| state != ForestFireState::Empty));
+}
 
-    // Evolve automaton
-    let _ = automaton.evolve(10);
+/// Build an ensemble of seeded forest fires on empty boards, one replicate per seed.
+fn forest_fire_ensemble(seeds: Vec<u64>) -> Ensemble<ForestFireState> {
+    let boards: Vec<Board<ForestFireState>> = seeds
+        .iter()
+        .map(|_| Board::new(vec![vec![ForestFireState::Empty; 10]; 10], BoundaryCondition::Periodic))
+        .collect();
+    Ensemble::new(boards, seeds, || vec![forest_fire_rule()])
+}
 
-    // Get stats of final state
-    let mut final_tree_count: i32 = 0;
-    let mut final_burning_count: i32 = 0;
-    let mut final_empty_count: i32 = 0;
+#[test]
+fn test_ensemble_same_seeds_reproducible() {
+    let seeds: Vec<u64> = (0..8).collect();
+    let first: Vec<Board<ForestFireState>> = forest_fire_ensemble(seeds.clone()).run(10).unwrap();
+    let second: Vec<Board<ForestFireState>> = forest_fire_ensemble(seeds).run(10).unwrap();
+    assert_eq!(first.len(), 8);
+    assert_eq!(first, second);
+
+    // Replicates are independent, and each matches a lone automaton with the same seed
+    assert_ne!(first[0], first[1]);
+    assert_eq!(first[3], *seeded_forest_fire(3).last().unwrap());
+
+    // Continuing a run gives the same boards as running for the total number of steps at once
+    let mut split: Ensemble<ForestFireState> = forest_fire_ensemble((0..8).collect());
+    split.run(4).unwrap();
+    assert_eq!(split.run(6).unwrap(), first);
+    assert_eq!(split.curr_time(), 10);
+}
 
-    board.iter_coords().for_each(|coord| {
-        match board.get(coord.0, coord.1).unwrap() {
-            ForestFireState::Tree => final_tree_count += 1,
-            ForestFireState::Burning => final_burning_count += 1,
-            ForestFireState::Empty => final_empty_count += 1,
+/// A rule that draws a number for each cell in the top row and writes it to the same column of its target row, so
+/// the draws of several rules can be compared.
+#[derive(Clone)]
+struct DrawRule {
+    target_row: usize,
+}
+
+impl RngRule<f64> for DrawRule {
+    fn delta_rng(&self, coords: (usize, usize), _board: &Board<f64>, rng: &mut impl Rng) -> Result<Vec<Delta<f64>>, OutOfBoundsSetError> {
+        match coords.1 {
+            0 => Ok(vec![Delta::new(coords.0, self.target_row, rng.gen::<f64>())]),
+            _ => Ok(vec![]),
         }
+    }
+}
+
+#[test]
+fn test_ensemble_consecutive_seeds_do_not_share_rule_draws() {
+    let boards: Vec<Board<f64>> = vec![Board::new(vec![vec![0.0; 4]; 3], BoundaryCondition::Periodic); 2];
+    let mut ensemble: Ensemble<f64> = Ensemble::new(boards, vec![7, 8], || {
+        vec![
+            Box::new(StochasticRule::new(DrawRule { target_row: 1 })),
+            Box::new(StochasticRule::new(DrawRule { target_row: 2 })),
+        ]
     });
-    
-    // Check if final state is different from initial state
-    assert_ne!(init_tree_count, final_tree_count);
-    assert_ne!(init_burning_count, final_burning_count);
-    assert_ne!(init_empty_count, final_empty_count);
-}
\ No newline at end of file
+    let boards: Vec<Board<f64>> = ensemble.run(1).unwrap();
+
+    // Rule 1 of the replicate with seed 7 draws differently from rule 0 of the replicate with seed 8
+    let row = |board: &Board<f64>, y: usize| (0..4).map(|x| board.get(x, y).unwrap()).collect::<Vec<f64>>();
+    assert_ne!(row(&boards[0], 2), row(&boards[1], 1));
+    assert_ne!(row(&boards[0], 1), row(&boards[0], 2));
+}
+
+/// A diffusion rule on continuous states, where each cell moves a fraction of the way towards the mean of its four
+/// neighbours. Every cell gives the same share to each neighbour, so the total mass of the board is conserved.
+#[derive(Clone)]
+struct DiffusionRule {
+    rate: f64,
+    neighbourhood: Arc<Neighbourhood>,
+}
+
+impl Rule<f64> for DiffusionRule {
+    fn delta(&self, coords: (usize, usize), board: &Board<f64>) -> Result<Vec<Delta<f64>>, OutOfBoundsSetError> {
+        let value: f64 = board.get(coords.0, coords.1).unwrap();
+        let average: f64 = self.neighbourhood.get_neighbourhood_average(board, coords.0, coords.1).unwrap();
+        Ok(vec![Delta::new(coords.0, coords.1, value + self.rate * (average - value))])
+    }
+}
+
+#[test]
+fn test_continuous_diffusion_conserves_mass() {
+    let mut board: Board<f64> = Board::from_fn(12, 9, BoundaryCondition::Periodic, |x, y| ((x * 7 + y * 13) % 10) as f64 / 10.0);
+    let initial_mass: f64 = board.iter_coords().map(|(x, y)| board.get(x, y).unwrap()).sum();
+    let (initial_min, initial_max): (f64, f64) = (0.0, 0.9);
+
+    let rule: DiffusionRule = DiffusionRule {
+        rate: 0.5,
+        neighbourhood: Arc::new(Neighbourhood::new_excluding_centre(NeighbourhoodType::VonNeumann, 1)),
+    };
+    let mut automaton: Automaton<f64> = Automaton::new(&mut board, vec![Box::new(rule)]);
+    automaton.evolve(50).unwrap();
+
+    let values: Vec<f64> = automaton.board().iter_coords().map(|(x, y)| automaton.board().get(x, y).unwrap()).collect();
+    let mass: f64 = values.iter().sum();
+    assert!((mass - initial_mass).abs() < 1e-9);
+
+    // Diffusion smooths the board towards its mean without leaving the initial range
+    let mean: f64 = initial_mass / values.len() as f64;
+    assert!(values.iter().all(|&value| value >= initial_min && value <= initial_max));
+    assert!(values.iter().all(|&value| (value - mean).abs() < 0.05));
+}
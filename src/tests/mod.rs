@@ -1,6 +1,23 @@
+use crate::components::board::{Board, BoundaryCondition};
+use crate::components::state::common_states::GameOfLifeState;
+
 pub mod state_tests;
 pub mod board_tests;
+pub mod fixed_board_tests;
 pub mod neighbourhood_tests;
 pub mod rule_tests;
 pub mod automaton_tests;
-pub mod integration_tests;
\ No newline at end of file
+pub mod integration_tests;
+pub mod ui_tests;
+pub mod genetic_tests;
+pub mod analysis_tests;
+pub mod stats_tests;
+
+/// Build a dead Game of Life board of the given size with the given cells alive.
+fn life_board(width: usize, height: usize, boundary_condition: BoundaryCondition<GameOfLifeState>, cells: &[(usize, usize)]) -> Board<GameOfLifeState> {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; width]; height], boundary_condition);
+    for &(x, y) in cells {
+        board.set(x, y, GameOfLifeState::Alive).unwrap();
+    }
+    board
+}
@@ -1,4 +1,5 @@
 use crate::components::{board::Board, neighbourhood::Neighbourhood, neighbourhood::NeighbourhoodType, state::common_states::GameOfLifeState, board::BoundaryCondition};
+use crate::components::neighbourhood::{CircularProvider, HexagonalProvider, MooreProvider, NeighbourhoodProvider, VonNeumannProvider};
 
 #[test]
 fn test_neighbourhood_new_no_panic() {
@@ -22,7 +23,7 @@ fn test_neighbourhood_get_neighbourhood_coords_von_neumann_non_edge_rad_1() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
 
     let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
         Some((0, 1)),
@@ -60,7 +61,7 @@ fn test_neighbourhood_get_neighbourhood_coords_von_neumann_edge_rad_1() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
 
     let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
         Some((4, 0)),
@@ -99,7 +100,7 @@ fn test_neighbourhood_get_neighbourhood_coords_von_neumann_non_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
 
     let expected_neighbourhood_bc_p: Vec<Option<(usize, usize)>> = vec![
         Some((4, 1)),
@@ -153,7 +154,7 @@ fn test_neighbourhood_get_neighbourhood_coords_von_neumann_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
 
     let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
         Some((3, 0)),
@@ -205,7 +206,7 @@ fn test_neighbourhood_get_neighbourhood_coords_moore_non_edge_rad_1() {
         _ => panic!("Invalid state"),
     }).collect()).collect();
 
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
 
@@ -251,7 +252,7 @@ fn test_neighbourhood_get_neighbourhood_coords_moore_edge_rad_1() {
         _ => panic!("Invalid state"),
     }).collect()).collect();
 
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
 
@@ -299,7 +300,7 @@ fn test_neighbourhood_get_neighbourhood_coords_moore_non_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
 
     let expected_neighbourhood_bc_p: Vec<Option<(usize, usize)>> = vec![
         Some((4, 4)),
@@ -377,7 +378,7 @@ fn test_neighbourhood_get_neighbourhood_coords_moore_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
 
     let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
         Some((3, 3)),
@@ -455,7 +456,7 @@ fn test_neighbourhood_get_neighbourhood_states_von_neumann_non_edge_rad_1() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
 
     let expected_neighbourhood_states_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Alive),
@@ -493,7 +494,7 @@ fn test_neighbourhood_get_neighbourhood_states_von_neumann_edge_rad_1() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
 
     let expected_neighbourhood_states_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Dead),
@@ -531,7 +532,7 @@ fn test_neighbourhood_get_neighbourhood_states_von_neumann_non_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
 
     let expected_neighbourhood_states_bc_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Alive),
@@ -585,7 +586,7 @@ fn test_neighbourhood_get_neighbourhood_states_von_neumann_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
 
     let expected_neighbourhood_states_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Alive),
@@ -637,7 +638,7 @@ fn test_neighbourhood_get_neighbourhood_states_moore_non_edge_rad_1() {
         _ => panic!("Invalid state"),
     }).collect()).collect();
 
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
 
@@ -683,7 +684,7 @@ fn test_neighbourhood_get_neighbourhood_states_moore_edge_rad_1() {
         _ => panic!("Invalid state"),
     }).collect()).collect();
 
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
 
@@ -731,7 +732,7 @@ fn test_neighbourhood_get_neighbourhood_states_moore_non_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
 
     let expected_neighbourhood_states_bc_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Dead),
@@ -809,7 +810,7 @@ fn test_neighbourhood_get_neighbourhood_states_moore_edge_rad_2() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
     let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
 
     let expected_neighbourhood_states_p: Vec<Option<GameOfLifeState>> = vec![
         Some(GameOfLifeState::Dead),
@@ -887,7 +888,7 @@ fn test_neighbourhood_get_neighbourhood_states_coords() {
 
     let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
 
-    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
     let neighbourhood_coords: Vec<Option<(usize, usize)>> = neighbourhood.get_neighbourhood_coords(&board_bc_p, 1, 1);
     let neighbourhood_states: Vec<Option<GameOfLifeState>> = neighbourhood.get_neighbourhood_states(&board_bc_p, 1, 1);
 
@@ -901,3 +902,632 @@ fn test_neighbourhood_get_neighbourhood_states_coords() {
 
     assert_eq!(neighbourhood_states_coords, expected_neighbourhood_states_coords);
 }
+
+#[test]
+fn test_neighbourhood_get_neighbourhood_coords_von_neumann_reflective_rad_1() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_r: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Reflective);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+
+    let expected_neighbourhood_top_left: Vec<Option<(usize, usize)>> = vec![
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((1, 0)),
+    ];
+
+    let expected_neighbourhood_bottom_right: Vec<Option<(usize, usize)>> = vec![
+        Some((3, 4)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((4, 4)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 0, 0), expected_neighbourhood_top_left);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 4, 4), expected_neighbourhood_bottom_right);
+    assert!(neighbourhood.get_neighbourhood_states(&board_bc_r, 0, 0).iter().all(|s| s.is_some()));
+}
+
+#[test]
+fn test_neighbourhood_get_neighbourhood_coords_von_neumann_reflective_rad_2() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_r: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Reflective);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 2);
+
+    let expected_neighbourhood_top_left: Vec<Option<(usize, usize)>> = vec![
+        Some((1, 0)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 1)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 2)),
+        Some((1, 0)),
+        Some((1, 0)),
+        Some((1, 1)),
+        Some((2, 0)),
+    ];
+
+    let expected_neighbourhood_bottom_right: Vec<Option<(usize, usize)>> = vec![
+        Some((2, 4)),
+        Some((3, 3)),
+        Some((3, 4)),
+        Some((3, 4)),
+        Some((4, 2)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((4, 3)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((3, 4)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 0, 0), expected_neighbourhood_top_left);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 4, 4), expected_neighbourhood_bottom_right);
+    assert!(neighbourhood.get_neighbourhood_states(&board_bc_r, 0, 0).iter().all(|s| s.is_some()));
+}
+
+#[test]
+fn test_neighbourhood_get_neighbourhood_coords_moore_reflective_rad_1() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_r: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Reflective);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+
+    let expected_neighbourhood_top_left: Vec<Option<(usize, usize)>> = vec![
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((1, 0)),
+        Some((1, 0)),
+        Some((1, 1)),
+    ];
+
+    let expected_neighbourhood_bottom_right: Vec<Option<(usize, usize)>> = vec![
+        Some((3, 3)),
+        Some((3, 4)),
+        Some((3, 4)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 0, 0), expected_neighbourhood_top_left);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 4, 4), expected_neighbourhood_bottom_right);
+    assert!(neighbourhood.get_neighbourhood_states(&board_bc_r, 0, 0).iter().all(|s| s.is_some()));
+}
+
+#[test]
+fn test_neighbourhood_get_neighbourhood_coords_moore_reflective_rad_2() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_r: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Reflective);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+
+    let expected_neighbourhood_top_left: Vec<Option<(usize, usize)>> = vec![
+        Some((1, 1)),
+        Some((1, 0)),
+        Some((1, 0)),
+        Some((1, 1)),
+        Some((1, 2)),
+        Some((0, 1)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 2)),
+        Some((0, 1)),
+        Some((0, 0)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 2)),
+        Some((1, 1)),
+        Some((1, 0)),
+        Some((1, 0)),
+        Some((1, 1)),
+        Some((1, 2)),
+        Some((2, 1)),
+        Some((2, 0)),
+        Some((2, 0)),
+        Some((2, 1)),
+        Some((2, 2)),
+    ];
+
+    let expected_neighbourhood_bottom_right: Vec<Option<(usize, usize)>> = vec![
+        Some((2, 2)),
+        Some((2, 3)),
+        Some((2, 4)),
+        Some((2, 4)),
+        Some((2, 3)),
+        Some((3, 2)),
+        Some((3, 3)),
+        Some((3, 4)),
+        Some((3, 4)),
+        Some((3, 3)),
+        Some((4, 2)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((4, 3)),
+        Some((4, 2)),
+        Some((4, 3)),
+        Some((4, 4)),
+        Some((4, 4)),
+        Some((4, 3)),
+        Some((3, 2)),
+        Some((3, 3)),
+        Some((3, 4)),
+        Some((3, 4)),
+        Some((3, 3)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 0, 0), expected_neighbourhood_top_left);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_r, 4, 4), expected_neighbourhood_bottom_right);
+    assert!(neighbourhood.get_neighbourhood_states(&board_bc_r, 0, 0).iter().all(|s| s.is_some()));
+}
+
+#[test]
+fn test_neighbourhood_from_offsets_near_corner() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
+    let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    // Knight's-move offsets, mixing negative and positive components
+    let offsets: Vec<(isize, isize)> = vec![(1, 2), (-1, 2), (2, -1), (-2, -1), (2, 1)];
+    let neighbourhood: Neighbourhood = Neighbourhood::from_offsets(offsets);
+
+    assert_eq!(neighbourhood.radius, 2);
+
+    let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
+        Some((2, 2)),
+        Some((0, 2)),
+        Some((3, 4)),
+        Some((4, 4)),
+        Some((3, 1)),
+    ];
+    let expected_neighbourhood_f: Vec<Option<(usize, usize)>> = vec![
+        Some((2, 2)),
+        Some((0, 2)),
+        None,
+        None,
+        Some((3, 1)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_p, 1, 0), expected_neighbourhood_p);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_f, 1, 0), expected_neighbourhood_f);
+    assert_eq!(neighbourhood.get_neighbourhood_states(&board_bc_f, 1, 0), vec![
+        Some(GameOfLifeState::Dead),
+        Some(GameOfLifeState::Dead),
+        Some(GameOfLifeState::Dead),
+        Some(GameOfLifeState::Dead),
+        Some(GameOfLifeState::Dead),
+    ]);
+}
+
+#[test]
+fn test_neighbourhood_from_offsets_cross_without_centre() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0],
+        vec![1, 0, 1],
+        vec![0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let neighbourhood: Neighbourhood = Neighbourhood::from_offsets(vec![(0, -1), (1, 0), (0, 1), (-1, 0)]);
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_f, 0, 0), vec![None, Some((1, 0)), Some((0, 1)), None]);
+    assert_eq!(neighbourhood.get_neighbourhood_states(&board_bc_f, 1, 1), vec![Some(GameOfLifeState::Alive); 4]);
+}
+
+#[test]
+fn test_neighbourhood_get_neighbourhood_coords_circular_rad_2() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = [
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+        vec![1, 0, 1, 0, 1],
+        vec![0, 1, 0, 1, 0],
+    ].iter().map(|x| x.iter().map(|&y| match y {
+        0 => GameOfLifeState::Dead,
+        1 => GameOfLifeState::Alive,
+        _ => panic!("Invalid state"),
+    }).collect()).collect();
+
+    let board_bc_p: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Periodic);
+    let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state.clone(), BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Circular, 2);
+
+    // Every (dx, dy) with dx * dx + dy * dy <= 4, ordered by dx and then dy
+    let expected_neighbourhood_p: Vec<Option<(usize, usize)>> = vec![
+        Some((3, 0)),
+        Some((4, 4)),
+        Some((4, 0)),
+        Some((4, 1)),
+        Some((0, 3)),
+        Some((0, 4)),
+        Some((0, 0)),
+        Some((0, 1)),
+        Some((0, 2)),
+        Some((1, 4)),
+        Some((1, 0)),
+        Some((1, 1)),
+        Some((2, 0)),
+    ];
+
+    let expected_neighbourhood_f: Vec<Option<(usize, usize)>> = vec![
+        Some((0, 2)),
+        Some((1, 1)),
+        Some((1, 2)),
+        Some((1, 3)),
+        Some((2, 0)),
+        Some((2, 1)),
+        Some((2, 2)),
+        Some((2, 3)),
+        Some((2, 4)),
+        Some((3, 1)),
+        Some((3, 2)),
+        Some((3, 3)),
+        Some((4, 2)),
+    ];
+
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_p, 0, 0), expected_neighbourhood_p);
+    assert_eq!(neighbourhood.get_neighbourhood_coords(&board_bc_f, 2, 2), expected_neighbourhood_f);
+}
+
+#[test]
+fn test_neighbourhood_circular_rad_3_between_von_neumann_and_moore() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = vec![vec![GameOfLifeState::Dead; 9]; 9];
+    let board_bc_f: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    let neighbourhood_vn: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 3);
+    let neighbourhood_c: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Circular, 3);
+    let neighbourhood_m: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 3);
+
+    let circle: Vec<Option<(usize, usize)>> = neighbourhood_c.get_neighbourhood_coords(&board_bc_f, 4, 4);
+
+    assert_eq!(neighbourhood_vn.get_neighbourhood_coords(&board_bc_f, 4, 4).len(), 25);
+    assert_eq!(circle.len(), 29);
+    assert_eq!(neighbourhood_m.get_neighbourhood_coords(&board_bc_f, 4, 4).len(), 49);
+    assert!(circle.contains(&Some((6, 6))));
+    assert!(!circle.contains(&Some((7, 6))));
+}
+
+#[test]
+fn test_neighbourhood_square_providers_match_types() {
+    let boundaries: [BoundaryCondition<GameOfLifeState>; 3] = [
+        BoundaryCondition::Periodic,
+        BoundaryCondition::Fixed(GameOfLifeState::Dead),
+        BoundaryCondition::Reflective,
+    ];
+    for boundary in boundaries {
+        let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 5]; 6], boundary);
+        for radius in 1..=2 {
+            let mut pairs: [(Neighbourhood, Neighbourhood); 3] = [
+                (Neighbourhood::new(NeighbourhoodType::VonNeumann, radius), Neighbourhood::from_provider(VonNeumannProvider { radius })),
+                (Neighbourhood::new(NeighbourhoodType::Moore, radius), Neighbourhood::from_provider(MooreProvider { radius })),
+                (Neighbourhood::new(NeighbourhoodType::Circular, radius), Neighbourhood::from_provider(CircularProvider { radius })),
+            ];
+            for (by_type, by_provider) in pairs.iter_mut() {
+                assert_eq!(by_provider.radius, radius);
+                for y in 0..6 {
+                    for x in 0..5 {
+                        assert_eq!(
+                            by_type.get_neighbourhood_coords(&board, x, y),
+                            by_provider.get_neighbourhood_coords(&board, x, y)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // The refactored providers keep the existing coordinate order.
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 5]; 5], BoundaryCondition::Periodic);
+    let von_neumann: Neighbourhood = Neighbourhood::from_provider(VonNeumannProvider { radius: 1 });
+    assert_eq!(
+        von_neumann.get_neighbourhood_coords(&board, 2, 2),
+        vec![Some((1, 2)), Some((2, 1)), Some((2, 2)), Some((2, 3)), Some((3, 2))]
+    );
+    let moore: Neighbourhood = Neighbourhood::from_provider(MooreProvider { radius: 1 });
+    assert_eq!(
+        moore.get_neighbourhood_coords(&board, 0, 0),
+        vec![
+            Some((4, 4)), Some((4, 0)), Some((4, 1)),
+            Some((0, 4)), Some((0, 0)), Some((0, 1)),
+            Some((1, 4)), Some((1, 0)), Some((1, 1)),
+        ]
+    );
+}
+
+#[test]
+fn test_neighbourhood_hexagonal_provider() {
+    assert_eq!(HexagonalProvider.radius(), 1);
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 4]; 4], BoundaryCondition::Periodic);
+    let hexagonal: Neighbourhood = Neighbourhood::from_provider(HexagonalProvider);
+
+    // Even rows lean left, odd rows lean right.
+    assert_eq!(
+        hexagonal.get_neighbourhood_coords(&board, 1, 2),
+        vec![Some((0, 1)), Some((1, 1)), Some((2, 2)), Some((1, 3)), Some((0, 3)), Some((0, 2))]
+    );
+    assert_eq!(
+        hexagonal.get_neighbourhood_coords(&board, 1, 1),
+        vec![Some((1, 0)), Some((2, 0)), Some((2, 1)), Some((2, 2)), Some((1, 2)), Some((0, 1))]
+    );
+
+    // Periodic boundaries wrap on a board with an even height.
+    assert_eq!(
+        hexagonal.get_neighbourhood_coords(&board, 0, 0),
+        vec![Some((3, 3)), Some((0, 3)), Some((1, 0)), Some((0, 1)), Some((3, 1)), Some((3, 0))]
+    );
+}
+
+#[test]
+fn test_neighbourhood_cache_stats() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 5]; 5], BoundaryCondition::Periodic);
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    assert_eq!(neighbourhood.cache_stats(), (0, 0));
+
+    // The first query of a cell misses and repeated queries hit.
+    neighbourhood.get_neighbourhood_coords(&board, 1, 1);
+    neighbourhood.get_neighbourhood_coords(&board, 1, 1);
+    neighbourhood.get_neighbourhood_states(&board, 1, 1);
+    neighbourhood.get_neighbourhood_coords(&board, 2, 1);
+    assert_eq!(neighbourhood.cache_stats(), (2, 2));
+
+    // A board with different dimensions clears the cache and resets the stats.
+    let larger: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 6]; 5], BoundaryCondition::Periodic);
+    neighbourhood.get_neighbourhood_coords(&larger, 1, 1);
+    assert_eq!(neighbourhood.cache_stats(), (0, 1));
+
+    // Fixed boundaries with different states have the same coordinates, so they share the cache.
+    let dead_edges: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 5]; 5], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let alive_edges: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 5]; 5], BoundaryCondition::Fixed(GameOfLifeState::Alive));
+    neighbourhood.get_neighbourhood_coords(&dead_edges, 0, 0);
+    neighbourhood.get_neighbourhood_coords(&alive_edges, 0, 0);
+    assert_eq!(neighbourhood.cache_stats(), (1, 1));
+}
+
+#[test]
+fn test_neighbourhood_periodic_radius_larger_than_board() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Periodic);
+
+    // Unchecked, a radius of 3 wraps around the 3x3 board and repeats cells.
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 3);
+    let coords: Vec<Option<(usize, usize)>> = neighbourhood.get_neighbourhood_coords(&board, 1, 1);
+    assert_eq!(coords.len(), 49);
+    assert_eq!(coords.iter().filter(|&&coord| coord == Some((1, 1))).count(), 9);
+
+    // The checked constructor rejects it.
+    assert!(Neighbourhood::new_checked(NeighbourhoodType::Moore, 3, &board).is_err());
+    assert!(Neighbourhood::new_checked(NeighbourhoodType::VonNeumann, 2, &board).is_err());
+
+    // Neighbourhoods that fit, and any neighbourhood under fixed boundaries, are accepted.
+    let fitting: Neighbourhood = Neighbourhood::new_checked(NeighbourhoodType::Moore, 1, &board).unwrap();
+    assert_eq!(fitting.radius, 1);
+    assert_eq!(fitting.get_neighbourhood_coords(&board, 1, 1).len(), 9);
+    let fixed: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 3], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    assert!(Neighbourhood::new_checked(NeighbourhoodType::Moore, 3, &fixed).is_ok());
+}
+
+#[test]
+fn test_neighbourhood_shared_between_threads() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 6]; 5], BoundaryCondition::Periodic);
+    let shared: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+    let single: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 2);
+
+    let coords: Vec<(usize, usize)> = board.iter_coords().collect();
+    let expected: Vec<Vec<Option<(usize, usize)>>> =
+        coords.iter().map(|&(x, y)| single.get_neighbourhood_coords(&board, x, y)).collect();
+
+    // Two threads query every cell of the same neighbourhood concurrently.
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| scope.spawn(|| coords.iter().map(|&(x, y)| shared.get_neighbourhood_coords(&board, x, y)).collect::<Vec<_>>()))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    });
+
+    let (hits, misses) = shared.cache_stats();
+    assert_eq!(hits + misses, 2 * coords.len());
+    assert!(misses >= coords.len());
+}
+
+#[test]
+fn test_neighbourhood_weighted_states_follow_offsets() {
+    use crate::components::state::common_states::TotalisticState;
+
+    // Each cell holds 10 * y + x, so a state identifies the cell it came from.
+    let board: Board<TotalisticState> = Board::from_fn(3, 3, BoundaryCondition::Fixed(TotalisticState(99)), |x, y| TotalisticState((10 * y + x) as u8));
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let offsets: Vec<(isize, isize)> = neighbourhood.offsets(1, 1);
+    assert_eq!(offsets, vec![(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 0), (0, 1), (1, -1), (1, 0), (1, 1)]);
+
+    let weights: Vec<f64> = (0..9).map(|i| i as f64 / 10.0).collect();
+    let weighted = neighbourhood.get_weighted_states(&board, 1, 1, &weights).unwrap();
+    for (i, ((state, weight), (dx, dy))) in weighted.into_iter().zip(offsets).enumerate() {
+        let (nx, ny) = ((1 + dx) as u8, (1 + dy) as u8);
+        assert_eq!(state, Some(TotalisticState(10 * ny + nx)));
+        assert_eq!(weight, weights[i]);
+    }
+
+    // A kernel that only weights the cell to the right picks out its value.
+    let mut right: Vec<f64> = vec![0.0; 9];
+    right[7] = 2.0;
+    assert_eq!(neighbourhood.get_weighted_sum(&board, 1, 1, &right, |state| state.0 as f64).unwrap(), 2.0 * 12.0);
+    // Cells past a fixed boundary take the fixed state.
+    assert_eq!(neighbourhood.get_weighted_sum(&board, 2, 1, &right, |state| state.0 as f64).unwrap(), 2.0 * 99.0);
+
+    assert!(neighbourhood.get_weighted_states(&board, 1, 1, &[1.0; 8]).is_err());
+    assert!(neighbourhood.get_weighted_sum(&board, 1, 1, &[1.0; 10], |state| state.0 as f64).is_err());
+}
+
+#[test]
+fn test_neighbourhood_ring_states_periodic() {
+    use crate::components::state::common_states::TotalisticState;
+    use std::collections::HashSet;
+
+    // Each cell holds 10 * y + x, so a state identifies the cell it came from.
+    let board: Board<TotalisticState> = Board::from_fn(7, 7, BoundaryCondition::Periodic, |x, y| TotalisticState((10 * y + x) as u8));
+    let cell = |x: usize, y: usize| TotalisticState((10 * y + x) as u8);
+
+    // The Moore annulus between radii 1 and 2 is the 5x5 square without its centre, wrapped around the board
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let ring: Vec<Option<TotalisticState>> = neighbourhood.get_ring_states(&board, 0, 0, 1, 2);
+    assert_eq!(ring.len(), 24);
+    let members: HashSet<TotalisticState> = ring.into_iter().map(Option::unwrap).collect();
+    let expected: HashSet<TotalisticState> = [5, 6, 0, 1, 2]
+        .iter()
+        .flat_map(|&x| [5, 6, 0, 1, 2].iter().map(move |&y| (x, y)))
+        .filter(|&(x, y)| (x, y) != (0, 0))
+        .map(|(x, y)| cell(x, y))
+        .collect();
+    assert_eq!(members, expected);
+
+    // Only the outer ring
+    let outer: HashSet<TotalisticState> = neighbourhood.get_ring_states(&board, 3, 3, 2, 2).into_iter().map(Option::unwrap).collect();
+    assert_eq!(outer.len(), 16);
+    assert!(outer.contains(&cell(1, 1)) && outer.contains(&cell(5, 3)));
+    assert!(!outer.contains(&cell(2, 2)) && !outer.contains(&cell(3, 3)));
+
+    // Rings follow the metric of the neighbourhood type
+    let von_neumann: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    assert_eq!(von_neumann.get_ring_states(&board, 3, 3, 1, 2).len(), 12);
+    let circular: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Circular, 1);
+    assert_eq!(circular.ring_offsets(3, 3, 2, 2), vec![(-2, 0), (0, -2), (0, 2), (2, 0)]);
+    assert_eq!(neighbourhood.get_ring_states(&board, 3, 3, 0, 0), vec![Some(cell(3, 3))]);
+}
+
+#[test]
+fn test_neighbourhood_count_matching_block() {
+    // A 2x2 block in the middle of a 4x4 board
+    let board: Board<GameOfLifeState> = Board::from_fn(4, 4, BoundaryCondition::Fixed(GameOfLifeState::Dead), |x, y| {
+        if (1..3).contains(&x) && (1..3).contains(&y) { GameOfLifeState::Alive } else { GameOfLifeState::Dead }
+    });
+    let neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    let alive = |state: &GameOfLifeState| *state == GameOfLifeState::Alive;
+
+    // Each cell of the block has three live neighbours, not counting itself
+    for (x, y) in [(1, 1), (2, 1), (1, 2), (2, 2)] {
+        assert_eq!(neighbourhood.count_matching(&board, x, y, alive), 3);
+    }
+    assert_eq!(neighbourhood.count_matching(&board, 0, 0, alive), 1);
+    assert_eq!(neighbourhood.count_matching(&board, 0, 1, alive), 2);
+
+    // Cells past a fixed boundary take the fixed state, so they can be counted
+    let dead = |state: &GameOfLifeState| *state == GameOfLifeState::Dead;
+    assert_eq!(neighbourhood.count_matching(&board, 0, 0, dead), 7);
+}
+
+#[test]
+fn test_neighbourhood_exclude_centre() {
+    let board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 7]; 7], BoundaryCondition::Periodic);
+
+    for (neighbourhood_type, radius, expected_len) in [
+        (NeighbourhoodType::VonNeumann, 1, 4),
+        (NeighbourhoodType::Moore, 1, 8),
+        (NeighbourhoodType::VonNeumann, 2, 12),
+        (NeighbourhoodType::Moore, 2, 24),
+    ] {
+        let neighbourhood: Neighbourhood = Neighbourhood::new_excluding_centre(neighbourhood_type, radius);
+        let coords: Vec<Option<(usize, usize)>> = neighbourhood.get_neighbourhood_coords(&board, 3, 3);
+        assert_eq!(coords.len(), expected_len);
+        assert!(!coords.contains(&Some((3, 3))));
+        assert!(!neighbourhood.offsets(3, 3).contains(&(0, 0)));
+    }
+
+    // Toggling the flag on an existing neighbourhood invalidates its cached neighbourhoods
+    let mut neighbourhood: Neighbourhood = Neighbourhood::new(NeighbourhoodType::Moore, 1);
+    assert!(neighbourhood.get_neighbourhood_coords(&board, 3, 3).contains(&Some((3, 3))));
+    neighbourhood.exclude_centre = true;
+    assert!(!neighbourhood.get_neighbourhood_coords(&board, 3, 3).contains(&Some((3, 3))));
+    assert_eq!(neighbourhood.cache_stats(), (0, 1));
+}
+
+#[test]
+fn test_neighbourhood_average_continuous_states() {
+    let board: Board<f64> = Board::from_fn(3, 3, BoundaryCondition::Fixed(1.0), |x, y| (3 * y + x) as f64);
+
+    // The centre of the board averages its four neighbours, or itself and its neighbours
+    let neighbours: Neighbourhood = Neighbourhood::new_excluding_centre(NeighbourhoodType::VonNeumann, 1);
+    assert_eq!(neighbours.get_neighbourhood_average(&board, 1, 1), Some((1.0 + 3.0 + 5.0 + 7.0) / 4.0));
+    let with_centre: Neighbourhood = Neighbourhood::new(NeighbourhoodType::VonNeumann, 1);
+    assert_eq!(with_centre.get_neighbourhood_average(&board, 1, 1), Some(4.0));
+
+    // Cells past the fixed boundary count as the fixed state
+    assert_eq!(neighbours.get_neighbourhood_average(&board, 0, 0), Some((1.0 + 1.0 + 1.0 + 3.0) / 4.0));
+
+    // An empty neighbourhood has no average
+    let empty: Neighbourhood = Neighbourhood::from_offsets(vec![]);
+    assert_eq!(empty.get_neighbourhood_average(&board, 1, 1), None);
+}
@@ -1,9 +1,14 @@
+use std::collections::{HashMap, HashSet};
+use crate::automaton::Automaton;
 use crate::components::{
-    board::{Board, BoundaryCondition},
+    board::{Board, BoundaryCondition, Rect},
+    error::OutOfBoundsSetError,
+    neighbourhood::NeighbourhoodType,
     rule::{Rule, Delta},
-    rule::common_rules::{GameOfLifeRule, LangtonsAntRule},
-    state::common_states::{AntDirection, CellColour, GameOfLifeState, LangtonsAntState},
+    rule::common_rules::{CompositeRule, CyclicRule, ElementaryRule, GameOfLifeRule, GenerationsRule, LangtonsAntRule, LifeLikeRule, RegionalRule, TotalisticRule, Turn, TurmiteRule},
+    state::common_states::{AntDirection, CellColour, CyclicState, GameOfLifeState, GenerationsState, LangtonsAntState, TotalisticState, TurmiteState},
 };
+use super::life_board;
 
 #[test]
 fn test_rule_game_of_life_underpopulation() {
@@ -194,4 +199,468 @@ fn test_rule_langtons_ant_turn_left() {
     });
 
     assert_eq!(result, vec![expected_delta_1, expected_delta_2]);
-}
\ No newline at end of file
+}
+
+/// Build a Game of Life board from rows of text, where `#` marks a live cell.
+fn pattern_board(pattern: &[&str], boundary_condition: BoundaryCondition<GameOfLifeState>) -> Board<GameOfLifeState> {
+    let cells: Vec<(usize, usize)> = pattern
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.char_indices().filter(|&(_, c)| c == '#').map(move |(x, _)| (x, y)))
+        .collect();
+    life_board(pattern[0].len(), pattern.len(), boundary_condition, &cells)
+}
+
+#[test]
+fn test_rule_life_like_from_bs() {
+    let rule: LifeLikeRule = LifeLikeRule::from_bs("B36/S23").unwrap();
+
+    assert_eq!(rule.birth(), &HashSet::from([3, 6]));
+    assert_eq!(rule.survival(), &HashSet::from([2, 3]));
+    assert_eq!(LifeLikeRule::from_bs("s23/b3").unwrap(), LifeLikeRule::from_bs("B3/S23").unwrap());
+    assert!(LifeLikeRule::from_bs("B2/S").unwrap().survival().is_empty());
+}
+
+#[test]
+fn test_rule_life_like_from_bs_malformed() {
+    assert!(LifeLikeRule::from_bs("B9/Sx").is_err());
+    assert!(LifeLikeRule::from_bs("B3S23").is_err());
+    assert!(LifeLikeRule::from_bs("B3/B23").is_err());
+    assert!(LifeLikeRule::from_bs("X3/S23").is_err());
+    assert!(LifeLikeRule::from_bs("B3/S2/S3").is_err());
+}
+
+#[test]
+fn test_rule_life_like_highlife_two_steps() {
+    let mut board: Board<GameOfLifeState> = pattern_board(&[
+        "......",
+        ".###..",
+        ".#....",
+        ".##...",
+        "......",
+        "......",
+    ], BoundaryCondition::Periodic);
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(LifeLikeRule::from_bs("B36/S23").unwrap())];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &pattern_board(&[
+        "..#...",
+        ".##...",
+        "#.##..",
+        ".##...",
+        "......",
+        "......",
+    ], BoundaryCondition::Periodic));
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &pattern_board(&[
+        ".##...",
+        "......",
+        "##.#..",
+        ".###..",
+        "......",
+        "......",
+    ], BoundaryCondition::Periodic));
+}
+
+#[test]
+fn test_rule_life_like_seeds_two_steps() {
+    let boundary_condition: BoundaryCondition<GameOfLifeState> = BoundaryCondition::Fixed(GameOfLifeState::Dead);
+    let mut board: Board<GameOfLifeState> = pattern_board(&[
+        ".....",
+        ".....",
+        ".##..",
+        ".....",
+        ".....",
+    ], boundary_condition.clone());
+    let rules: Vec<Box<dyn Rule<GameOfLifeState>>> = vec![Box::new(LifeLikeRule::from_bs("B2/S").unwrap())];
+    let mut automaton: Automaton<'_, GameOfLifeState> = Automaton::new(&mut board, rules);
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &pattern_board(&[
+        ".....",
+        ".##..",
+        ".....",
+        ".##..",
+        ".....",
+    ], boundary_condition.clone()));
+
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &pattern_board(&[
+        ".##..",
+        ".....",
+        "#..#.",
+        ".....",
+        ".##..",
+    ], boundary_condition));
+}
+
+#[test]
+fn test_rule_elementary_rule_30() {
+    let mut initial_row: Vec<GameOfLifeState> = vec![GameOfLifeState::Dead; 11];
+    initial_row[5] = GameOfLifeState::Alive;
+    let mut board: Board<GameOfLifeState> = Board::new(vec![initial_row], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    let rows: Vec<Vec<GameOfLifeState>> = ElementaryRule::new(30).evolve_rows(&mut board, 4).unwrap();
+
+    let expected: Vec<Vec<GameOfLifeState>> = [
+        ".....#.....",
+        "....###....",
+        "...##..#...",
+        "..##.####..",
+        ".##..#...#.",
+    ].iter().map(|row| row.chars().map(|c| match c {
+        '#' => GameOfLifeState::Alive,
+        _ => GameOfLifeState::Dead,
+    }).collect()).collect();
+
+    assert_eq!(rows, expected);
+    assert_eq!(board.get(9, 0), Some(GameOfLifeState::Alive));
+}
+
+#[test]
+fn test_rule_elementary_periodic_wrap() {
+    let mut initial_row: Vec<GameOfLifeState> = vec![GameOfLifeState::Dead; 5];
+    initial_row[0] = GameOfLifeState::Alive;
+    let board: Board<GameOfLifeState> = Board::new(vec![initial_row], BoundaryCondition::Periodic);
+
+    // Rule 2 moves every live cell one cell to the left
+    let rule: ElementaryRule = ElementaryRule::new(2);
+
+    assert_eq!(rule.delta((4, 0), &board).unwrap(), vec![Delta::new(4, 0, GameOfLifeState::Alive)]);
+    assert_eq!(rule.delta((0, 0), &board).unwrap(), vec![Delta::new(0, 0, GameOfLifeState::Dead)]);
+}
+
+#[test]
+fn test_rule_regional_halves() {
+    let pattern: [&str; 5] = [
+        "..........",
+        "..#.......",
+        "..#....##.",
+        "..#.......",
+        "..........",
+    ];
+    let mut board: Board<GameOfLifeState> = pattern_board(&pattern, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    // Conway's Game of Life on the left half and Seeds on the right half
+    let regional_rule: RegionalRule<GameOfLifeState> = RegionalRule::new(vec![
+        (Rect::new(0, 0, 5, 5), Box::new(GameOfLifeRule)),
+        (Rect::new(5, 0, 5, 5), Box::new(LifeLikeRule::from_bs("B2/S").unwrap())),
+    ], None);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(regional_rule)]);
+    automaton.evolve(1).unwrap();
+
+    let expected: Board<GameOfLifeState> = pattern_board(&[
+        "..........",
+        ".......##.",
+        ".###......",
+        ".......##.",
+        "..........",
+    ], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    assert_eq!(*automaton.board(), expected);
+}
+
+#[test]
+fn test_rule_regional_cells_outside_regions_unchanged() {
+    let pattern: [&str; 5] = [
+        "..........",
+        "..#.......",
+        "..#....##.",
+        "..#.......",
+        "..........",
+    ];
+    let mut board: Board<GameOfLifeState> = pattern_board(&pattern, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    let regional_rule: RegionalRule<GameOfLifeState> = RegionalRule::new(vec![
+        (Rect::new(0, 0, 5, 5), Box::new(GameOfLifeRule)),
+    ], None);
+    let mut automaton: Automaton<GameOfLifeState> = Automaton::new(&mut board, vec![Box::new(regional_rule)]);
+    automaton.evolve(1).unwrap();
+
+    let expected: Board<GameOfLifeState> = pattern_board(&[
+        "..........",
+        "..........",
+        ".###...##.",
+        "..........",
+        "..........",
+    ], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    assert_eq!(*automaton.board(), expected);
+}
+
+/// Build a board of Generations states from rows of digits.
+fn generations_board(rows: &[&str]) -> Board<GenerationsState> {
+    let initial_state: Vec<Vec<GenerationsState>> = rows
+        .iter()
+        .map(|row| row.chars().map(|c| GenerationsState(c.to_digit(10).unwrap() as u8)).collect())
+        .collect();
+    Board::new(initial_state, BoundaryCondition::Fixed(GenerationsState::DEAD))
+}
+
+#[test]
+fn test_rule_generations_brians_brain() {
+    let mut board: Board<GenerationsState> = generations_board(&["000000", "000000", "001100", "000000", "000000", "000000"]);
+    let mut automaton: Automaton<GenerationsState> = Automaton::new(&mut board, vec![Box::new(GenerationsRule::brians_brain())]);
+
+    // Live cells start dying, and dead cells with exactly two live neighbours are born.
+    automaton.evolve(1).unwrap();
+    assert_eq!(
+        *automaton.board(),
+        generations_board(&["000000", "001100", "002200", "001100", "000000", "000000"])
+    );
+
+    // Dying cells die without being born again, and the new cells start dying.
+    automaton.evolve(1).unwrap();
+    assert_eq!(
+        *automaton.board(),
+        generations_board(&["001100", "002200", "010010", "002200", "001100", "000000"])
+    );
+}
+
+#[test]
+fn test_rule_generations_refractory_band() {
+    // With 4 states, a cell that fails to survive ages through states 2 and 3 before it dies.
+    let rule: GenerationsRule = GenerationsRule::new(HashSet::new(), HashSet::new(), 4);
+    assert_eq!(rule.states(), 4);
+    let mut board: Board<GenerationsState> = generations_board(&["000", "010", "000"]);
+    let mut automaton: Automaton<GenerationsState> = Automaton::new(&mut board, vec![Box::new(rule)]);
+
+    let mut ages: Vec<GenerationsState> = Vec::new();
+    for _ in 0..3 {
+        automaton.evolve(1).unwrap();
+        ages.push(automaton.board().get(1, 1).unwrap());
+    }
+    assert_eq!(ages, vec![GenerationsState(2), GenerationsState(3), GenerationsState::DEAD]);
+}
+
+#[test]
+fn test_rule_generations_oldest_state_dies() {
+    // GenerationsState(255) is a valid state, and ageing it must not overflow.
+    let rule: GenerationsRule = GenerationsRule::new(HashSet::new(), HashSet::new(), 255);
+    let mut board: Board<GenerationsState> = Board::new(vec![vec![GenerationsState(254), GenerationsState(255)]], BoundaryCondition::Fixed(GenerationsState::DEAD));
+    let mut automaton: Automaton<GenerationsState> = Automaton::new(&mut board, vec![Box::new(rule)]);
+
+    automaton.evolve(1).unwrap();
+
+    assert_eq!(automaton.board().get(0, 0), Some(GenerationsState::DEAD));
+    assert_eq!(automaton.board().get(1, 0), Some(GenerationsState::DEAD));
+}
+
+#[test]
+fn test_rule_composite_concatenates_deltas() {
+    let initial_state: Vec<Vec<GameOfLifeState>> = vec![
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive, GameOfLifeState::Dead],
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive, GameOfLifeState::Dead],
+        vec![GameOfLifeState::Dead, GameOfLifeState::Alive, GameOfLifeState::Dead],
+    ];
+    let board: Board<GameOfLifeState> = Board::new(initial_state, BoundaryCondition::Fixed(GameOfLifeState::Dead));
+    let composite: CompositeRule<GameOfLifeState> = CompositeRule::new(vec![Box::new(GameOfLifeRule), Box::new(GameOfLifeRule)]);
+    assert_eq!(composite.rules().len(), 2);
+
+    // Each cell gets the deltas of both rules, in order.
+    for (x, y) in board.iter_coords() {
+        let single: Vec<Delta<GameOfLifeState>> = GameOfLifeRule.delta((x, y), &board).unwrap();
+        let doubled: Vec<Delta<GameOfLifeState>> = single.iter().chain(single.iter()).copied().collect();
+        assert_eq!(composite.delta((x, y), &board).unwrap(), doubled);
+    }
+
+    // The composite evolves the blinker like a single Game of Life rule.
+    let mut composite_board: Board<GameOfLifeState> = board.clone();
+    let mut composite_automaton: Automaton<GameOfLifeState> = Automaton::new(&mut composite_board, vec![Box::new(composite)]);
+    composite_automaton.evolve(1).unwrap();
+    let mut single_board: Board<GameOfLifeState> = board.clone();
+    let mut single_automaton: Automaton<GameOfLifeState> = Automaton::new(&mut single_board, vec![Box::new(GameOfLifeRule)]);
+    single_automaton.evolve(1).unwrap();
+    assert_eq!(composite_automaton.board(), single_automaton.board());
+}
+
+#[test]
+fn test_rule_totalistic_reproduces_game_of_life() {
+    // Conway's Game of Life is the outer-totalistic rule B3/S23 over 2 states and the Moore neighbourhood.
+    let life: TotalisticRule = TotalisticRule::from_fn(2, NeighbourhoodType::Moore, 1, |state, sum| match (state, sum) {
+        (0, 3) | (1, 2) | (1, 3) => 1,
+        _ => 0,
+    });
+    assert_eq!(life.k(), 2);
+    assert_eq!(life.next_state(1, 4), 0);
+
+    let glider: [(usize, usize); 5] = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    let mut expected: Board<GameOfLifeState> = life_board(6, 6, BoundaryCondition::Periodic, &glider);
+    let mut board: Board<TotalisticState> = Board::new(vec![vec![TotalisticState(0); 6]; 6], BoundaryCondition::Periodic);
+    for &(x, y) in &glider {
+        board.set(x, y, TotalisticState(1)).unwrap();
+    }
+
+    let mut automaton: Automaton<TotalisticState> = Automaton::new(&mut board, vec![Box::new(life)]);
+    let mut life_automaton: Automaton<GameOfLifeState> = Automaton::new(&mut expected, vec![Box::new(GameOfLifeRule)]);
+    for _ in 0..4 {
+        automaton.evolve(1).unwrap();
+        life_automaton.evolve(1).unwrap();
+        for (x, y) in automaton.board().iter_coords() {
+            let alive: bool = life_automaton.board().get(x, y) == Some(GameOfLifeState::Alive);
+            assert_eq!(automaton.board().get(x, y), Some(TotalisticState(alive as u8)));
+        }
+    }
+}
+
+#[test]
+fn test_rule_totalistic_sums_state_values() {
+    // With 3 states, the next state is the neighbour sum modulo 3, excluding the cell itself.
+    let table: HashMap<(u8, u32), u8> = (0..3).flat_map(|state| (0..=8).map(move |sum| ((state, sum), (sum % 3) as u8))).collect();
+    let rule: TotalisticRule = TotalisticRule::new(3, NeighbourhoodType::VonNeumann, 1, table);
+
+    let board: Board<TotalisticState> = Board::new(
+        vec![
+            vec![TotalisticState(0), TotalisticState(2), TotalisticState(0)],
+            vec![TotalisticState(1), TotalisticState(2), TotalisticState(2)],
+            vec![TotalisticState(0), TotalisticState(0), TotalisticState(0)],
+        ],
+        BoundaryCondition::Fixed(TotalisticState(1)),
+    );
+
+    // The centre cell sums 2 + 1 + 2 + 0 = 5, and the top-left corner sums 2 + 1 plus two fixed cells of 1 = 5.
+    assert_eq!(rule.delta((1, 1), &board).unwrap(), vec![Delta::new(1, 1, TotalisticState(2))]);
+    assert_eq!(rule.delta((0, 0), &board).unwrap(), vec![Delta::new(0, 0, TotalisticState(2))]);
+    // The bottom-right corner sums 2 + 0 plus two fixed cells of 1 = 4.
+    assert_eq!(rule.delta((2, 2), &board).unwrap(), vec![Delta::new(2, 2, TotalisticState(1))]);
+}
+
+#[test]
+#[should_panic]
+fn test_rule_totalistic_rejects_out_of_range_state() {
+    TotalisticRule::new(2, NeighbourhoodType::Moore, 1, HashMap::from([((0, 3), 2)]));
+}
+
+#[test]
+fn test_rule_turmite_three_colours() {
+    let mut board: Board<TurmiteState> = Board::new(vec![vec![TurmiteState::cell(0); 5]; 5], BoundaryCondition::Periodic);
+    board.set(2, 2, TurmiteState::ant(0, AntDirection::Up, 0)).unwrap();
+    let rule: TurmiteRule = TurmiteRule::from_turns("RLR").unwrap();
+    assert_eq!(rule.table().get(&(2, 0)), Some(&(0, Turn::Right, 0)));
+
+    let mut automaton: Automaton<TurmiteState> = Automaton::new(&mut board, vec![Box::new(rule)]);
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board().get(2, 2), Some(TurmiteState::cell(1)));
+    assert_eq!(automaton.board().get(3, 2), Some(TurmiteState::ant(0, AntDirection::Right, 0)));
+
+    // The ant circles clockwise back to its start, then turns left on the cell it has already recoloured
+    automaton.evolve(5).unwrap();
+    let board: &Board<TurmiteState> = automaton.board();
+    assert_eq!(board.get(2, 2), Some(TurmiteState::cell(2)));
+    for (x, y) in [(3, 2), (3, 3), (2, 3), (1, 2)] {
+        assert_eq!(board.get(x, y), Some(TurmiteState::cell(1)));
+    }
+    assert_eq!(board.get(1, 1), Some(TurmiteState::ant(0, AntDirection::Up, 0)));
+    assert_eq!(board.count_where(|state| state.ant_direction.is_some()), 1);
+}
+
+#[test]
+fn test_rule_turmite_table_and_parsing() {
+    // A two-state turmite that U-turns and switches state on colour 0
+    let table: HashMap<(u8, u8), (u8, Turn, u8)> = HashMap::from([((0, 0), (1, Turn::UTurn, 1))]);
+    let mut board: Board<TurmiteState> = Board::new(vec![vec![TurmiteState::cell(0); 3]; 3], BoundaryCondition::Periodic);
+    board.set(1, 0, TurmiteState::ant(0, AntDirection::Down, 0)).unwrap();
+    let mut automaton: Automaton<TurmiteState> = Automaton::new(&mut board, vec![Box::new(TurmiteRule::new(table))]);
+
+    // The ant wraps off the top of the board, then halts as it has no transition for state 1
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board().get(1, 0), Some(TurmiteState::cell(1)));
+    assert_eq!(automaton.board().get(1, 2), Some(TurmiteState::ant(0, AntDirection::Up, 1)));
+    let halted: Board<TurmiteState> = automaton.board().clone();
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &halted);
+
+    assert_eq!(Turn::Left.apply(AntDirection::Up), AntDirection::Left);
+    assert_eq!(Turn::NoTurn.apply(AntDirection::Right), AntDirection::Right);
+    assert!(TurmiteRule::from_turns("lrrrrrllr").is_ok());
+    assert!(TurmiteRule::from_turns("").is_err());
+    assert!(TurmiteRule::from_turns("RXL").is_err());
+}
+
+#[test]
+fn test_rule_cyclic_one_step() {
+    let grid = |rows: [[u8; 3]; 3]| {
+        Board::new(
+            rows.iter().map(|row| row.iter().map(|&k| CyclicState(k)).collect()).collect(),
+            BoundaryCondition::Fixed(CyclicState(0)),
+        )
+    };
+    let initial: Board<CyclicState> = grid([[0, 1, 1], [2, 0, 1], [2, 2, 0]]);
+
+    // With a threshold of 2, a cell advances only if two of its four neighbours are in the next state
+    let mut board: Board<CyclicState> = initial.clone();
+    let mut automaton: Automaton<CyclicState> = Automaton::new(&mut board, vec![Box::new(CyclicRule::new(3, 2, NeighbourhoodType::VonNeumann, 1))]);
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &grid([[0, 1, 1], [0, 1, 1], [0, 0, 0]]));
+
+    // With a threshold of 1, any neighbour in the next state is enough, including the fixed boundary state
+    let mut board: Board<CyclicState> = initial.clone();
+    let mut automaton: Automaton<CyclicState> = Automaton::new(&mut board, vec![Box::new(CyclicRule::new(3, 1, NeighbourhoodType::VonNeumann, 1))]);
+    automaton.evolve(1).unwrap();
+    assert_eq!(automaton.board(), &grid([[1, 1, 1], [0, 1, 1], [0, 0, 1]]));
+}
+
+#[test]
+fn test_rule_langtons_ant_top_edge() {
+    let white: LangtonsAntState = LangtonsAntState { colour: CellColour::White, ant_direction: None };
+    let black: LangtonsAntState = LangtonsAntState { colour: CellColour::Black, ant_direction: None };
+    let board_with_ant = |boundary_condition: BoundaryCondition<LangtonsAntState>| {
+        let mut board: Board<LangtonsAntState> = Board::new(vec![vec![white; 3]; 3], boundary_condition);
+        // The ant faces left on a white cell, so it turns right to face up, off the top edge
+        board.set(1, 0, LangtonsAntState { colour: CellColour::White, ant_direction: Some(AntDirection::Left) }).unwrap();
+        board
+    };
+
+    // Under periodic boundaries, the ant wraps around to the bottom row
+    let board: Board<LangtonsAntState> = board_with_ant(BoundaryCondition::Periodic);
+    assert_eq!(
+        LangtonsAntRule.delta((1, 0), &board).unwrap(),
+        vec![
+            Delta::new(1, 0, black),
+            Delta::new(1, 2, LangtonsAntState { colour: CellColour::White, ant_direction: Some(AntDirection::Up) }),
+        ]
+    );
+
+    // Under fixed boundaries, the ant leaves the board after flipping its cell
+    let mut board: Board<LangtonsAntState> = board_with_ant(BoundaryCondition::Fixed(white));
+    assert_eq!(LangtonsAntRule.delta((1, 0), &board).unwrap(), vec![Delta::new(1, 0, black)]);
+    let mut automaton: Automaton<LangtonsAntState> = Automaton::new(&mut board, vec![Box::new(LangtonsAntRule)]);
+    automaton.evolve(1).unwrap();
+    assert!(automaton.none_match(|state| state.ant_direction.is_some()));
+    assert_eq!(automaton.board().get(1, 0), Some(black));
+}
+
+/// A rule that moves every live cell two cells to the right, creating its deltas with `Delta::clamped`.
+#[derive(Clone)]
+struct JumpRightRule;
+
+impl Rule<GameOfLifeState> for JumpRightRule {
+    fn delta(&self, coord: (usize, usize), board: &Board<GameOfLifeState>) -> Result<Vec<Delta<GameOfLifeState>>, OutOfBoundsSetError> {
+        if board.get(coord.0, coord.1) != Some(GameOfLifeState::Alive) {
+            return Ok(vec![]);
+        }
+        let destination: Option<Delta<GameOfLifeState>> = Delta::clamped(coord.0 as isize + 2, coord.1 as isize, GameOfLifeState::Alive, board);
+        Ok(std::iter::once(Delta::new(coord.0, coord.1, GameOfLifeState::Dead)).chain(destination).collect())
+    }
+}
+
+#[test]
+fn test_delta_clamped_wraps_periodic_and_drops_fixed() {
+    let row: Vec<GameOfLifeState> = vec![GameOfLifeState::Dead, GameOfLifeState::Dead, GameOfLifeState::Alive, GameOfLifeState::Dead];
+    let mut periodic: Board<GameOfLifeState> = Board::new(vec![row.clone()], BoundaryCondition::Periodic);
+    let mut fixed: Board<GameOfLifeState> = Board::new(vec![row], BoundaryCondition::Fixed(GameOfLifeState::Dead));
+
+    assert_eq!(Delta::clamped(5, -1, GameOfLifeState::Alive, &periodic), Some(Delta::new(1, 0, GameOfLifeState::Alive)));
+    assert_eq!(Delta::clamped(4, 0, GameOfLifeState::Alive, &fixed), None);
+    assert_eq!(Delta::clamped(3, 0, GameOfLifeState::Alive, &fixed), Some(Delta::new(3, 0, GameOfLifeState::Alive)));
+
+    // The live cell at x = 2 jumps past the right edge, wrapping to x = 0 or leaving a fixed board
+    Automaton::new(&mut periodic, vec![Box::new(JumpRightRule)]).evolve(1).unwrap();
+    assert_eq!(periodic.get(0, 0), Some(GameOfLifeState::Alive));
+    assert_eq!(periodic.count_where(|&state| state == GameOfLifeState::Alive), 1);
+
+    Automaton::new(&mut fixed, vec![Box::new(JumpRightRule)]).evolve(1).unwrap();
+    assert!(fixed.none_match(|state| state == GameOfLifeState::Alive));
+}
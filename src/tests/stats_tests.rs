@@ -0,0 +1,30 @@
+use crate::components::board::{Board, BoundaryCondition, Rect};
+use crate::components::state::common_states::GameOfLifeState;
+use crate::stats::{bounding_box, centre_of_mass, column_profile, density, row_profile};
+use super::life_board;
+
+fn is_alive(state: &GameOfLifeState) -> bool {
+    *state == GameOfLifeState::Alive
+}
+
+#[test]
+fn test_stats_bounding_box_and_centre_of_mass_of_offset_glider() {
+    // A glider whose top-left corner is offset to (3, 2)
+    let board: Board<GameOfLifeState> = life_board(8, 6, BoundaryCondition::Periodic, &[(4, 2), (5, 3), (3, 4), (4, 4), (5, 4)]);
+
+    assert_eq!(bounding_box(&board, GameOfLifeState::Dead), Some(Rect::new(3, 2, 3, 3)));
+    assert_eq!(centre_of_mass(&board, is_alive), Some((4.2, 3.4)));
+    assert_eq!(density(&board, is_alive), 5.0 / 48.0);
+    assert_eq!(row_profile(&board, is_alive), vec![0, 0, 1, 1, 3, 0]);
+    assert_eq!(column_profile(&board, is_alive), vec![0, 0, 0, 1, 2, 2, 0, 0]);
+}
+
+#[test]
+fn test_stats_empty_selection() {
+    let board: Board<GameOfLifeState> = life_board(8, 6, BoundaryCondition::Periodic, &[]);
+
+    assert_eq!(bounding_box(&board, GameOfLifeState::Dead), None);
+    assert_eq!(centre_of_mass(&board, is_alive), None);
+    assert_eq!(density(&board, is_alive), 0.0);
+    assert_eq!(bounding_box(&board, GameOfLifeState::Alive), Some(Rect::new(0, 0, 8, 6)));
+}
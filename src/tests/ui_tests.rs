@@ -0,0 +1,115 @@
+use crate::components::{
+    board::{Board, BoardRepresentation, BoundaryCondition, Colour},
+    state::common_states::GameOfLifeState,
+};
+use crate::ui::{apply_trail, clamp_interval, offset_step, remap_colours, seeded_board, toggle_cell, trail_colour};
+use rand::{rngs::StdRng, Rng};
+use std::collections::HashMap;
+
+fn random_board(rng: &mut StdRng) -> Board<GameOfLifeState> {
+    let initial_state: Vec<Vec<GameOfLifeState>> = (0..8)
+        .map(|_| {
+            (0..8)
+                .map(|_| match rng.gen_bool(0.5) {
+                    true => GameOfLifeState::Alive,
+                    false => GameOfLifeState::Dead,
+                })
+                .collect()
+        })
+        .collect();
+    Board::new(initial_state, BoundaryCondition::Periodic)
+}
+
+#[test]
+fn test_ui_seeded_board_same_seed() {
+    let board_a: Board<GameOfLifeState> = seeded_board(random_board, 42);
+    let board_b: Board<GameOfLifeState> = seeded_board(random_board, 42);
+
+    assert_eq!(board_a, board_b);
+}
+
+#[test]
+fn test_ui_seeded_board_different_seed() {
+    let board_a: Board<GameOfLifeState> = seeded_board(random_board, 42);
+    let board_b: Board<GameOfLifeState> = seeded_board(random_board, 43);
+
+    assert_ne!(board_a, board_b);
+}
+
+#[test]
+fn test_ui_remap_colours() {
+    let board_state: BoardRepresentation = vec![
+        vec![Colour::black(), Colour::white()],
+        vec![Colour::white(), Colour::blue()],
+    ];
+    let overrides: HashMap<Colour, Colour> = HashMap::from([
+        (Colour::white(), Colour::red()),
+        (Colour::green(), Colour::black()),
+    ]);
+
+    let expected: BoardRepresentation = vec![
+        vec![Colour::black(), Colour::red()],
+        vec![Colour::red(), Colour::blue()],
+    ];
+
+    assert_eq!(remap_colours(&board_state, &overrides), expected);
+    assert_eq!(remap_colours(&board_state, &HashMap::new()), board_state);
+}
+
+#[test]
+fn test_ui_trail_colour() {
+    let base: Colour = Colour::white();
+    let trail: Colour = Colour::red();
+
+    // A just-changed cell uses the trail colour and an old cell uses its base colour.
+    assert_eq!(trail_colour(base, trail, 0, 4), trail);
+    assert_eq!(trail_colour(base, trail, 4, 4), base);
+    assert_eq!(trail_colour(base, trail, 10, 4), base);
+    assert_eq!(trail_colour(base, trail, 2, 4), Colour::new(255, 128, 128));
+
+    // Only the cell that changed between frames is blended towards the trail colour.
+    let states: [BoardRepresentation; 3] = [
+        vec![vec![Colour::black(), Colour::white()]],
+        vec![vec![Colour::white(), Colour::white()]],
+        vec![vec![Colour::white(), Colour::white()]],
+    ];
+    let trailed: Vec<BoardRepresentation> = apply_trail(&states, trail, 2);
+    assert_eq!(trailed[0], states[0]);
+    assert_eq!(trailed[1], vec![vec![trail, base]]);
+    assert_eq!(trailed[2], vec![vec![Colour::new(255, 128, 128), base]]);
+}
+
+#[test]
+fn test_ui_offset_step() {
+    assert_eq!(offset_step(3, 1, 10), 4);
+    assert_eq!(offset_step(3, -1, 10), 2);
+    // Stepping stays within the precomputed frames
+    assert_eq!(offset_step(0, -1, 10), 0);
+    assert_eq!(offset_step(10, 1, 10), 10);
+}
+
+#[test]
+fn test_ui_clamp_interval() {
+    assert_eq!(clamp_interval(100), 100);
+    assert_eq!(clamp_interval(0), 50);
+    assert_eq!(clamp_interval(10_000), 2000);
+}
+
+#[test]
+fn test_ui_toggle_cell() {
+    let mut board: Board<GameOfLifeState> = Board::new(vec![vec![GameOfLifeState::Dead; 3]; 2], BoundaryCondition::Periodic);
+    let toggle = |state: GameOfLifeState| match state {
+        GameOfLifeState::Alive => GameOfLifeState::Dead,
+        GameOfLifeState::Dead => GameOfLifeState::Alive,
+    };
+
+    assert!(toggle_cell(&mut board, 2, 1, toggle));
+    assert_eq!(board.get(2, 1), Some(GameOfLifeState::Alive));
+    assert_eq!(board.count_where(|&state| state == GameOfLifeState::Alive), 1);
+    assert!(toggle_cell(&mut board, 2, 1, toggle));
+    assert_eq!(board.get(2, 1), Some(GameOfLifeState::Dead));
+
+    // Clicks outside the board leave it unchanged
+    assert!(!toggle_cell(&mut board, 3, 1, toggle));
+    assert_eq!(board.count_where(|&state| state == GameOfLifeState::Alive), 0);
+}
@@ -1,11 +1,15 @@
-use crate::{automaton::Automaton, components::{board::{BoardRepresentation, Colour}, genetic::genotype::Genotype}, genetic_automaton::GeneticAutomaton};
+use crate::{automaton::Automaton, components::{board::{Board, BoardRepresentation, Colour}, genetic::genotype::Genotype, rule::Rule}, genetic_automaton::GeneticAutomaton};
 use crate::components::state::State;
 use dioxus::prelude::*;
-use tokio::time::Interval;
-use std::sync::Arc;
+use rand::{rngs::StdRng, SeedableRng};
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const MAIN_CSS: Asset = asset!("/assets/main.css");
+const MIN_INTERVAL_MS: u64 = 50;
+const MAX_INTERVAL_MS: u64 = 2000;
 
 /// A private struct that represents the render context for the simulation.
 #[derive(Debug, Clone)]
@@ -13,21 +17,48 @@ struct BoardSimulationRender {
     states: Arc<Vec<BoardRepresentation>>,
     steps: usize,
     interval: u64,
+    seed: u64,
+    regenerator: Option<BoardRegenerator>,
+    editor: Option<BoardEditor>,
+    colour_overrides: Option<HashMap<Colour, Colour>>,
 }
 
-/// The main function that runs the simulation for a cellular automaton.
+/// A private struct that wraps a function recomputing the board states of a simulation from a seed.
+#[derive(Clone)]
+struct BoardRegenerator(Arc<dyn Fn(u64) -> Vec<BoardRepresentation> + Send + Sync>);
+
+impl std::fmt::Debug for BoardRegenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoardRegenerator")
+    }
+}
+
+/// A private struct that wraps an editable starting board, hiding its state type from the application component.
 /// 
-/// This function takes an automaton and runs the simulation for the given number of steps with the given interval between each step.
-/// To ensure that the interval is consistent, board states are precomputed before rendering the simulation. This may lead to a delay before the simulation starts, depending on the number of steps.
+/// - `representation` renders the board as it is currently drawn.
+/// - `toggle` toggles the cell at the given coordinates.
+/// - `run` precomputes the board states of a simulation starting from the board as it is currently drawn.
+#[derive(Clone)]
+struct BoardEditor {
+    representation: Arc<dyn Fn() -> BoardRepresentation + Send + Sync>,
+    toggle: Arc<dyn Fn(usize, usize) + Send + Sync>,
+    run: Arc<dyn Fn() -> Vec<BoardRepresentation> + Send + Sync>,
+}
+
+impl std::fmt::Debug for BoardEditor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoardEditor")
+    }
+}
+
+/// Precompute the representations of the board at each step of the simulation, including the initial state.
 /// 
 /// Parameters:
 /// 
 /// - `automaton`: The automaton to run the simulation on.
 /// 
 /// - `steps`: The number of steps to run the simulation for.
-/// 
-/// - `interval`: The interval between each step in milliseconds.
-pub fn simulate<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: usize, interval: u64) {
+fn precompute_states<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: usize) -> Vec<BoardRepresentation> {
     // Create a vector to store all board states
     let mut state_vec: Vec<BoardRepresentation> = Vec::with_capacity(steps + 1);
     
@@ -42,20 +73,353 @@ pub fn simulate<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: us
             state_vec.push(new_state);
         }
     }
+
+    state_vec
+}
+
+/// Build a board from a seeded random number generator.
+/// 
+/// The same seed always produces the same board, so random starting states can be reproduced.
+/// 
+/// Parameters:
+/// 
+/// - `make_board`: A function that builds a board using the given random number generator.
+/// 
+/// - `seed`: The seed of the random number generator.
+pub fn seeded_board<S: State>(make_board: impl Fn(&mut StdRng) -> Board<S>, seed: u64) -> Board<S> {
+    let mut rng: StdRng = StdRng::seed_from_u64(seed);
+    make_board(&mut rng)
+}
+
+/// Toggle the state of a cell on a board being drawn.
+/// 
+/// Returns whether the cell exists, so clicks outside the board are ignored.
+/// 
+/// Parameters:
+/// 
+/// - `board`: The board being drawn.
+/// 
+/// - `x`: The x-coordinate of the cell.
+/// 
+/// - `y`: The y-coordinate of the cell.
+/// 
+/// - `toggle`: A function that returns the state a cell should take when it is clicked, given its current state.
+pub fn toggle_cell<S: State>(board: &mut Board<S>, x: usize, y: usize, toggle: impl Fn(S) -> S) -> bool {
+    match board.get(x, y) {
+        Some(state) => board.set(x, y, toggle(state)).is_ok(),
+        None => false,
+    }
+}
+
+/// Recolour a board representation by replacing colours according to a remapping.
+/// 
+/// Colours without an entry in the remapping are left unchanged. This only changes how the board is rendered, not the states of the automaton.
+/// 
+/// Parameters:
+/// 
+/// - `board_state`: The board representation to recolour.
+/// 
+/// - `overrides`: A map from each colour to replace to the colour to render in its place.
+pub fn remap_colours(board_state: &BoardRepresentation, overrides: &HashMap<Colour, Colour>) -> BoardRepresentation {
+    board_state
+        .iter()
+        .map(|row| row.iter().map(|colour| *overrides.get(colour).unwrap_or(colour)).collect())
+        .collect()
+}
+
+/// Compute the colour of a cell in trail mode, blending its base colour towards the trail colour by its age.
+/// 
+/// A cell that has just changed (age 0) is rendered in the trail colour, and the trail fades linearly back to the base colour, which is reached once the age is at least `trail_length`.
+/// 
+/// Parameters:
+/// 
+/// - `base`: The colour of the cell's state.
+/// 
+/// - `trail`: The colour of the trail.
+/// 
+/// - `age`: The number of steps since the cell last changed.
+/// 
+/// - `trail_length`: The number of steps the trail takes to fade.
+pub fn trail_colour(base: Colour, trail: Colour, age: usize, trail_length: usize) -> Colour {
+    if age >= trail_length {
+        return base;
+    }
+    trail.lerp(base, age as f64 / trail_length as f64)
+}
+
+/// Apply trail rendering to a sequence of board representations.
+/// 
+/// The age of each cell is the number of steps since its colour last changed. Cells in the initial state are treated as old, so the first frame is unchanged.
+/// 
+/// Parameters:
+/// 
+/// - `states`: The board representations at each step of the simulation.
+/// 
+/// - `trail`: The colour of the trail.
+/// 
+/// - `trail_length`: The number of steps the trail takes to fade.
+pub fn apply_trail(states: &[BoardRepresentation], trail: Colour, trail_length: usize) -> Vec<BoardRepresentation> {
+    let mut ages: Vec<Vec<usize>> = match states.first() {
+        Some(first) => first.iter().map(|row| vec![trail_length; row.len()]).collect(),
+        None => return Vec::new(),
+    };
+
+    states
+        .iter()
+        .enumerate()
+        .map(|(i, board_state)| {
+            board_state
+                .iter()
+                .enumerate()
+                .map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(x, &colour)| {
+                            let changed: bool = i > 0 && states[i - 1][y][x] != colour;
+                            ages[y][x] = if changed { 0 } else { ages[y][x].saturating_add(1) };
+                            trail_colour(colour, trail, ages[y][x], trail_length)
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Move a playback step by an offset, staying within the precomputed steps.
+/// 
+/// Parameters:
+/// 
+/// - `step`: The current step.
+/// 
+/// - `offset`: The number of steps to move by, which is negative to move backwards.
+/// 
+/// - `last_step`: The last precomputed step.
+pub fn offset_step(step: usize, offset: isize, last_step: usize) -> usize {
+    step.saturating_add_signed(offset).min(last_step)
+}
+
+/// Clamp a playback interval to the range of the speed slider.
+/// 
+/// Parameters:
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+pub fn clamp_interval(interval: u64) -> u64 {
+    interval.clamp(MIN_INTERVAL_MS, MAX_INTERVAL_MS)
+}
+
+/// Format a colour as a `#rrggbb` hex string, as used by colour inputs.
+fn to_hex(colour: Colour) -> String {
+    format!("#{:02x}{:02x}{:02x}", colour.r, colour.g, colour.b)
+}
+
+/// Parse a `#rrggbb` hex string, as produced by colour inputs, into a colour.
+fn parse_hex_colour(hex: &str) -> Option<Colour> {
+    let hex: &str = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let component = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some(Colour::new(component(0)?, component(2)?, component(4)?))
+}
+
+/// The main function that runs the simulation for a cellular automaton.
+/// 
+/// This function takes an automaton and runs the simulation for the given number of steps with the given interval between each step.
+/// To ensure that the interval is consistent, board states are precomputed before rendering the simulation. This may lead to a delay before the simulation starts, depending on the number of steps.
+/// 
+/// Parameters:
+/// 
+/// - `automaton`: The automaton to run the simulation on.
+/// 
+/// - `steps`: The number of steps to run the simulation for.
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+pub fn simulate<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: usize, interval: u64) {
+    // Precompute all states upfront and wrap in Arc for thread-safe sharing
+    let states: Arc<Vec<BoardRepresentation>> = Arc::new(precompute_states(automaton, steps));
     
-    // Wrap in Arc for thread-safe sharing
-    let states: Arc<Vec<BoardRepresentation>> = Arc::new(state_vec);
+    // Prepare the render context
+    let render: BoardSimulationRender = BoardSimulationRender {
+        states,
+        steps,
+        interval,
+        seed: 0,
+        regenerator: None,
+        editor: None,
+        colour_overrides: None,
+    };
+    
+    dioxus::LaunchBuilder::new().with_context(render).launch(App);
+}
+
+/// The main function that runs the simulation for a cellular automaton with some state colours remapped.
+/// 
+/// This function behaves like `simulate`, but the window starts with the given colour overrides applied, so a tracked state can be highlighted from the first frame.
+/// The overrides can still be edited or cleared at runtime with the recolour control, and never change the automaton.
+/// 
+/// Parameters:
+/// 
+/// - `automaton`: The automaton to run the simulation on.
+/// 
+/// - `steps`: The number of steps to run the simulation for.
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+/// 
+/// - `colour_overrides`: A map from the colour of a state to the colour it is rendered in.
+pub fn simulate_with_colour_overrides<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: usize, interval: u64, colour_overrides: HashMap<Colour, Colour>) {
+    // Precompute all states upfront and wrap in Arc for thread-safe sharing
+    let states: Arc<Vec<BoardRepresentation>> = Arc::new(precompute_states(automaton, steps));
+    
+    // Prepare the render context
+    let render: BoardSimulationRender = BoardSimulationRender {
+        states,
+        steps,
+        interval,
+        seed: 0,
+        regenerator: None,
+        editor: None,
+        colour_overrides: Some(colour_overrides),
+    };
+    
+    dioxus::LaunchBuilder::new().with_context(render).launch(App);
+}
+
+/// The main function that runs the simulation for a cellular automaton in trail mode.
+/// 
+/// This function behaves like `simulate`, but cells that changed recently are blended towards the trail colour, leaving a fading trail behind moving patterns such as gliders and ants.
+/// 
+/// Parameters:
+/// 
+/// - `automaton`: The automaton to run the simulation on.
+/// 
+/// - `steps`: The number of steps to run the simulation for.
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+/// 
+/// - `trail`: The colour of the trail.
+/// 
+/// - `trail_length`: The number of steps the trail takes to fade.
+pub fn simulate_with_trail<S: State + Into<Colour>>(automaton: &mut Automaton<S>, steps: usize, interval: u64, trail: Colour, trail_length: usize) {
+    // Precompute all states upfront and blend in the trail
+    let states: Arc<Vec<BoardRepresentation>> = Arc::new(apply_trail(&precompute_states(automaton, steps), trail, trail_length));
     
     // Prepare the render context
     let render: BoardSimulationRender = BoardSimulationRender {
         states,
         steps,
         interval,
+        seed: 0,
+        regenerator: None,
+        editor: None,
+        colour_overrides: None,
     };
     
     dioxus::LaunchBuilder::new().with_context(render).launch(App);
 }
 
+/// The main function that runs the simulation for a cellular automaton with a randomly generated starting board.
+/// 
+/// This function builds a board from a random number generator seeded with `seed`, constructs an automaton with the rules returned by `make_rules`, and runs the simulation for the given number of steps with the given interval between each step.
+/// The window shows a "Randomise" button that reseeds the generator with the next seed, rebuilds the board and the automaton, and restarts the simulation.
+/// As with `simulate`, board states are precomputed before rendering the simulation.
+/// 
+/// Parameters:
+/// 
+/// - `make_board`: A function that builds the starting board using the given random number generator.
+/// 
+/// - `make_rules`: A function that returns the rules of the automaton.
+/// 
+/// - `steps`: The number of steps to run the simulation for.
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+/// 
+/// - `seed`: The seed of the random number generator used for the first board.
+pub fn simulate_random<S, B, R>(make_board: B, make_rules: R, steps: usize, interval: u64, seed: u64)
+where
+    S: State + Into<Colour>,
+    B: Fn(&mut StdRng) -> Board<S> + Send + Sync + 'static,
+    R: Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync + 'static,
+{
+    let regenerate = move |seed: u64| -> Vec<BoardRepresentation> {
+        let mut board: Board<S> = seeded_board(&make_board, seed);
+        let mut automaton: Automaton<S> = Automaton::new(&mut board, make_rules());
+        precompute_states(&mut automaton, steps)
+    };
+
+    // Precompute all states for the first seed upfront
+    let states: Arc<Vec<BoardRepresentation>> = Arc::new(regenerate(seed));
+    
+    // Prepare the render context
+    let render: BoardSimulationRender = BoardSimulationRender {
+        states,
+        steps,
+        interval,
+        seed,
+        regenerator: Some(BoardRegenerator(Arc::new(regenerate))),
+        editor: None,
+        colour_overrides: None,
+    };
+    
+    dioxus::LaunchBuilder::new().with_context(render).launch(App);
+}
+
+/// The main function that runs the simulation for a cellular automaton whose starting board is drawn by the user.
+/// 
+/// The window opens in an editing mode showing the given board, where clicking a cell replaces its state with the state returned by `toggle`.
+/// The "Run" button builds an automaton on a copy of the drawn board with the rules returned by `make_rules`, precomputes the given number of steps, and plays the simulation.
+/// The "Edit" button returns to the drawn board, so the pattern can be changed and run again.
+/// 
+/// Parameters:
+/// 
+/// - `board`: The starting board to draw on.
+/// 
+/// - `make_rules`: A function that returns the rules of the automaton.
+/// 
+/// - `steps`: The number of steps to run the simulation for.
+/// 
+/// - `interval`: The interval between each step in milliseconds.
+/// 
+/// - `toggle`: A function that returns the state a cell should take when it is clicked, given its current state.
+pub fn simulate_editable<S, R, T>(board: Board<S>, make_rules: R, steps: usize, interval: u64, toggle: T)
+where
+    S: State + Into<Colour>,
+    R: Fn() -> Vec<Box<dyn Rule<S>>> + Send + Sync + 'static,
+    T: Fn(S) -> S + Send + Sync + 'static,
+{
+    let board: Arc<Mutex<Board<S>>> = Arc::new(Mutex::new(board));
+    let editor: BoardEditor = BoardEditor {
+        representation: {
+            let board: Arc<Mutex<Board<S>>> = board.clone();
+            Arc::new(move || board.lock().unwrap().to_representation())
+        },
+        toggle: {
+            let board: Arc<Mutex<Board<S>>> = board.clone();
+            Arc::new(move |x: usize, y: usize| {
+                toggle_cell(&mut board.lock().unwrap(), x, y, &toggle);
+            })
+        },
+        run: Arc::new(move || {
+            let mut board: Board<S> = board.lock().unwrap().clone();
+            let mut automaton: Automaton<S> = Automaton::new(&mut board, make_rules());
+            precompute_states(&mut automaton, steps)
+        }),
+    };
+
+    // Nothing is precomputed until the drawn board is run
+    let render: BoardSimulationRender = BoardSimulationRender {
+        states: Arc::new(vec![(editor.representation)()]),
+        steps,
+        interval,
+        seed: 0,
+        regenerator: None,
+        editor: Some(editor),
+        colour_overrides: None,
+    };
+
+    dioxus::LaunchBuilder::new().with_context(render).launch(App);
+}
+
 /// The main function that runs the simulation for a genetic automaton.
 /// 
 /// This function takes a genetic automaton and runs the simulation for the given number of steps with the given interval between each step using the given growth and death rates.
@@ -96,6 +460,10 @@ pub fn simulate_genetic<S: State + Into<Colour>, G: Genotype<S>>(automaton: &mut
         states,
         steps,
         interval,
+        seed: 0,
+        regenerator: None,
+        editor: None,
+        colour_overrides: None,
     };
     
     dioxus::LaunchBuilder::new().with_context(render).launch(App);
@@ -108,10 +476,20 @@ pub fn simulate_genetic<S: State + Into<Colour>, G: Genotype<S>>(automaton: &mut
 /// Parameters:
 /// 
 /// - `colour`: The colour of the cell.
+/// 
+/// - `onclick`: An optional handler called when the cell is clicked.
 #[component]
-fn board_cell(colour: Colour) -> Element {
+fn board_cell(colour: Colour, onclick: Option<EventHandler<MouseEvent>>) -> Element {
     rsx! {
-        td { class: "cell", style: format!("background-color: {}", String::from(colour)) }
+        td {
+            class: "cell",
+            style: format!("background-color: {}", String::from(colour)),
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick {
+                    handler.call(event);
+                }
+            }
+        }
     }
 }
 
@@ -122,14 +500,22 @@ fn board_cell(colour: Colour) -> Element {
 /// Parameters:
 /// 
 /// - `board_state`: The state of the board represented as a 2D vector of `Colour`.
+/// 
+/// - `on_cell_click`: An optional handler called with the `(x, y)` coordinates of a cell when it is clicked.
 #[component]
-pub fn board_table(board_state: BoardRepresentation) -> Element {
+pub fn board_table(board_state: BoardRepresentation, on_cell_click: Option<EventHandler<(usize, usize)>>) -> Element {
     rsx! {
         table { class: "board",
             for (row_idx, row) in board_state.iter().enumerate() {
                 tr { key: "{row_idx}",
                     for (cell_idx, cell) in row.iter().enumerate() {
-                        board_cell { key: "{cell_idx}", colour: *cell }
+                        board_cell {
+                            key: "{cell_idx}",
+                            colour: *cell,
+                            onclick: on_cell_click.map(|handler: EventHandler<(usize, usize)>| {
+                                EventHandler::new(move |_: MouseEvent| handler.call((cell_idx, row_idx)))
+                            })
+                        }
                     }
                 }
             }
@@ -138,29 +524,64 @@ pub fn board_table(board_state: BoardRepresentation) -> Element {
 }
 
 /// The main application component that renders the simulation.
+/// 
+/// The simulation plays automatically on load. Below the board, playback controls pause and resume the simulation, step one frame forwards or backwards (pausing playback), and reset to the first frame.
+/// A speed slider sets the interval between frames from 50ms to 2000ms while the simulation is running.
+/// For simulations started with `simulate_editable`, the app opens in an editing mode where clicking a cell toggles it, and the "Run" button precomputes and plays the drawn board.
+/// Below those, a recolouring control lets the user pick a colour to replace and the colour to render in its place.
+/// "Recolour" adds the pair to the colour overrides and "Reset colours" removes all overrides. Overrides only change how the board is rendered.
 #[component]
 fn App() -> Element {
     let render: BoardSimulationRender = use_context::<BoardSimulationRender>();
     
-    let step: Signal<usize> = use_signal(|| 0);
+    let mut step: Signal<usize> = use_signal(|| 0);
+    let mut playing: Signal<bool> = use_signal(|| true);
+    let mut interval_ms: Signal<u64> = use_signal(|| clamp_interval(render.interval));
+    let mut editing: Signal<bool> = use_signal(|| render.editor.is_some());
+    let mut edit_board: Signal<BoardRepresentation> = use_signal(|| render.editor.as_ref().map(|editor| (editor.representation)()).unwrap_or_default());
+    let mut states: Signal<Arc<Vec<BoardRepresentation>>> = use_signal(|| render.states.clone());
+    let mut seed: Signal<u64> = use_signal(|| render.seed);
+    let mut colour_overrides: Signal<HashMap<Colour, Colour>> = use_signal(|| render.colour_overrides.clone().unwrap_or_default());
+    let mut override_from: Signal<Colour> = use_signal(Colour::white);
+    let mut override_to: Signal<Colour> = use_signal(Colour::red);
     
     let board_state: BoardRepresentation = {
-        let current_index: usize = step.read().min(render.states.len().saturating_sub(1));
-        render.states.get(current_index).cloned().unwrap_or_default()
+        let states = states.read();
+        let current_index: usize = step.read().min(states.len().saturating_sub(1));
+        let board_state: BoardRepresentation = states.get(current_index).cloned().unwrap_or_default();
+        remap_colours(&board_state, &colour_overrides.read())
     };
     
+    let steps: usize = render.steps;
     let _update_task: Coroutine<()> = use_coroutine(move |_rx: UnboundedReceiver<()>| {
         let mut step_clone: Signal<usize> = step.clone();
-        let steps: usize = render.steps;
-        let interval_ms: u64 = render.interval;
+        let playing_clone: Signal<bool> = playing;
+        let interval_clone: Signal<u64> = interval_ms;
         
         async move {
-            let mut interval: Interval = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+            let mut period_ms: u64 = *interval_clone.peek();
+            let mut interval: Interval = tokio::time::interval(std::time::Duration::from_millis(period_ms));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
             interval.tick().await;
             
-            for i in 1..steps {
-                step_clone.set(i + 1);
+            loop {
                 interval.tick().await;
+                if *playing_clone.peek() {
+                    let current: usize = *step_clone.peek();
+                    if current < steps {
+                        step_clone.set(current + 1);
+                    }
+                }
+
+                // Each tick advances exactly one frame, so restarting the interval after a tick when the speed
+                // changes neither skips nor repeats a frame
+                let requested_ms: u64 = *interval_clone.peek();
+                if requested_ms != period_ms {
+                    period_ms = requested_ms;
+                    let period: std::time::Duration = std::time::Duration::from_millis(period_ms);
+                    interval = tokio::time::interval_at(Instant::now() + period, period);
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                }
             }
         }
     });
@@ -171,7 +592,117 @@ fn App() -> Element {
 
         style { {include_str!("../assets/main.css")} }
         h1 {"LiveIron Simulation"}
-        board_table { board_state: board_state }
+        if let (true, Some(editor)) = (editing(), render.editor.clone()) {
+            board_table {
+                board_state: edit_board(),
+                on_cell_click: {
+                    let editor: BoardEditor = editor.clone();
+                    move |(x, y): (usize, usize)| {
+                        (editor.toggle)(x, y);
+                        edit_board.set((editor.representation)());
+                    }
+                }
+            }
+            button {
+                onclick: move |_| {
+                    states.set(Arc::new((editor.run)()));
+                    step.set(0);
+                    playing.set(true);
+                    editing.set(false);
+                },
+                "Run"
+            }
+        } else {
+            board_table { board_state: board_state }
+            if render.editor.is_some() {
+                button {
+                    onclick: move |_| {
+                        playing.set(false);
+                        editing.set(true);
+                    },
+                    "Edit"
+                }
+            }
+        }
         p { "Step {step}" }
+        div { class: "playback-controls",
+            button {
+                onclick: move |_| {
+                    playing.set(false);
+                    step.set(offset_step(step(), -1, steps));
+                },
+                "Step back"
+            }
+            button {
+                onclick: move |_| playing.set(!playing()),
+                if playing() { "Pause" } else { "Play" }
+            }
+            button {
+                onclick: move |_| {
+                    playing.set(false);
+                    step.set(offset_step(step(), 1, steps));
+                },
+                "Step forward"
+            }
+            button {
+                onclick: move |_| step.set(0),
+                "Reset"
+            }
+            input {
+                r#type: "range",
+                min: "{MIN_INTERVAL_MS}",
+                max: "{MAX_INTERVAL_MS}",
+                value: "{interval_ms}",
+                oninput: move |event: FormEvent| {
+                    if let Ok(interval) = event.value().parse::<u64>() {
+                        interval_ms.set(clamp_interval(interval));
+                    }
+                }
+            }
+            span { "{interval_ms}ms per frame" }
+        }
+        if let Some(regenerator) = render.regenerator.clone() {
+            button {
+                onclick: move |_| {
+                    let next_seed: u64 = seed().wrapping_add(1);
+                    seed.set(next_seed);
+                    states.set(Arc::new((regenerator.0)(next_seed)));
+                    step.set(0);
+                },
+                "Randomise"
+            }
+            p { "Seed {seed}" }
+        }
+        div { class: "colour-overrides",
+            input {
+                r#type: "color",
+                value: to_hex(override_from()),
+                oninput: move |event: FormEvent| {
+                    if let Some(colour) = parse_hex_colour(&event.value()) {
+                        override_from.set(colour);
+                    }
+                }
+            }
+            span { " as " }
+            input {
+                r#type: "color",
+                value: to_hex(override_to()),
+                oninput: move |event: FormEvent| {
+                    if let Some(colour) = parse_hex_colour(&event.value()) {
+                        override_to.set(colour);
+                    }
+                }
+            }
+            button {
+                onclick: move |_| {
+                    colour_overrides.write().insert(override_from(), override_to());
+                },
+                "Recolour"
+            }
+            button {
+                onclick: move |_| colour_overrides.write().clear(),
+                "Reset colours"
+            }
+        }
     }
-}
+}
\ No newline at end of file
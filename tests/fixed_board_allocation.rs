@@ -0,0 +1,77 @@
+//! Allocation counting for `FixedBoard`, kept in its own test binary so the counting allocator does not replace the
+//! allocator of the library tests.
+
+use live_iron::components::{board::BoundaryCondition, fixed_board::FixedBoard, state::common_states::GameOfLifeState};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// A global allocator that counts the allocations made by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+fn game_of_life<const W: usize, const H: usize>(
+    coord: (usize, usize),
+    board: &FixedBoard<GameOfLifeState, W, H>,
+) -> GameOfLifeState {
+    let mut num_alive: usize = 0;
+    for dy in 0..3 {
+        for dx in 0..3 {
+            if dx == 1 && dy == 1 {
+                continue;
+            }
+            let x: usize = (coord.0 + W + dx - 1) % W;
+            let y: usize = (coord.1 + H + dy - 1) % H;
+            if board.get(x, y) == Some(GameOfLifeState::Alive) {
+                num_alive += 1;
+            }
+        }
+    }
+
+    match (board.get(coord.0, coord.1).unwrap(), num_alive) {
+        (GameOfLifeState::Alive, 2) | (_, 3) => GameOfLifeState::Alive,
+        _ => GameOfLifeState::Dead,
+    }
+}
+
+#[test]
+fn test_fixed_board_blinker_no_allocation() {
+    let mut board: FixedBoard<GameOfLifeState, 5, 5> = FixedBoard::filled(GameOfLifeState::Dead, BoundaryCondition::Periodic);
+    board.set(2, 1, GameOfLifeState::Alive).unwrap();
+    board.set(2, 2, GameOfLifeState::Alive).unwrap();
+    board.set(2, 3, GameOfLifeState::Alive).unwrap();
+    let initial: FixedBoard<GameOfLifeState, 5, 5> = board.clone();
+
+    let before: usize = allocations();
+    board.step(game_of_life);
+    let after_one_step: FixedBoard<GameOfLifeState, 5, 5> = board.clone();
+    board.step(game_of_life);
+    let after: usize = allocations();
+
+    assert_eq!(after - before, 0);
+    assert_eq!(after_one_step.get(1, 2), Some(GameOfLifeState::Alive));
+    assert_eq!(after_one_step.get(2, 2), Some(GameOfLifeState::Alive));
+    assert_eq!(after_one_step.get(3, 2), Some(GameOfLifeState::Alive));
+    assert_eq!(after_one_step.get(2, 1), Some(GameOfLifeState::Dead));
+    assert_eq!(board, initial);
+}